@@ -5,7 +5,12 @@ use vrp_solver::{
     parsers::vrp_parser::VrpInputs,
     solvers::{
         genetic::{
-            crossover::order_crossover::OrderCrossover, genetic_solver::GeneticSolverParameters,
+            crossover::order_crossover::OrderCrossover,
+            genetic_solver::GeneticSolverParameters,
+            mutation::{
+                mutation_strategy::{MutationStrategy, WeightedMutation},
+                swap_mutation::SwapMutation,
+            },
         },
         grasp::vrp_grasp_solver::{GraspSolverParameters, VrpGraspSolver},
         solver::Solver,
@@ -25,6 +30,7 @@ pub fn genetic_grasp_benchmark(c: &mut Criterion) {
             stops,
             vehicles,
             distances,
+            ..
         } = InstanceRepository::get_instance(instance, 5);
 
         let rcl_size = (stops.len() as f32 * 0.3).round() as usize;
@@ -50,9 +56,13 @@ pub fn genetic_grasp_benchmark(c: &mut Criterion) {
                     population_size: 200,
                     genetic_solver_parameters: GeneticSolverParameters {
                         elite_size: 5,
-                        mutation_rate: 0.05,
+                        mutations: vec![WeightedMutation::new(
+                            MutationStrategy::Swap(SwapMutation),
+                            0.05,
+                        )],
                         max_generations: 1000,
                         local_search_rate: 0.3,
+                        ..Default::default()
                     },
                 };
 
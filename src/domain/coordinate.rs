@@ -0,0 +1,19 @@
+use std::collections::HashMap;
+
+pub type CoordinateMap = HashMap<u32, Coordinate>;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Coordinate {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Coordinate {
+    pub fn new(x: f32, y: f32) -> Coordinate {
+        Coordinate { x, y }
+    }
+
+    pub fn get_polar_angle(&self, origin: &Coordinate) -> f32 {
+        (self.y - origin.y).atan2(self.x - origin.x)
+    }
+}
@@ -1,4 +1,7 @@
-use crate::domain::errors::vehicle::vehicle_overload::VehicleOverloadError;
+use crate::{
+    domain::errors::vehicle::vehicle_overload::VehicleOverloadError,
+    services::distance::distance_service::DistanceService,
+};
 
 use super::{stop::Stop, vehicle::Vehicle};
 
@@ -42,14 +45,33 @@ impl Route {
         Ok(())
     }
 
-    pub fn remove_stop(&mut self, stop_index: usize, distance_reduction: f32) {
-        self.stops.remove(stop_index);
+    pub fn remove_stop(&mut self, stop_index: usize, distance_reduction: f32) -> Stop {
+        let stop = self.stops.remove(stop_index);
+
+        self.vehicle.unload(stop.usage).unwrap_or_else(|_| {
+            panic!(
+                "the vehicle {0} should be carrying the load of stop {1}",
+                self.vehicle.id, stop.id
+            )
+        });
+
         self.total_distance -= distance_reduction;
+
+        stop
     }
 
-    pub fn add_stop_at(&mut self, stop: Stop, index: usize, distance_change: f32) {
+    pub fn add_stop_at(
+        &mut self,
+        stop: Stop,
+        index: usize,
+        distance_change: f32,
+    ) -> Result<(), VehicleOverloadError> {
+        self.vehicle.load(stop.usage)?;
+
         self.stops.insert(index, stop);
         self.total_distance += distance_change;
+
+        Ok(())
     }
 
     pub fn add_multiple_stops_at(&mut self, stops: Vec<Stop>, index: usize, distance_change: f32) {
@@ -69,6 +91,55 @@ impl Route {
         self.total_distance
     }
 
+    pub fn replace_stops(
+        &mut self,
+        stops: Vec<Stop>,
+        distance_service: &DistanceService,
+    ) -> Result<(), VehicleOverloadError> {
+        let mut vehicle = self.vehicle;
+        vehicle.reset();
+
+        for stop in stops.iter() {
+            vehicle.load(stop.usage)?;
+        }
+
+        self.total_distance = stops
+            .windows(2)
+            .map(|window| distance_service.get_distance(&window[0], &window[1]))
+            .sum();
+
+        self.vehicle = vehicle;
+        self.stops = stops;
+
+        Ok(())
+    }
+
+    /// Replaces the stops even if they overload the vehicle, so the caller is responsible for
+    /// checking the capacity. The vehicle still carries the load of every stop.
+    pub fn replace_stops_unchecked(
+        &mut self,
+        stops: Vec<Stop>,
+        distance_service: &DistanceService,
+    ) {
+        self.vehicle.reset();
+
+        for stop in stops.iter() {
+            self.vehicle.load_unchecked(stop.usage);
+        }
+
+        self.total_distance = stops
+            .windows(2)
+            .map(|window| distance_service.get_distance(&window[0], &window[1]))
+            .sum();
+
+        self.stops = stops;
+    }
+
+    pub fn reverse_stops(&mut self, start: usize, end: usize, distance_change: f32) {
+        self.stops[start..=end].reverse();
+        self.total_distance += distance_change;
+    }
+
     pub fn swap_stops(&mut self, index1: usize, index2: usize, distance_change: f32) {
         self.stops.swap(index1, index2);
         self.total_distance += distance_change;
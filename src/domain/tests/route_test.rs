@@ -31,3 +31,69 @@ fn route_cannot_overload_vehicle(full_stops: Vec<Stop>, distance_service: Distan
         panic!();
     }
 }
+
+#[rstest]
+fn route_frees_vehicle_load_when_removing_stop(full_stops: Vec<Stop>) {
+    let vehicle = Vehicle::new(0, 10);
+
+    let mut route = Route::new(vehicle);
+
+    route.add_stop(full_stops[0], 0.0).unwrap();
+    route.remove_stop(0, 0.0);
+
+    assert!(route.add_stop_at(full_stops[0], 0, 0.0).is_ok());
+    assert!(route.add_stop_at(full_stops[0], 1, 0.0).is_err());
+}
+
+#[rstest]
+fn route_can_replace_its_stops(
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+    distance_service: DistanceService,
+) {
+    let mut route = route_factory(stops[0..4].to_vec());
+
+    route
+        .replace_stops(
+            vec![stops[0], stops[2], stops[1], stops[0]],
+            &distance_service,
+        )
+        .unwrap();
+
+    assert_eq!(route.stops[1].id, 2);
+    assert_eq!(route.total_distance(), 8.0);
+}
+
+#[rstest]
+fn route_keeps_its_stops_when_replacement_overloads(
+    full_stops: Vec<Stop>,
+    distance_service: DistanceService,
+) {
+    let mut route = Route::new(Vehicle::new(0, 10));
+
+    route.add_stop(full_stops[0], 0.0).unwrap();
+
+    assert!(route
+        .replace_stops(full_stops.clone(), &distance_service)
+        .is_err());
+    assert_eq!(route.stops.len(), 1);
+}
+
+#[rstest]
+fn route_can_replace_stops_beyond_its_capacity(
+    full_stops: Vec<Stop>,
+    distance_service: DistanceService,
+) {
+    let mut route = Route::new(Vehicle::new(0, 10));
+
+    route.replace_stops_unchecked(full_stops.clone(), &distance_service);
+
+    assert_eq!(route.stops.len(), 2);
+    assert_eq!(route.total_distance(), 2.0);
+    assert_eq!(route.vehicle.usage(), 105);
+    assert!(!route.can_add_stop(&Stop::new(2, 0)));
+
+    route.remove_stop(1, 2.0);
+
+    assert_eq!(route.vehicle.usage(), 5);
+}
@@ -19,3 +19,12 @@ fn cannot_have_negative_usage() {
         panic!();
     }
 }
+
+#[test]
+fn excess_load_keeps_the_vehicle_strictly_below_its_capacity() {
+    let vehicle = Vehicle::new(0, 10);
+
+    assert_eq!(vehicle.calculate_excess_load(9), 0);
+    assert_eq!(vehicle.calculate_excess_load(10), 1);
+    assert_eq!(vehicle.calculate_excess_load(14), 5);
+}
@@ -23,10 +23,19 @@ impl Vehicle {
         self.usage = Default::default();
     }
 
+    pub fn usage(&self) -> u32 {
+        self.usage
+    }
+
     pub fn can_support_load(&self, quantity: u32) -> bool {
         self.usage + quantity < self.capacity
     }
 
+    /// Load beyond what the vehicle can carry, given that it must stay strictly below its capacity.
+    pub fn calculate_excess_load(&self, load: u32) -> u32 {
+        (load + 1).saturating_sub(self.capacity)
+    }
+
     pub fn load(&mut self, quantity: u32) -> Result<(), VehicleOverloadError> {
         match self.can_support_load(quantity) {
             false => Err(VehicleOverloadError::new()),
@@ -37,12 +46,17 @@ impl Vehicle {
         }
     }
 
+    /// Loads the quantity even beyond the capacity. An overloaded vehicle supports no more load.
+    pub fn load_unchecked(&mut self, quantity: u32) {
+        self.usage += quantity;
+    }
+
     pub fn unload(&mut self, quantity: u32) -> Result<(), NegativeVehicleCapacityError> {
         if quantity > self.usage {
             return Err(NegativeVehicleCapacityError::new());
         }
 
-        self.usage = quantity;
+        self.usage -= quantity;
         Ok(())
     }
 }
@@ -10,6 +10,10 @@ use crate::{
         genetic::{
             crossover::crossover_operator::CrossoverOperator,
             genetic_solver::GeneticSolverParameters,
+            mutation::{
+                mutation_strategy::{MutationStrategy, WeightedMutation},
+                swap_mutation::SwapMutation,
+            },
         },
         grasp::vrp_grasp_solver::{GraspSolverParameters, VrpGraspSolver},
         solver::Solver,
@@ -59,9 +63,13 @@ where
         population_size: parameters.population_size,
         genetic_solver_parameters: GeneticSolverParameters {
             elite_size: parameters.elite_size,
-            mutation_rate: parameters.mutation_rate,
+            mutations: vec![WeightedMutation::new(
+                MutationStrategy::Swap(SwapMutation),
+                parameters.mutation_rate,
+            )],
             max_generations: parameters.max_generations,
             local_search_rate: parameters.local_search_rate,
+            ..Default::default()
         },
     };
 
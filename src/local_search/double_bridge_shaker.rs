@@ -0,0 +1,79 @@
+use std::{cell::RefCell, ops::DerefMut, rc::Rc};
+
+use rand::{seq::IteratorRandom, Rng};
+
+use crate::{
+    domain::stop::Stop, services::distance::distance_service::DistanceService,
+    solvers::vrp_solution::VrpSolution,
+};
+
+use super::shaker::Shaker;
+
+pub struct DoubleBridgeShaker<R: Rng + ?Sized> {
+    distance_service: Rc<DistanceService>,
+    rng: RefCell<R>,
+}
+
+impl<R: Rng> DoubleBridgeShaker<R> {
+    pub fn new(distance_service: Rc<DistanceService>, rng: R) -> Self {
+        Self {
+            distance_service,
+            rng: RefCell::new(rng),
+        }
+    }
+}
+
+impl<R: Rng + ?Sized> DoubleBridgeShaker<R> {
+    pub(super) fn double_bridge(stops: &[Stop], cuts: [usize; 3]) -> Vec<Stop> {
+        let [first_cut, second_cut, third_cut] = cuts;
+
+        let mut bridged_stops = Vec::with_capacity(stops.len());
+
+        bridged_stops.extend_from_slice(&stops[..first_cut]);
+        bridged_stops.extend_from_slice(&stops[second_cut..third_cut]);
+        bridged_stops.extend_from_slice(&stops[first_cut..second_cut]);
+        bridged_stops.extend_from_slice(&stops[third_cut..]);
+
+        bridged_stops
+    }
+}
+
+impl<R: Rng + ?Sized> Shaker<VrpSolution> for DoubleBridgeShaker<R> {
+    fn shake(&self, solution: &mut VrpSolution, intensity: usize) {
+        let mut rng = self.rng.borrow_mut();
+
+        for _ in 0..intensity {
+            let route = match solution
+                .routes
+                .values_mut()
+                .filter(|route| route.stops.len() > 3)
+                .choose(rng.deref_mut())
+            {
+                Some(route) => route,
+                None => break,
+            };
+
+            let last_stop_index = route.stops.len() - 1;
+
+            let mut cuts = (1..=last_stop_index).choose_multiple(rng.deref_mut(), 3);
+            cuts.sort_unstable();
+
+            let bridged_stops = Self::double_bridge(&route.stops, [cuts[0], cuts[1], cuts[2]]);
+
+            route
+                .replace_stops(bridged_stops, &self.distance_service)
+                .unwrap_or_else(|_| {
+                    panic!(
+                        "the vehicle {} should support the load of its own stops",
+                        route.vehicle.id
+                    )
+                });
+        }
+
+        solution.total_distance = solution
+            .routes
+            .values()
+            .map(|route| route.total_distance())
+            .sum();
+    }
+}
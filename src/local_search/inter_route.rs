@@ -0,0 +1,391 @@
+use std::{ops::Range, rc::Rc};
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::distance::distance_service::DistanceService,
+    solvers::vrp_solution::VrpSolution,
+};
+
+use super::local_searcher::LocalSearcher;
+
+const MIN_IMPROVEMENT: f32 = 1e-4;
+const MAX_MUTATION_ATTEMPTS: usize = 20;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InterRouteMove {
+    Relocate,
+    Exchange,
+    TwoOptStar,
+    CrossExchange,
+}
+
+#[derive(Clone, Debug)]
+struct SegmentExchange {
+    first_route: usize,
+    second_route: usize,
+    first_segment: Range<usize>,
+    second_segment: Range<usize>,
+}
+
+struct RouteProfile<'a> {
+    stops: &'a [Stop],
+    capacity: u32,
+    loads: Vec<u32>,
+    distances: Vec<f32>,
+}
+
+impl<'a> RouteProfile<'a> {
+    fn new(route: &'a Route, distance_service: &DistanceService) -> Self {
+        let mut loads = Vec::with_capacity(route.stops.len() + 1);
+        let mut distances = Vec::with_capacity(route.stops.len());
+
+        loads.push(0);
+        distances.push(0.0);
+
+        for (index, stop) in route.stops.iter().enumerate() {
+            loads.push(loads[index] + stop.usage);
+
+            if index > 0 {
+                distances.push(
+                    distances[index - 1]
+                        + distance_service.get_distance(&route.stops[index - 1], stop),
+                );
+            }
+        }
+
+        Self {
+            loads,
+            distances,
+            stops: &route.stops,
+            capacity: route.vehicle.capacity,
+        }
+    }
+
+    fn get_load(&self, segment: &Range<usize>) -> u32 {
+        self.loads[segment.end] - self.loads[segment.start]
+    }
+
+    fn get_inner_distance(&self, segment: &Range<usize>) -> f32 {
+        match segment.is_empty() {
+            true => 0.0,
+            false => self.distances[segment.end - 1] - self.distances[segment.start],
+        }
+    }
+
+    fn get_path_distance(&self, segment: &Range<usize>) -> f32 {
+        self.distances[segment.end] - self.distances[segment.start - 1]
+    }
+
+    fn number_of_customers(&self) -> usize {
+        self.stops.len() - 2
+    }
+}
+
+pub struct InterRouteSearcher {
+    moves: Vec<InterRouteMove>,
+    max_segment_length: usize,
+    distance_service: Rc<DistanceService>,
+}
+
+impl InterRouteSearcher {
+    pub fn new(distance_service: Rc<DistanceService>) -> Self {
+        Self::with_moves(
+            distance_service,
+            vec![
+                InterRouteMove::Relocate,
+                InterRouteMove::Exchange,
+                InterRouteMove::TwoOptStar,
+                InterRouteMove::CrossExchange,
+            ],
+        )
+    }
+
+    pub fn with_moves(distance_service: Rc<DistanceService>, moves: Vec<InterRouteMove>) -> Self {
+        Self {
+            moves,
+            distance_service,
+            max_segment_length: 3,
+        }
+    }
+
+    pub fn with_max_segment_length(mut self, max_segment_length: usize) -> Self {
+        self.max_segment_length = max_segment_length.max(1);
+        self
+    }
+
+    pub fn run(&self, routes: &mut [Route]) {
+        while let Some(exchange) = self.find_best_exchange(routes) {
+            self.apply(routes, &exchange);
+        }
+    }
+
+    pub fn mutate<R: Rng + ?Sized>(&self, routes: &mut [Route], rng: &mut R) -> bool {
+        let candidates: Vec<usize> = (0..routes.len())
+            .filter(|index| routes[*index].stops.len() >= 2)
+            .collect();
+
+        if candidates.len() < 2 {
+            return false;
+        }
+
+        for _ in 0..MAX_MUTATION_ATTEMPTS {
+            let route_indexes: Vec<usize> = candidates.choose_multiple(rng, 2).copied().collect();
+
+            let inter_route_move = match self.moves.choose(rng) {
+                Some(inter_route_move) => *inter_route_move,
+                None => return false,
+            };
+
+            let profile1 = RouteProfile::new(&routes[route_indexes[0]], &self.distance_service);
+            let profile2 = RouteProfile::new(&routes[route_indexes[1]], &self.distance_service);
+
+            let exchanges = self.list_exchanges(
+                inter_route_move,
+                (route_indexes[0], &profile1),
+                (route_indexes[1], &profile2),
+            );
+
+            let feasible: Vec<&SegmentExchange> = exchanges
+                .iter()
+                .filter(|exchange| Self::is_feasible(&profile1, &profile2, exchange))
+                .collect();
+
+            if let Some(exchange) = feasible.choose(rng) {
+                let exchange = (*exchange).clone();
+                self.apply(routes, &exchange);
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn find_best_exchange(&self, routes: &[Route]) -> Option<SegmentExchange> {
+        let profiles: Vec<Option<RouteProfile>> = routes
+            .iter()
+            .map(|route| match route.stops.len() >= 2 {
+                true => Some(RouteProfile::new(route, &self.distance_service)),
+                false => None,
+            })
+            .collect();
+
+        let mut best_exchange: Option<(SegmentExchange, f32)> = None;
+
+        for (index1, profile1) in profiles.iter().enumerate() {
+            for (index2, profile2) in profiles.iter().enumerate() {
+                let (profile1, profile2) = match (profile1, profile2) {
+                    (Some(profile1), Some(profile2)) if index1 != index2 => (profile1, profile2),
+                    _ => continue,
+                };
+
+                for inter_route_move in self.moves.iter() {
+                    let exchanges = self.list_exchanges(
+                        *inter_route_move,
+                        (index1, profile1),
+                        (index2, profile2),
+                    );
+
+                    for exchange in exchanges {
+                        if !Self::is_feasible(profile1, profile2, &exchange) {
+                            continue;
+                        }
+
+                        let cost = self.calculate_exchange_cost(profile1, profile2, &exchange);
+
+                        if cost >= -MIN_IMPROVEMENT {
+                            continue;
+                        }
+
+                        match best_exchange {
+                            Some((_, best_cost)) if best_cost <= cost => (),
+                            _ => best_exchange = Some((exchange, cost)),
+                        }
+                    }
+                }
+            }
+        }
+
+        best_exchange.map(|(exchange, _)| exchange)
+    }
+
+    fn list_exchanges(
+        &self,
+        inter_route_move: InterRouteMove,
+        (index1, profile1): (usize, &RouteProfile),
+        (index2, profile2): (usize, &RouteProfile),
+    ) -> Vec<SegmentExchange> {
+        let segment_lengths: Vec<(usize, usize)> = match inter_route_move {
+            InterRouteMove::Relocate => vec![(1, 0)],
+            InterRouteMove::Exchange => vec![(1, 1)],
+            InterRouteMove::CrossExchange => (1..=self.max_segment_length)
+                .flat_map(|length1| {
+                    (1..=self.max_segment_length).map(move |length2| (length1, length2))
+                })
+                .filter(|lengths| *lengths != (1, 1))
+                .collect(),
+            InterRouteMove::TwoOptStar => {
+                return (1..profile1.stops.len())
+                    .flat_map(|start1| {
+                        (1..profile2.stops.len()).map(move |start2| SegmentExchange {
+                            first_route: index1,
+                            second_route: index2,
+                            first_segment: start1..profile1.stops.len() - 1,
+                            second_segment: start2..profile2.stops.len() - 1,
+                        })
+                    })
+                    .filter(|exchange| {
+                        !exchange.first_segment.is_empty() || !exchange.second_segment.is_empty()
+                    })
+                    .collect();
+            }
+        };
+
+        let mut exchanges = Vec::new();
+
+        for (length1, length2) in segment_lengths {
+            if length1 > profile1.number_of_customers() || length2 > profile2.number_of_customers()
+            {
+                continue;
+            }
+
+            for start1 in 1..=(profile1.number_of_customers() + 1 - length1) {
+                for start2 in 1..=(profile2.number_of_customers() + 1 - length2) {
+                    exchanges.push(SegmentExchange {
+                        first_route: index1,
+                        second_route: index2,
+                        first_segment: start1..start1 + length1,
+                        second_segment: start2..start2 + length2,
+                    });
+                }
+            }
+        }
+
+        exchanges
+    }
+
+    fn is_feasible(
+        profile1: &RouteProfile,
+        profile2: &RouteProfile,
+        exchange: &SegmentExchange,
+    ) -> bool {
+        let load1 = profile1.get_load(&exchange.first_segment);
+        let load2 = profile2.get_load(&exchange.second_segment);
+        let total_load1 = profile1.get_load(&(0..profile1.stops.len()));
+        let total_load2 = profile2.get_load(&(0..profile2.stops.len()));
+
+        total_load1 - load1 + load2 < profile1.capacity
+            && total_load2 - load2 + load1 < profile2.capacity
+    }
+
+    fn calculate_splice_cost(
+        &self,
+        target: &RouteProfile,
+        removed: &Range<usize>,
+        source: &RouteProfile,
+        inserted: &Range<usize>,
+    ) -> f32 {
+        let before = &target.stops[removed.start - 1];
+        let after = &target.stops[removed.end];
+
+        let new_distance = match inserted.is_empty() {
+            true => self.distance_service.get_distance(before, after),
+            false => {
+                self.distance_service
+                    .get_distance(before, &source.stops[inserted.start])
+                    + source.get_inner_distance(inserted)
+                    + self
+                        .distance_service
+                        .get_distance(&source.stops[inserted.end - 1], after)
+            }
+        };
+
+        new_distance - target.get_path_distance(removed)
+    }
+
+    fn calculate_exchange_cost(
+        &self,
+        profile1: &RouteProfile,
+        profile2: &RouteProfile,
+        exchange: &SegmentExchange,
+    ) -> f32 {
+        self.calculate_splice_cost(
+            profile1,
+            &exchange.first_segment,
+            profile2,
+            &exchange.second_segment,
+        ) + self.calculate_splice_cost(
+            profile2,
+            &exchange.second_segment,
+            profile1,
+            &exchange.first_segment,
+        )
+    }
+
+    fn apply(&self, routes: &mut [Route], exchange: &SegmentExchange) {
+        let stops1 = &routes[exchange.first_route].stops;
+        let stops2 = &routes[exchange.second_route].stops;
+
+        let new_stops1 = Self::splice(
+            stops1,
+            &exchange.first_segment,
+            stops2,
+            &exchange.second_segment,
+        );
+        let new_stops2 = Self::splice(
+            stops2,
+            &exchange.second_segment,
+            stops1,
+            &exchange.first_segment,
+        );
+
+        for (route_index, stops) in [
+            (exchange.first_route, new_stops1),
+            (exchange.second_route, new_stops2),
+        ] {
+            let route = &mut routes[route_index];
+
+            route
+                .replace_stops(stops, &self.distance_service)
+                .unwrap_or_else(|_| {
+                    panic!(
+                        "the vehicle {} should support the exchanged load",
+                        route.vehicle.id
+                    )
+                });
+        }
+    }
+
+    fn splice(
+        target: &[Stop],
+        removed: &Range<usize>,
+        source: &[Stop],
+        inserted: &Range<usize>,
+    ) -> Vec<Stop> {
+        target[..removed.start]
+            .iter()
+            .chain(source[inserted.clone()].iter())
+            .chain(target[removed.end..].iter())
+            .copied()
+            .collect()
+    }
+}
+
+impl LocalSearcher<VrpSolution> for InterRouteSearcher {
+    fn run(&self, solution: &mut VrpSolution) {
+        let mut routes: Vec<Route> = solution.routes.values().cloned().collect();
+
+        self.run(&mut routes);
+
+        for route in routes {
+            solution.routes.insert(route.vehicle.id, route);
+        }
+
+        solution.total_distance = solution
+            .routes
+            .values()
+            .map(|route| route.total_distance())
+            .sum();
+    }
+}
@@ -0,0 +1,346 @@
+use std::{
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::distance::distance_service::DistanceService,
+    solvers::vrp_solution::VrpSolution,
+};
+
+use super::{local_searcher::LocalSearcher, segment_costs::SegmentCosts};
+
+const MIN_IMPROVEMENT: f32 = 1e-4;
+const MAX_SEGMENT_SIZE: usize = 3;
+
+type NeighborLists = HashMap<u32, Vec<u32>>;
+type Positions = HashMap<u32, usize>;
+type Edge = (u32, u32);
+
+#[derive(Clone, Copy)]
+struct Reversal {
+    start: usize,
+    end: usize,
+    cost: f32,
+}
+
+pub struct LinKernighanSearcher {
+    max_depth: usize,
+    neighborhood_size: usize,
+    distance_service: Rc<DistanceService>,
+}
+
+impl LinKernighanSearcher {
+    pub fn new(
+        distance_service: Rc<DistanceService>,
+        neighborhood_size: usize,
+        max_depth: usize,
+    ) -> Self {
+        Self {
+            max_depth,
+            neighborhood_size,
+            distance_service,
+        }
+    }
+
+    pub fn run(&self, route: &mut Route) {
+        if route.stops.len() < 4 {
+            return;
+        }
+
+        let mut stops = route.stops.clone();
+        let neighbor_lists = self.map_neighbor_lists(&stops);
+
+        loop {
+            let improved_by_or_opt = self.improve_with_or_opt(&mut stops, &neighbor_lists);
+            let improved_by_chain = self.improve_with_reversal_chain(&mut stops, &neighbor_lists);
+
+            if !improved_by_or_opt && !improved_by_chain {
+                break;
+            }
+        }
+
+        route
+            .replace_stops(stops, &self.distance_service)
+            .unwrap_or_else(|_| {
+                panic!(
+                    "the vehicle {} should support the load of its own stops",
+                    route.vehicle.id
+                )
+            });
+    }
+
+    fn distance(&self, from: &Stop, to: &Stop) -> f32 {
+        self.distance_service.get_distance(from, to)
+    }
+
+    fn map_neighbor_lists(&self, stops: &[Stop]) -> NeighborLists {
+        let customers = &stops[..stops.len() - 1];
+
+        customers
+            .iter()
+            .map(|stop| {
+                let mut neighbors: Vec<&Stop> = customers
+                    .iter()
+                    .filter(|neighbor| neighbor.id != stop.id)
+                    .collect();
+
+                neighbors.sort_by(|neighbor1, neighbor2| {
+                    self.distance(stop, neighbor1)
+                        .total_cmp(&self.distance(stop, neighbor2))
+                });
+
+                let neighbors = neighbors
+                    .into_iter()
+                    .take(self.neighborhood_size)
+                    .map(|neighbor| neighbor.id)
+                    .collect();
+
+                (stop.id, neighbors)
+            })
+            .collect()
+    }
+
+    fn map_positions(stops: &[Stop]) -> Positions {
+        stops[..stops.len() - 1]
+            .iter()
+            .enumerate()
+            .map(|(position, stop)| (stop.id, position))
+            .collect()
+    }
+
+    fn evaluate_reversal(
+        &self,
+        stops: &[Stop],
+        segment_costs: &SegmentCosts,
+        start: usize,
+        end: usize,
+    ) -> Reversal {
+        let cost = self.distance(&stops[start - 1], &stops[end])
+            + self.distance(&stops[start], &stops[end + 1])
+            - self.distance(&stops[start - 1], &stops[start])
+            - self.distance(&stops[end], &stops[end + 1])
+            + segment_costs.get_reversal_change(start, end);
+
+        Reversal { start, end, cost }
+    }
+
+    fn find_best_reversal(
+        &self,
+        stops: &[Stop],
+        neighbor_lists: &NeighborLists,
+        added_edges: &HashSet<Edge>,
+    ) -> Option<Reversal> {
+        let positions = Self::map_positions(stops);
+        let segment_costs = SegmentCosts::new(stops, &self.distance_service);
+        let last_customer = stops.len() - 2;
+
+        let mut best_reversal: Option<Reversal> = None;
+
+        for (position, stop) in stops[..stops.len() - 1].iter().enumerate() {
+            for neighbor_id in neighbor_lists[&stop.id].iter() {
+                let neighbor_position = positions[neighbor_id];
+
+                let (start, end) = match neighbor_position > position + 1 {
+                    true if neighbor_position <= last_customer => (position + 1, neighbor_position),
+                    false if neighbor_position >= 1 && neighbor_position + 1 < position => {
+                        (neighbor_position, position - 1)
+                    }
+                    _ => continue,
+                };
+
+                if added_edges.contains(&Self::get_edge(&stops[start - 1], &stops[start]))
+                    || added_edges.contains(&Self::get_edge(&stops[end], &stops[end + 1]))
+                {
+                    continue;
+                }
+
+                let reversal = self.evaluate_reversal(stops, &segment_costs, start, end);
+
+                match best_reversal {
+                    Some(best) if best.cost <= reversal.cost => (),
+                    _ => best_reversal = Some(reversal),
+                }
+            }
+        }
+
+        best_reversal
+    }
+
+    fn get_edge(from: &Stop, to: &Stop) -> Edge {
+        (from.id.min(to.id), from.id.max(to.id))
+    }
+
+    fn improve_with_reversal_chain(
+        &self,
+        stops: &mut Vec<Stop>,
+        neighbor_lists: &NeighborLists,
+    ) -> bool {
+        let mut improved = false;
+
+        while let Some(improved_stops) = self.find_reversal_chain(stops, neighbor_lists) {
+            *stops = improved_stops;
+            improved = true;
+        }
+
+        improved
+    }
+
+    fn find_reversal_chain(
+        &self,
+        stops: &[Stop],
+        neighbor_lists: &NeighborLists,
+    ) -> Option<Vec<Stop>> {
+        let mut current = stops.to_vec();
+        let mut added_edges: HashSet<Edge> = HashSet::new();
+        let mut cumulative_cost = 0.0;
+        let mut best_cost = -MIN_IMPROVEMENT;
+        let mut best_stops = None;
+
+        for depth in 0..self.max_depth.max(1) {
+            let reversal = match self.find_best_reversal(&current, neighbor_lists, &added_edges) {
+                Some(reversal) => reversal,
+                None => break,
+            };
+
+            cumulative_cost += reversal.cost;
+
+            if depth > 0 && cumulative_cost >= 0.0 {
+                break;
+            }
+
+            current[reversal.start..=reversal.end].reverse();
+
+            added_edges.insert(Self::get_edge(
+                &current[reversal.start - 1],
+                &current[reversal.start],
+            ));
+            added_edges.insert(Self::get_edge(
+                &current[reversal.end],
+                &current[reversal.end + 1],
+            ));
+
+            if cumulative_cost < best_cost {
+                best_cost = cumulative_cost;
+                best_stops = Some(current.clone());
+            }
+        }
+
+        best_stops
+    }
+
+    fn improve_with_or_opt(&self, stops: &mut Vec<Stop>, neighbor_lists: &NeighborLists) -> bool {
+        let mut improved = false;
+
+        while let Some(moved_stops) = self.find_or_opt_move(stops, neighbor_lists) {
+            *stops = moved_stops;
+            improved = true;
+        }
+
+        improved
+    }
+
+    fn find_or_opt_move(
+        &self,
+        stops: &[Stop],
+        neighbor_lists: &NeighborLists,
+    ) -> Option<Vec<Stop>> {
+        let positions = Self::map_positions(stops);
+        let segment_costs = SegmentCosts::new(stops, &self.distance_service);
+        let last_customer = stops.len() - 2;
+
+        for start in 1..=last_customer {
+            for size in 1..=MAX_SEGMENT_SIZE {
+                let end = start + size - 1;
+
+                if end > last_customer {
+                    break;
+                }
+
+                let removal_gain = self.distance(&stops[start - 1], &stops[start])
+                    + self.distance(&stops[end], &stops[end + 1])
+                    - self.distance(&stops[start - 1], &stops[end + 1]);
+
+                let insertion_points = neighbor_lists[&stops[start].id]
+                    .iter()
+                    .chain(neighbor_lists[&stops[end].id].iter())
+                    .flat_map(|neighbor_id| {
+                        let position = positions[neighbor_id];
+                        [position.saturating_sub(1), position]
+                    })
+                    .filter(|position| *position + 1 < start || *position > end);
+
+                for position in insertion_points {
+                    let before = &stops[position];
+                    let after = &stops[position + 1];
+                    let current_edge = self.distance(before, after);
+
+                    let forward_cost = self.distance(before, &stops[start])
+                        + self.distance(&stops[end], after)
+                        - current_edge;
+
+                    let backward_cost = self.distance(before, &stops[end])
+                        + self.distance(&stops[start], after)
+                        - current_edge
+                        + segment_costs.get_reversal_change(start, end);
+
+                    let (insertion_cost, reverse) = match backward_cost < forward_cost {
+                        true => (backward_cost, true),
+                        false => (forward_cost, false),
+                    };
+
+                    if insertion_cost - removal_gain < -MIN_IMPROVEMENT {
+                        return Some(Self::move_segment(stops, start, end, position, reverse));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn move_segment(
+        stops: &[Stop],
+        start: usize,
+        end: usize,
+        position: usize,
+        reverse: bool,
+    ) -> Vec<Stop> {
+        let mut segment = stops[start..=end].to_vec();
+
+        if reverse {
+            segment.reverse();
+        }
+
+        let mut moved_stops: Vec<Stop> = Vec::with_capacity(stops.len());
+
+        for (index, stop) in stops.iter().enumerate() {
+            if (start..=end).contains(&index) {
+                continue;
+            }
+
+            moved_stops.push(*stop);
+
+            if index == position {
+                moved_stops.extend_from_slice(&segment);
+            }
+        }
+
+        moved_stops
+    }
+}
+
+impl LocalSearcher<VrpSolution> for LinKernighanSearcher {
+    fn run(&self, solution: &mut VrpSolution) {
+        for route in solution.routes.values_mut() {
+            self.run(route);
+        }
+
+        solution.total_distance = solution
+            .routes
+            .values()
+            .map(|route| route.total_distance())
+            .sum();
+    }
+}
@@ -1,3 +1,13 @@
+pub mod double_bridge_shaker;
+pub mod inter_route;
+pub mod lin_kernighan;
 pub mod local_searcher;
+pub mod random_swap_shaker;
+mod segment_costs;
+pub mod segment_swap_shaker;
+pub mod shaker;
+pub mod swap_star;
 mod tests;
 pub mod two_opt;
+pub mod variable_neighborhood_descent;
+pub mod variable_neighborhood_search;
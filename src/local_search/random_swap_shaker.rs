@@ -0,0 +1,63 @@
+use std::{cell::RefCell, ops::DerefMut, rc::Rc};
+
+use rand::{seq::IteratorRandom, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::vrp_solution::VrpSolution,
+    stop_swapper::{neighborhood::Neighborhood, StopSwapper},
+};
+
+use super::shaker::Shaker;
+
+pub struct RandomSwapShaker<R: Rng + ?Sized> {
+    stop_swapper: StopSwapper,
+    rng: RefCell<R>,
+}
+
+impl<R: Rng> RandomSwapShaker<R> {
+    pub fn new(distance_service: Rc<DistanceService>, rng: R) -> Self {
+        Self {
+            rng: RefCell::new(rng),
+            stop_swapper: StopSwapper { distance_service },
+        }
+    }
+}
+
+impl<R: Rng + ?Sized> Shaker<VrpSolution> for RandomSwapShaker<R> {
+    fn shake(&self, solution: &mut VrpSolution, intensity: usize) {
+        let mut rng = self.rng.borrow_mut();
+        let distance_service = self.stop_swapper.distance_service.as_ref();
+
+        for _ in 0..intensity {
+            let route = match solution
+                .routes
+                .values_mut()
+                .filter(|route| route.stops.len() > 3)
+                .choose(rng.deref_mut())
+            {
+                Some(route) => route,
+                None => break,
+            };
+
+            let stop_indexes = (1..route.stops.len() - 1).choose_multiple(rng.deref_mut(), 2);
+
+            let neighborhood1 =
+                Neighborhood::from((route.stops.as_slice(), stop_indexes[0], distance_service));
+            let neighborhood2 =
+                Neighborhood::from((route.stops.as_slice(), stop_indexes[1], distance_service));
+
+            let swap_cost = self
+                .stop_swapper
+                .calculate_swap_cost(&neighborhood1, &neighborhood2);
+
+            route.swap_stops(stop_indexes[0], stop_indexes[1], swap_cost);
+        }
+
+        solution.total_distance = solution
+            .routes
+            .values()
+            .map(|route| route.total_distance())
+            .sum();
+    }
+}
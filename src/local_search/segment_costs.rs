@@ -0,0 +1,34 @@
+use crate::{domain::stop::Stop, services::distance::distance_service::DistanceService};
+
+pub(super) struct SegmentCosts {
+    forward: Vec<f32>,
+    backward: Vec<f32>,
+}
+
+impl SegmentCosts {
+    pub(super) fn new(stops: &[Stop], distance_service: &DistanceService) -> Self {
+        let mut forward = vec![0.0; stops.len()];
+        let mut backward = vec![0.0; stops.len()];
+
+        for index in 1..stops.len() {
+            forward[index] = forward[index - 1]
+                + distance_service.get_distance(&stops[index - 1], &stops[index]);
+            backward[index] = backward[index - 1]
+                + distance_service.get_distance(&stops[index], &stops[index - 1]);
+        }
+
+        Self { forward, backward }
+    }
+
+    pub(super) fn get_forward_cost(&self, start: usize, end: usize) -> f32 {
+        self.forward[end] - self.forward[start]
+    }
+
+    pub(super) fn get_backward_cost(&self, start: usize, end: usize) -> f32 {
+        self.backward[end] - self.backward[start]
+    }
+
+    pub(super) fn get_reversal_change(&self, start: usize, end: usize) -> f32 {
+        self.get_backward_cost(start, end) - self.get_forward_cost(start, end)
+    }
+}
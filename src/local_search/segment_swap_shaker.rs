@@ -0,0 +1,112 @@
+use std::{cell::RefCell, cmp::min, ops::DerefMut, rc::Rc};
+
+use rand::{seq::IteratorRandom, Rng};
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::distance::distance_service::DistanceService,
+    solvers::vrp_solution::VrpSolution,
+};
+
+use super::shaker::Shaker;
+
+type Segment = (usize, usize);
+
+pub struct SegmentSwapShaker<R: Rng + ?Sized> {
+    max_segment_size: usize,
+    distance_service: Rc<DistanceService>,
+    rng: RefCell<R>,
+}
+
+impl<R: Rng> SegmentSwapShaker<R> {
+    pub fn new(distance_service: Rc<DistanceService>, max_segment_size: usize, rng: R) -> Self {
+        Self {
+            distance_service,
+            max_segment_size,
+            rng: RefCell::new(rng),
+        }
+    }
+}
+
+impl<R: Rng + ?Sized> SegmentSwapShaker<R> {
+    fn choose_segment(&self, route: &Route, rng: &mut R) -> Segment {
+        let number_of_customers = route.stops.len() - 2;
+        let segment_size = rng.gen_range(1..=min(self.max_segment_size, number_of_customers));
+        let start = rng.gen_range(1..=number_of_customers - segment_size + 1);
+
+        (start, start + segment_size)
+    }
+
+    pub(super) fn swap_segments(
+        stops1: &[Stop],
+        segment1: Segment,
+        stops2: &[Stop],
+        segment2: Segment,
+    ) -> (Vec<Stop>, Vec<Stop>) {
+        let splice = |stops: &[Stop], segment: Segment, replacement: &[Stop]| -> Vec<Stop> {
+            let mut spliced_stops = stops[..segment.0].to_vec();
+            spliced_stops.extend_from_slice(replacement);
+            spliced_stops.extend_from_slice(&stops[segment.1..]);
+            spliced_stops
+        };
+
+        (
+            splice(stops1, segment1, &stops2[segment2.0..segment2.1]),
+            splice(stops2, segment2, &stops1[segment1.0..segment1.1]),
+        )
+    }
+}
+
+impl<R: Rng + ?Sized> Shaker<VrpSolution> for SegmentSwapShaker<R> {
+    fn shake(&self, solution: &mut VrpSolution, intensity: usize) {
+        if self.max_segment_size == 0 {
+            return;
+        }
+
+        let mut rng = self.rng.borrow_mut();
+
+        for _ in 0..intensity {
+            let vehicle_ids = solution
+                .routes
+                .iter()
+                .filter(|(_, route)| route.stops.len() > 2)
+                .map(|(vehicle_id, _)| *vehicle_id)
+                .choose_multiple(rng.deref_mut(), 2);
+
+            if vehicle_ids.len() < 2 {
+                break;
+            }
+
+            let route1 = &solution.routes[&vehicle_ids[0]];
+            let route2 = &solution.routes[&vehicle_ids[1]];
+
+            let segment1 = self.choose_segment(route1, rng.deref_mut());
+            let segment2 = self.choose_segment(route2, rng.deref_mut());
+
+            let (stops1, stops2) =
+                Self::swap_segments(&route1.stops, segment1, &route2.stops, segment2);
+
+            let mut swapped_route1 = route1.clone();
+            let mut swapped_route2 = route2.clone();
+
+            if swapped_route1
+                .replace_stops(stops1, &self.distance_service)
+                .is_err()
+                || swapped_route2
+                    .replace_stops(stops2, &self.distance_service)
+                    .is_err()
+            {
+                continue;
+            }
+
+            solution.routes.insert(vehicle_ids[0], swapped_route1);
+            solution.routes.insert(vehicle_ids[1], swapped_route2);
+        }
+
+        solution.total_distance = solution
+            .routes
+            .values()
+            .map(|route| route.total_distance())
+            .sum();
+    }
+}
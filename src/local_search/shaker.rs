@@ -0,0 +1,3 @@
+pub trait Shaker<T> {
+    fn shake(&self, target: &mut T, intensity: usize);
+}
@@ -0,0 +1,336 @@
+use std::{f32::consts::TAU, rc::Rc};
+
+use crate::{
+    domain::{
+        coordinate::{Coordinate, CoordinateMap},
+        route::Route,
+        stop::Stop,
+    },
+    services::distance::distance_service::DistanceService,
+    solvers::vrp_solution::VrpSolution,
+};
+
+use super::local_searcher::LocalSearcher;
+
+const MIN_IMPROVEMENT: f32 = 1e-4;
+const INSERTION_CANDIDATES: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct CircleSector {
+    start: f32,
+    end: f32,
+}
+
+impl CircleSector {
+    pub(super) fn new(angle: f32) -> Self {
+        let angle = angle.rem_euclid(TAU);
+
+        Self {
+            start: angle,
+            end: angle,
+        }
+    }
+
+    pub(super) fn contains(&self, angle: f32) -> bool {
+        (angle - self.start).rem_euclid(TAU) <= (self.end - self.start).rem_euclid(TAU)
+    }
+
+    pub(super) fn extend(&mut self, angle: f32) {
+        let angle = angle.rem_euclid(TAU);
+
+        if self.contains(angle) {
+            return;
+        }
+
+        match (angle - self.end).rem_euclid(TAU) <= (self.start - angle).rem_euclid(TAU) {
+            true => self.end = angle,
+            false => self.start = angle,
+        }
+    }
+
+    pub(super) fn overlaps(&self, other: &CircleSector) -> bool {
+        self.contains(other.start) || other.contains(self.start)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Insertion {
+    cost: f32,
+    after: usize,
+}
+
+#[derive(Clone, Copy)]
+struct SwapStarMove {
+    cost: f32,
+    first_index: usize,
+    second_index: usize,
+    first_insertion: usize,
+    second_insertion: usize,
+}
+
+pub struct SwapStarSearcher {
+    coordinates: CoordinateMap,
+    distance_service: Rc<DistanceService>,
+}
+
+impl SwapStarSearcher {
+    pub fn new(distance_service: Rc<DistanceService>, coordinates: CoordinateMap) -> Self {
+        Self {
+            coordinates,
+            distance_service,
+        }
+    }
+
+    pub fn run(&self, routes: &mut [Route]) {
+        loop {
+            let mut improved = false;
+
+            for first in 0..routes.len() {
+                for second in (first + 1)..routes.len() {
+                    let sectors = (
+                        self.calculate_sector(&routes[first]),
+                        self.calculate_sector(&routes[second]),
+                    );
+
+                    let overlapping = match sectors {
+                        (Some(sector1), Some(sector2)) => sector1.overlaps(&sector2),
+                        _ => false,
+                    };
+
+                    if !overlapping {
+                        continue;
+                    }
+
+                    let swap_star_move = match self.find_best_move(&routes[first], &routes[second])
+                    {
+                        Some(swap_star_move) => swap_star_move,
+                        None => continue,
+                    };
+
+                    let (head, tail) = routes.split_at_mut(second);
+                    self.apply(&mut head[first], &mut tail[0], &swap_star_move);
+                    improved = true;
+                }
+            }
+
+            if !improved {
+                break;
+            }
+        }
+    }
+
+    fn get_coordinate(&self, stop: &Stop) -> &Coordinate {
+        self.coordinates
+            .get(&stop.id)
+            .unwrap_or_else(|| panic!("the coordinates should contain the stop {}", stop.id))
+    }
+
+    pub(super) fn calculate_sector(&self, route: &Route) -> Option<CircleSector> {
+        if route.stops.len() < 3 {
+            return None;
+        }
+
+        let depot = self.get_coordinate(&route.stops[0]);
+        let customers = &route.stops[1..route.stops.len() - 1];
+
+        let mut sector =
+            CircleSector::new(self.get_coordinate(&customers[0]).get_polar_angle(depot));
+
+        for customer in customers.iter().skip(1) {
+            sector.extend(self.get_coordinate(customer).get_polar_angle(depot));
+        }
+
+        Some(sector)
+    }
+
+    fn distance(&self, from: &Stop, to: &Stop) -> f32 {
+        self.distance_service.get_distance(from, to)
+    }
+
+    fn calculate_removal_cost(&self, stops: &[Stop], index: usize) -> f32 {
+        self.distance(&stops[index - 1], &stops[index + 1])
+            - self.distance(&stops[index - 1], &stops[index])
+            - self.distance(&stops[index], &stops[index + 1])
+    }
+
+    fn calculate_insertion_cost(&self, stops: &[Stop], stop: &Stop, after: usize) -> f32 {
+        self.distance(&stops[after], stop) + self.distance(stop, &stops[after + 1])
+            - self.distance(&stops[after], &stops[after + 1])
+    }
+
+    fn list_best_insertions(&self, stops: &[Stop], stop: &Stop) -> Vec<Insertion> {
+        let mut insertions: Vec<Insertion> = (0..stops.len() - 1)
+            .map(|after| Insertion {
+                after,
+                cost: self.calculate_insertion_cost(stops, stop, after),
+            })
+            .collect();
+
+        insertions.sort_by(|insertion1, insertion2| insertion1.cost.total_cmp(&insertion2.cost));
+        insertions.truncate(INSERTION_CANDIDATES);
+
+        insertions
+    }
+
+    fn find_cheapest_insertion_replacing(
+        &self,
+        stops: &[Stop],
+        best_insertions: &[Insertion],
+        stop: &Stop,
+        replaced: usize,
+    ) -> Insertion {
+        let in_place = Insertion {
+            after: replaced - 1,
+            cost: self.distance(&stops[replaced - 1], stop)
+                + self.distance(stop, &stops[replaced + 1])
+                - self.distance(&stops[replaced - 1], &stops[replaced + 1]),
+        };
+
+        best_insertions
+            .iter()
+            .filter(|insertion| insertion.after != replaced && insertion.after + 1 != replaced)
+            .fold(in_place, |best, insertion| {
+                match insertion.cost < best.cost {
+                    true => *insertion,
+                    false => best,
+                }
+            })
+    }
+
+    fn calculate_load(route: &Route) -> u32 {
+        route.stops.iter().map(|stop| stop.usage).sum()
+    }
+
+    fn find_best_move(&self, route1: &Route, route2: &Route) -> Option<SwapStarMove> {
+        let stops1 = &route1.stops;
+        let stops2 = &route2.stops;
+
+        let load1 = Self::calculate_load(route1);
+        let load2 = Self::calculate_load(route2);
+
+        let best_insertions1: Vec<Vec<Insertion>> = (1..stops1.len() - 1)
+            .map(|index| self.list_best_insertions(stops2, &stops1[index]))
+            .collect();
+
+        let best_insertions2: Vec<Vec<Insertion>> = (1..stops2.len() - 1)
+            .map(|index| self.list_best_insertions(stops1, &stops2[index]))
+            .collect();
+
+        let mut best_move: Option<SwapStarMove> = None;
+
+        for first_index in 1..stops1.len() - 1 {
+            for second_index in 1..stops2.len() - 1 {
+                let stop1 = &stops1[first_index];
+                let stop2 = &stops2[second_index];
+
+                if load1 - stop1.usage + stop2.usage >= route1.vehicle.capacity
+                    || load2 - stop2.usage + stop1.usage >= route2.vehicle.capacity
+                {
+                    continue;
+                }
+
+                let first_insertion = self.find_cheapest_insertion_replacing(
+                    stops2,
+                    &best_insertions1[first_index - 1],
+                    stop1,
+                    second_index,
+                );
+
+                let second_insertion = self.find_cheapest_insertion_replacing(
+                    stops1,
+                    &best_insertions2[second_index - 1],
+                    stop2,
+                    first_index,
+                );
+
+                let cost = self.calculate_removal_cost(stops1, first_index)
+                    + self.calculate_removal_cost(stops2, second_index)
+                    + first_insertion.cost
+                    + second_insertion.cost;
+
+                if cost >= -MIN_IMPROVEMENT {
+                    continue;
+                }
+
+                match best_move {
+                    Some(swap_star_move) if swap_star_move.cost <= cost => (),
+                    _ => {
+                        best_move = Some(SwapStarMove {
+                            cost,
+                            first_index,
+                            second_index,
+                            first_insertion: first_insertion.after,
+                            second_insertion: second_insertion.after,
+                        })
+                    }
+                }
+            }
+        }
+
+        best_move
+    }
+
+    fn rebuild_stops(stops: &[Stop], removed: usize, inserted: Stop, after: usize) -> Vec<Stop> {
+        let mut new_stops = Vec::with_capacity(stops.len());
+
+        for (index, stop) in stops.iter().enumerate() {
+            if index != removed {
+                new_stops.push(*stop);
+            }
+
+            if index == after {
+                new_stops.push(inserted);
+            }
+        }
+
+        new_stops
+    }
+
+    fn apply(&self, route1: &mut Route, route2: &mut Route, swap_star_move: &SwapStarMove) {
+        let stop1 = route1.stops[swap_star_move.first_index];
+        let stop2 = route2.stops[swap_star_move.second_index];
+
+        let new_stops1 = Self::rebuild_stops(
+            &route1.stops,
+            swap_star_move.first_index,
+            stop2,
+            swap_star_move.second_insertion,
+        );
+
+        let new_stops2 = Self::rebuild_stops(
+            &route2.stops,
+            swap_star_move.second_index,
+            stop1,
+            swap_star_move.first_insertion,
+        );
+
+        for (route, stops) in [(route1, new_stops1), (route2, new_stops2)] {
+            route
+                .replace_stops(stops, &self.distance_service)
+                .unwrap_or_else(|_| {
+                    panic!(
+                        "the vehicle {} should support the swapped load",
+                        route.vehicle.id
+                    )
+                });
+        }
+    }
+}
+
+impl LocalSearcher<VrpSolution> for SwapStarSearcher {
+    fn run(&self, solution: &mut VrpSolution) {
+        let mut routes: Vec<Route> = solution.routes.values().cloned().collect();
+
+        self.run(&mut routes);
+
+        for route in routes {
+            solution.routes.insert(route.vehicle.id, route);
+        }
+
+        solution.total_distance = solution
+            .routes
+            .values()
+            .map(|route| route.total_distance())
+            .sum();
+    }
+}
@@ -0,0 +1,246 @@
+use std::rc::Rc;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    local_search::{
+        inter_route::{InterRouteMove, InterRouteSearcher},
+        local_searcher::LocalSearcher,
+    },
+    services::{
+        distance::distance_service::{DistanceMatrix, DistanceService},
+        route::route_service::RouteMap,
+    },
+    solvers::vrp_solution::VrpSolution,
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::{ids, stops};
+
+fn build_route(vehicle_id: u32, stops: Vec<Stop>, distance_service: &DistanceService) -> Route {
+    let mut route = Route::new(Vehicle::new(vehicle_id, 10));
+
+    route
+        .replace_stops(stops, distance_service)
+        .unwrap_or_else(|_| panic!("the vehicle {vehicle_id} should support the load"));
+
+    route
+}
+
+fn total_distance(routes: &[Route]) -> f32 {
+    routes.iter().map(|route| route.total_distance()).sum()
+}
+
+#[rstest]
+fn test_relocate_moves_a_stop_to_another_route(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let searcher =
+        InterRouteSearcher::with_moves(distance_service.clone(), vec![InterRouteMove::Relocate]);
+
+    let mut routes = vec![
+        build_route(0, vec![stops[0], stops[1], stops[0]], &distance_service),
+        build_route(
+            1,
+            vec![stops[0], stops[2], stops[3], stops[0]],
+            &distance_service,
+        ),
+    ];
+
+    searcher.run(&mut routes);
+
+    assert_eq!(ids(&routes[0].stops), vec![0, 0]);
+    assert_eq!(ids(&routes[1].stops), vec![0, 2, 3, 1, 0]);
+    assert_eq!(total_distance(&routes), 8.0);
+}
+
+#[rstest]
+fn test_exchange_swaps_stops_between_routes(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let searcher =
+        InterRouteSearcher::with_moves(distance_service.clone(), vec![InterRouteMove::Exchange]);
+
+    let mut routes = vec![
+        build_route(0, vec![stops[0], stops[3], stops[0]], &distance_service),
+        build_route(
+            1,
+            vec![stops[0], stops[1], stops[2], stops[0]],
+            &distance_service,
+        ),
+    ];
+
+    searcher.run(&mut routes);
+
+    assert_eq!(routes[0].stops.len(), 3);
+    assert_eq!(routes[1].stops.len(), 4);
+    assert_eq!(total_distance(&routes), 10.0);
+}
+
+#[rstest]
+fn test_two_opt_star_swaps_route_tails(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let searcher =
+        InterRouteSearcher::with_moves(distance_service.clone(), vec![InterRouteMove::TwoOptStar]);
+
+    let mut routes = vec![
+        build_route(0, vec![stops[0], stops[1], stops[0]], &distance_service),
+        build_route(
+            1,
+            vec![stops[0], stops[2], stops[3], stops[0]],
+            &distance_service,
+        ),
+    ];
+
+    searcher.run(&mut routes);
+
+    assert_eq!(ids(&routes[0].stops), vec![0, 0]);
+    assert_eq!(ids(&routes[1].stops), vec![0, 2, 3, 1, 0]);
+    assert_eq!(total_distance(&routes), 8.0);
+}
+
+#[rstest]
+fn test_cross_exchange_swaps_segments_between_routes() {
+    let positions: [f32; 5] = [0.0, -1.0, -2.0, 1.0, 2.0];
+    let stops: Vec<Stop> = (0..5).map(|id| Stop::new(id, 0)).collect();
+
+    let mut distances = DistanceMatrix::new();
+
+    for from in 0..5 {
+        for to in 0..5 {
+            if from != to {
+                distances.insert(
+                    (from, to),
+                    (positions[from as usize] - positions[to as usize]).abs(),
+                );
+            }
+        }
+    }
+
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let searcher = InterRouteSearcher::with_moves(
+        distance_service.clone(),
+        vec![InterRouteMove::CrossExchange],
+    )
+    .with_max_segment_length(2);
+
+    let mut routes = vec![
+        build_route(
+            0,
+            vec![stops[0], stops[3], stops[4], stops[1], stops[0]],
+            &distance_service,
+        ),
+        build_route(1, vec![stops[0], stops[2], stops[0]], &distance_service),
+    ];
+
+    assert_eq!(total_distance(&routes), 10.0);
+
+    searcher.run(&mut routes);
+
+    assert_eq!(ids(&routes[0].stops), vec![0, 2, 1, 0]);
+    assert_eq!(ids(&routes[1].stops), vec![0, 3, 4, 0]);
+    assert_eq!(total_distance(&routes), 8.0);
+}
+
+#[rstest]
+fn test_moves_respect_vehicle_capacity(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let loaded_stops = [stops[0], Stop::new(1, 5), Stop::new(2, 5), Stop::new(3, 4)];
+    let distance_service = Rc::new(DistanceService::new(stops, &distances));
+    let searcher = InterRouteSearcher::new(distance_service.clone());
+
+    let mut routes = vec![
+        build_route(
+            0,
+            vec![loaded_stops[0], loaded_stops[1], loaded_stops[0]],
+            &distance_service,
+        ),
+        build_route(
+            1,
+            vec![
+                loaded_stops[0],
+                loaded_stops[2],
+                loaded_stops[3],
+                loaded_stops[0],
+            ],
+            &distance_service,
+        ),
+    ];
+
+    searcher.run(&mut routes);
+
+    assert_eq!(total_distance(&routes), 10.0);
+
+    for route in routes.iter() {
+        let load: u32 = route.stops.iter().map(|stop| stop.usage).sum();
+        assert!(load < route.vehicle.capacity);
+    }
+}
+
+#[rstest]
+fn test_can_improve_solutions_as_local_searcher(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let searcher = InterRouteSearcher::new(distance_service.clone());
+
+    let routes: RouteMap = [
+        build_route(0, vec![stops[0], stops[1], stops[0]], &distance_service),
+        build_route(
+            1,
+            vec![stops[0], stops[2], stops[3], stops[0]],
+            &distance_service,
+        ),
+    ]
+    .into_iter()
+    .map(|route| (route.vehicle.id, route))
+    .collect();
+
+    let mut solution = VrpSolution::new(&routes, 10.0);
+
+    LocalSearcher::run(&searcher, &mut solution);
+
+    assert_eq!(solution.total_distance, 8.0);
+    assert_eq!(solution.routes.len(), 2);
+}
+
+#[rstest]
+fn test_mutation_keeps_every_stop_and_the_route_distances(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let searcher = InterRouteSearcher::new(distance_service.clone());
+    let mut rng = ChaCha8Rng::seed_from_u64(7);
+
+    let mut routes = vec![
+        build_route(
+            0,
+            vec![stops[0], stops[1], stops[2], stops[0]],
+            &distance_service,
+        ),
+        build_route(1, vec![stops[0], stops[3], stops[0]], &distance_service),
+    ];
+
+    for _ in 0..10 {
+        assert!(searcher.mutate(&mut routes, &mut rng));
+
+        let mut customer_ids: Vec<u32> = routes
+            .iter()
+            .flat_map(|route| route.stops.iter())
+            .filter(|stop| stop.id != 0)
+            .map(|stop| stop.id)
+            .collect();
+        customer_ids.sort();
+
+        assert_eq!(customer_ids, vec![1, 2, 3]);
+
+        for route in routes.iter() {
+            let expected_distance: f32 = route
+                .stops
+                .windows(2)
+                .map(|window| distance_service.get_distance(&window[0], &window[1]))
+                .sum();
+
+            assert_eq!(route.total_distance(), expected_distance);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::rc::Rc;
+
+use rstest::rstest;
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    local_search::lin_kernighan::LinKernighanSearcher,
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::stops_fixture::{ids, stops, stops_with_crossings};
+
+#[rstest]
+fn test_lin_kernighan_untangles_crossing_routes(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops, &distances));
+    let searcher = LinKernighanSearcher::new(distance_service, 4, 3);
+
+    let mut route = route_factory(stops_with_crossings);
+
+    searcher.run(&mut route);
+
+    assert_eq!(route.stops.first().unwrap().id, 0);
+    assert_eq!(route.stops.last().unwrap().id, 0);
+    assert_eq!(route.stops.len(), 6);
+    assert_eq!(route.total_distance(), 9.5);
+}
+
+#[rstest]
+fn test_lin_kernighan_respects_asymmetric_distances() {
+    let stops: Vec<Stop> = (0..4).map(|id| Stop::new(id, 0)).collect();
+
+    let mut distances = DistanceMatrix::new();
+
+    for from in 0..4 {
+        for to in 0..4 {
+            if from != to {
+                let distance = match (to + 4 - from) % 4 == 1 {
+                    true => 1.0,
+                    false => 10.0,
+                };
+
+                distances.insert((from, to), distance);
+            }
+        }
+    }
+
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let searcher = LinKernighanSearcher::new(distance_service.clone(), 3, 3);
+
+    let mut route = Route::new(Vehicle::new(0, 10));
+    route
+        .replace_stops(
+            vec![stops[0], stops[3], stops[2], stops[1], stops[0]],
+            &distance_service,
+        )
+        .unwrap();
+
+    assert_eq!(route.total_distance(), 40.0);
+
+    searcher.run(&mut route);
+
+    assert_eq!(ids(&route.stops), vec![0, 1, 2, 3, 0]);
+    assert_eq!(route.total_distance(), 4.0);
+}
@@ -1,2 +1,14 @@
 #[cfg(test)]
+mod inter_route_test;
+#[cfg(test)]
+mod lin_kernighan_test;
+#[cfg(test)]
+mod perturbation_shakers_test;
+#[cfg(test)]
+mod swap_star_test;
+#[cfg(test)]
 pub mod two_opt_test;
+#[cfg(test)]
+mod variable_neighborhood_descent_test;
+#[cfg(test)]
+mod variable_neighborhood_search_test;
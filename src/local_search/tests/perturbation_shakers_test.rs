@@ -0,0 +1,82 @@
+use std::rc::Rc;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::local_search::{
+    double_bridge_shaker::DoubleBridgeShaker, segment_swap_shaker::SegmentSwapShaker,
+    shaker::Shaker,
+};
+use crate::services::distance::distance_service::DistanceService;
+use crate::solvers::{greedy::vrp_greedy_solver::VrpGreedySolver, solver::Solver};
+
+use crate::tests::fixtures::greedy_solver_fixture::{greedy_solver_factory, GreedySolverFactory};
+use crate::tests::fixtures::services_fixture::distance_service;
+use crate::tests::fixtures::stops_fixture::{ids, stops};
+
+#[rstest]
+fn test_double_bridge_reconnects_the_middle_segments(stops: Vec<Stop>) {
+    let route = vec![stops[0], stops[1], stops[2], stops[3], stops[4], stops[0]];
+
+    let bridged = DoubleBridgeShaker::<ChaCha8Rng>::double_bridge(&route, [2, 3, 5]);
+
+    assert_eq!(ids(&bridged), vec![0, 1, 3, 4, 2, 0]);
+}
+
+#[rstest]
+fn test_segment_swap_exchanges_segments_between_routes(stops: Vec<Stop>) {
+    let route1 = vec![stops[0], stops[1], stops[2], stops[0]];
+    let route2 = vec![stops[0], stops[3], stops[4], stops[0]];
+
+    let (swapped1, swapped2) =
+        SegmentSwapShaker::<ChaCha8Rng>::swap_segments(&route1, (1, 3), &route2, (2, 3));
+
+    assert_eq!(ids(&swapped1), vec![0, 4, 0]);
+    assert_eq!(ids(&swapped2), vec![0, 3, 1, 2, 0]);
+}
+
+#[rstest]
+fn test_perturbations_keep_the_solution_consistent(
+    distance_service: DistanceService,
+    greedy_solver_factory: GreedySolverFactory,
+) {
+    let distance_service = Rc::new(distance_service);
+
+    let shakers: Vec<Box<dyn Shaker<_>>> = vec![
+        Box::new(DoubleBridgeShaker::new(
+            distance_service.clone(),
+            ChaCha8Rng::seed_from_u64(0),
+        )),
+        Box::new(SegmentSwapShaker::new(
+            distance_service.clone(),
+            2,
+            ChaCha8Rng::seed_from_u64(0),
+        )),
+    ];
+
+    for shaker in shakers {
+        let mut greedy_solver: VrpGreedySolver = greedy_solver_factory(2);
+        let mut solution = greedy_solver.solve();
+
+        shaker.shake(&mut solution, 5);
+
+        let mut visited_stops: Vec<u32> = solution
+            .routes
+            .values()
+            .flat_map(|route| ids(&route.stops[1..route.stops.len() - 1]))
+            .collect();
+        visited_stops.sort_unstable();
+
+        let expected_distance: f32 = solution
+            .routes
+            .values()
+            .flat_map(|route| route.stops.windows(2))
+            .map(|window| distance_service.get_distance(&window[0], &window[1]))
+            .sum();
+
+        assert_eq!(visited_stops, vec![1, 2, 3]);
+        assert_eq!(solution.total_distance, expected_distance);
+    }
+}
@@ -0,0 +1,189 @@
+use std::{f32::consts::PI, rc::Rc};
+
+use rstest::{fixture, rstest};
+
+use crate::{
+    domain::{
+        coordinate::{Coordinate, CoordinateMap},
+        route::Route,
+        stop::Stop,
+        vehicle::Vehicle,
+    },
+    local_search::swap_star::{CircleSector, SwapStarSearcher},
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
+    tests::fixtures::stops_fixture::ids,
+};
+
+fn build_route(vehicle: Vehicle, stops: Vec<Stop>, distance_service: &DistanceService) -> Route {
+    let vehicle_id = vehicle.id;
+    let mut route = Route::new(vehicle);
+
+    route
+        .replace_stops(stops, distance_service)
+        .unwrap_or_else(|_| panic!("the vehicle {vehicle_id} should support the load"));
+
+    route
+}
+
+fn sorted_customer_ids(route: &Route) -> Vec<u32> {
+    let mut customer_ids: Vec<u32> = route.stops[1..route.stops.len() - 1]
+        .iter()
+        .map(|stop| stop.id)
+        .collect();
+
+    customer_ids.sort();
+    customer_ids
+}
+
+#[fixture]
+fn coordinates() -> CoordinateMap {
+    CoordinateMap::from([
+        (0, Coordinate::new(0.0, 0.0)),
+        (1, Coordinate::new(-1.0, 0.5)),
+        (2, Coordinate::new(-2.0, 0.5)),
+        (3, Coordinate::new(1.0, 0.5)),
+        (4, Coordinate::new(2.0, 0.5)),
+    ])
+}
+
+fn build_distances(coordinates: &CoordinateMap) -> DistanceMatrix {
+    coordinates
+        .iter()
+        .flat_map(|(from, origin)| {
+            coordinates.iter().map(move |(to, destination)| {
+                let distance = ((destination.x - origin.x).powi(2)
+                    + (destination.y - origin.y).powi(2))
+                .sqrt();
+
+                ((*from, *to), distance)
+            })
+        })
+        .collect()
+}
+
+#[rstest]
+fn test_circle_sectors_detect_overlaps() {
+    let mut sector1 = CircleSector::new(0.1);
+    sector1.extend(0.5);
+
+    let mut sector2 = CircleSector::new(0.4);
+    sector2.extend(1.0);
+
+    let mut sector3 = CircleSector::new(PI);
+    sector3.extend(PI + 0.2);
+
+    assert!(sector1.contains(0.3));
+    assert!(sector1.overlaps(&sector2));
+    assert!(sector2.overlaps(&sector1));
+    assert!(!sector1.overlaps(&sector3));
+    assert!(!sector3.overlaps(&sector2));
+}
+
+#[rstest]
+fn test_circle_sectors_wrap_around_the_origin() {
+    let mut sector = CircleSector::new(-0.2);
+    sector.extend(0.2);
+
+    assert!(sector.contains(0.0));
+    assert!(!sector.contains(PI));
+    assert!(sector.overlaps(&CircleSector::new(0.1)));
+}
+
+#[rstest]
+fn test_swap_star_exchanges_stops_between_overlapping_routes(coordinates: CoordinateMap) {
+    let stops: Vec<Stop> = (0..5).map(|id| Stop::new(id, 1)).collect();
+    let distances = build_distances(&coordinates);
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let searcher = SwapStarSearcher::new(distance_service.clone(), coordinates);
+
+    let mut routes = vec![
+        build_route(
+            Vehicle::new(0, 10),
+            vec![stops[0], stops[1], stops[3], stops[0]],
+            &distance_service,
+        ),
+        build_route(
+            Vehicle::new(1, 10),
+            vec![stops[0], stops[2], stops[4], stops[0]],
+            &distance_service,
+        ),
+    ];
+
+    let initial_distance: f32 = routes.iter().map(|route| route.total_distance()).sum();
+
+    searcher.run(&mut routes);
+
+    let final_distance: f32 = routes.iter().map(|route| route.total_distance()).sum();
+
+    let mut groups = vec![
+        sorted_customer_ids(&routes[0]),
+        sorted_customer_ids(&routes[1]),
+    ];
+    groups.sort();
+
+    assert!(final_distance < initial_distance);
+    assert_eq!(groups, vec![vec![1, 2], vec![3, 4]]);
+}
+
+#[rstest]
+fn test_swap_star_respects_vehicle_capacity(coordinates: CoordinateMap) {
+    let stops = vec![
+        Stop::new(0, 0),
+        Stop::new(1, 1),
+        Stop::new(2, 1),
+        Stop::new(3, 2),
+        Stop::new(4, 2),
+    ];
+    let distances = build_distances(&coordinates);
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let searcher = SwapStarSearcher::new(distance_service.clone(), coordinates);
+
+    let mut routes = vec![
+        build_route(
+            Vehicle::new(0, 4),
+            vec![stops[0], stops[1], stops[3], stops[0]],
+            &distance_service,
+        ),
+        build_route(
+            Vehicle::new(1, 4),
+            vec![stops[0], stops[2], stops[4], stops[0]],
+            &distance_service,
+        ),
+    ];
+
+    searcher.run(&mut routes);
+
+    for route in routes.iter() {
+        let load: u32 = route.stops.iter().map(|stop| stop.usage).sum();
+        assert!(load < route.vehicle.capacity);
+    }
+
+    assert_eq!(ids(&routes[0].stops), vec![0, 1, 3, 0]);
+    assert_eq!(ids(&routes[1].stops), vec![0, 2, 4, 0]);
+}
+
+#[rstest]
+fn test_swap_star_skips_routes_in_disjoint_sectors(coordinates: CoordinateMap) {
+    let stops: Vec<Stop> = (0..5).map(|id| Stop::new(id, 1)).collect();
+    let distances = build_distances(&coordinates);
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let searcher = SwapStarSearcher::new(distance_service.clone(), coordinates);
+
+    let routes = [
+        build_route(
+            Vehicle::new(0, 10),
+            vec![stops[0], stops[2], stops[1], stops[0]],
+            &distance_service,
+        ),
+        build_route(
+            Vehicle::new(1, 10),
+            vec![stops[0], stops[3], stops[4], stops[0]],
+            &distance_service,
+        ),
+    ];
+
+    let sector1 = searcher.calculate_sector(&routes[0]).unwrap();
+    let sector2 = searcher.calculate_sector(&routes[1]).unwrap();
+
+    assert!(!sector1.overlaps(&sector2));
+}
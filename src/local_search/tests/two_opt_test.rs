@@ -1,6 +1,10 @@
+use std::rc::Rc;
+
+use crate::domain::{route::Route, vehicle::Vehicle};
 use crate::stop_swapper::neighborhood::Neighborhood;
 use crate::{
-    local_search::two_opt::TwoOptSearcher, services::distance::distance_service::DistanceService,
+    local_search::two_opt::{ImprovementStrategy, TwoOptSearcher},
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
 };
 use rstest::rstest;
 
@@ -31,7 +35,7 @@ fn can_optimize_route(
 
     two_opt.run(&mut route);
 
-    assert_eq!(route.stops.get(0).unwrap().id, 0);
+    assert_eq!(route.stops.first().unwrap().id, 0);
     assert_eq!(route.stops.get(1).unwrap().id, 1);
     assert_eq!(route.stops.get(2).unwrap().id, 3);
     assert_eq!(route.stops.get(3).unwrap().id, 2);
@@ -40,3 +44,61 @@ fn can_optimize_route(
 
     assert_eq!(route.total_distance(), 9.5);
 }
+
+#[rstest]
+fn can_reverse_segments_with_best_improvement(
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+    distance_service: DistanceService,
+) {
+    let two_opt = TwoOptSearcher::with_strategy(
+        Rc::new(distance_service),
+        ImprovementStrategy::BestImprovement,
+    );
+
+    let mut route = route_factory(stops_with_crossings);
+
+    two_opt.run(&mut route);
+
+    let ids: Vec<u32> = route.stops.iter().map(|stop| stop.id).collect();
+
+    assert_eq!(ids, vec![0, 4, 2, 3, 1, 0]);
+    assert_eq!(route.total_distance(), 9.5);
+}
+
+#[rstest]
+fn can_evaluate_reversals_on_asymmetric_distances() {
+    let stops: Vec<Stop> = (0..4).map(|id| Stop::new(id, 0)).collect();
+    let mut distances = DistanceMatrix::new();
+
+    for from in 0..4 {
+        for to in 0..4 {
+            if from != to {
+                let distance = match (to + 4 - from) % 4 == 1 {
+                    true => 1.0,
+                    false => 10.0,
+                };
+
+                distances.insert((from, to), distance);
+            }
+        }
+    }
+
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let mut route = Route::new(Vehicle::new(0, 10));
+    route
+        .replace_stops(
+            vec![stops[0], stops[3], stops[2], stops[1], stops[0]],
+            &distance_service,
+        )
+        .unwrap();
+
+    TwoOptSearcher::with_strategy(distance_service, ImprovementStrategy::BestImprovement)
+        .run(&mut route);
+
+    let ids: Vec<u32> = route.stops.iter().map(|stop| stop.id).collect();
+
+    assert_eq!(ids, vec![0, 1, 2, 3, 0]);
+    assert_eq!(route.total_distance(), 4.0);
+}
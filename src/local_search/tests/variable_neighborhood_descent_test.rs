@@ -0,0 +1,65 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rstest::rstest;
+
+use crate::local_search::{
+    local_searcher::LocalSearcher, variable_neighborhood_descent::VariableNeighborhoodDescent,
+};
+use crate::solvers::vrp_solution::VrpSolution;
+
+type CallLog = Rc<RefCell<Vec<usize>>>;
+
+struct ScriptedSearcher {
+    id: usize,
+    call_log: CallLog,
+    improvements: RefCell<Vec<f32>>,
+}
+
+impl ScriptedSearcher {
+    fn new(id: usize, call_log: CallLog, improvements: Vec<f32>) -> Self {
+        Self {
+            id,
+            call_log,
+            improvements: RefCell::new(improvements),
+        }
+    }
+}
+
+impl LocalSearcher<VrpSolution> for ScriptedSearcher {
+    fn run(&self, solution: &mut VrpSolution) {
+        self.call_log.borrow_mut().push(self.id);
+
+        if let Some(improvement) = self.improvements.borrow_mut().pop() {
+            solution.total_distance -= improvement;
+        }
+    }
+}
+
+#[rstest]
+fn test_restarts_from_the_first_searcher_after_an_improvement() {
+    let call_log: CallLog = Default::default();
+
+    let vnd = VariableNeighborhoodDescent::new(vec![
+        Box::new(ScriptedSearcher::new(0, call_log.clone(), vec![])),
+        Box::new(ScriptedSearcher::new(1, call_log.clone(), vec![0.0, 2.0])),
+        Box::new(ScriptedSearcher::new(2, call_log.clone(), vec![0.0, 1.0])),
+    ]);
+
+    let mut solution = VrpSolution::new(&Default::default(), 10.0);
+
+    vnd.run(&mut solution);
+
+    assert_eq!(*call_log.borrow(), vec![0, 1, 0, 1, 2, 0, 1, 2]);
+    assert_eq!(solution.total_distance, 7.0);
+}
+
+#[rstest]
+fn test_does_nothing_without_searchers() {
+    let vnd: VariableNeighborhoodDescent<VrpSolution> = VariableNeighborhoodDescent::new(vec![]);
+
+    let mut solution = VrpSolution::new(&Default::default(), 10.0);
+
+    vnd.run(&mut solution);
+
+    assert_eq!(solution.total_distance, 10.0);
+}
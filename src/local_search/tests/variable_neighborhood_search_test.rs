@@ -0,0 +1,82 @@
+use std::rc::Rc;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::domain::stop::Stop;
+use crate::local_search::{
+    local_searcher::LocalSearcher, random_swap_shaker::RandomSwapShaker, shaker::Shaker,
+    two_opt::TwoOptSearcher, variable_neighborhood_search::VariableNeighborhoodSearch,
+};
+use crate::services::{
+    distance::distance_service::DistanceService, route::route_service::RouteMap,
+};
+use crate::solvers::vrp_solution::VrpSolution;
+
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::services_fixture::distance_service;
+use crate::tests::fixtures::stops_fixture::stops_with_crossings;
+
+fn solution_from_stops(route_factory: RouteFactory, stops: Vec<Stop>) -> VrpSolution {
+    let route = route_factory(stops);
+    let total_distance = route.total_distance();
+    let routes = RouteMap::from([(route.vehicle.id, route)]);
+
+    VrpSolution::new(&routes, total_distance)
+}
+
+#[rstest]
+fn test_shaker_keeps_distance_consistent(
+    distance_service: DistanceService,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let distance_service = Rc::new(distance_service);
+    let shaker = RandomSwapShaker::new(distance_service.clone(), ChaCha8Rng::seed_from_u64(0));
+
+    let mut solution = solution_from_stops(route_factory, stops_with_crossings);
+
+    shaker.shake(&mut solution, 3);
+
+    let stops = &solution.routes[&0].stops;
+    let expected_distance: f32 = stops
+        .windows(2)
+        .map(|window| distance_service.get_distance(&window[0], &window[1]))
+        .sum();
+
+    assert_eq!(solution.total_distance, expected_distance);
+    assert_eq!(stops.first().unwrap().id, 0);
+    assert_eq!(stops.last().unwrap().id, 0);
+}
+
+#[rstest]
+fn test_vns_is_not_worse_than_its_local_searcher(
+    distance_service: DistanceService,
+    route_factory: RouteFactory,
+    stops_with_crossings: Vec<Stop>,
+) {
+    let distance_service = Rc::new(distance_service);
+
+    let mut two_opt_solution = solution_from_stops(route_factory, stops_with_crossings);
+    let mut vns_solution = two_opt_solution.clone();
+
+    LocalSearcher::run(
+        &TwoOptSearcher::new(distance_service.clone()),
+        &mut two_opt_solution,
+    );
+
+    let vns = VariableNeighborhoodSearch::new(
+        5,
+        3,
+        Box::new(RandomSwapShaker::new(
+            distance_service.clone(),
+            ChaCha8Rng::seed_from_u64(0),
+        )),
+        Box::new(TwoOptSearcher::new(distance_service)),
+    );
+
+    vns.run(&mut vns_solution);
+
+    assert!(vns_solution.total_distance <= two_opt_solution.total_distance);
+}
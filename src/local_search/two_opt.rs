@@ -1,64 +1,102 @@
 use std::rc::Rc;
 
 use crate::{
-    domain::route::Route,
+    domain::{route::Route, stop::Stop},
     services::distance::distance_service::DistanceService,
     solvers::vrp_solution::VrpSolution,
-    stop_swapper::{neighborhood::Neighborhood, StopSwapper},
 };
 
-use super::local_searcher::LocalSearcher;
+use super::{local_searcher::LocalSearcher, segment_costs::SegmentCosts};
+
+const MIN_IMPROVEMENT: f32 = 1e-4;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImprovementStrategy {
+    FirstImprovement,
+    BestImprovement,
+}
 
 pub struct TwoOptSearcher {
-    stop_swapper: StopSwapper,
+    is_symmetric: bool,
+    strategy: ImprovementStrategy,
     distance_service: Rc<DistanceService>,
 }
 
 impl TwoOptSearcher {
     pub fn new(distance_service: Rc<DistanceService>) -> Self {
+        Self::with_strategy(distance_service, ImprovementStrategy::FirstImprovement)
+    }
+
+    pub fn with_strategy(
+        distance_service: Rc<DistanceService>,
+        strategy: ImprovementStrategy,
+    ) -> Self {
         Self {
-            distance_service: distance_service.clone(),
-            stop_swapper: StopSwapper { distance_service },
+            strategy,
+            is_symmetric: distance_service.is_symmetric(),
+            distance_service,
         }
     }
 
-    pub fn run(&self, route: &mut Route) {
-        if route.stops.len() < 3 {
-            return;
+    pub(super) fn calculate_reversal_cost(
+        &self,
+        stops: &[Stop],
+        segment_costs: Option<&SegmentCosts>,
+        start: usize,
+        end: usize,
+    ) -> f32 {
+        let before = &stops[start - 1];
+        let after = &stops[end + 1];
+
+        let boundary_cost = self.distance_service.get_distance(before, &stops[end])
+            + self.distance_service.get_distance(&stops[start], after)
+            - self.distance_service.get_distance(before, &stops[start])
+            - self.distance_service.get_distance(&stops[end], after);
+
+        match segment_costs {
+            Some(segment_costs) => boundary_cost + segment_costs.get_reversal_change(start, end),
+            None => boundary_cost,
         }
+    }
+
+    fn find_reversal(&self, stops: &[Stop]) -> Option<(usize, usize, f32)> {
+        let segment_costs = match self.is_symmetric {
+            true => None,
+            false => Some(SegmentCosts::new(stops, &self.distance_service)),
+        };
+
+        let mut best_reversal: Option<(usize, usize, f32)> = None;
+
+        for start in 1..stops.len() - 2 {
+            for end in (start + 1)..stops.len() - 1 {
+                let cost = self.calculate_reversal_cost(stops, segment_costs.as_ref(), start, end);
 
-        loop {
-            let mut found_improvement = false;
-
-            for stop_index_1 in 1..route.stops.len() - 2 {
-                for stop_index_2 in (stop_index_1 + 1)..route.stops.len() - 1 {
-                    let neighborhood_1 = Neighborhood::from((
-                        route.stops.as_slice(),
-                        stop_index_1,
-                        self.distance_service.as_ref(),
-                    ));
-
-                    let neighborhood_2 = Neighborhood::from((
-                        route.stops.as_slice(),
-                        stop_index_2,
-                        self.distance_service.as_ref(),
-                    ));
-
-                    let swap_cost = self
-                        .stop_swapper
-                        .calculate_swap_cost(&neighborhood_1, &neighborhood_2);
-
-                    if swap_cost < 0.0 {
-                        route.swap_stops(stop_index_1, stop_index_2, swap_cost);
-                        found_improvement = true;
-                    }
+                if cost >= -MIN_IMPROVEMENT {
+                    continue;
+                }
+
+                if self.strategy == ImprovementStrategy::FirstImprovement {
+                    return Some((start, end, cost));
                 }
-            }
 
-            if !found_improvement {
-                break;
+                match best_reversal {
+                    Some((_, _, best_cost)) if best_cost <= cost => (),
+                    _ => best_reversal = Some((start, end, cost)),
+                }
             }
         }
+
+        best_reversal
+    }
+
+    pub fn run(&self, route: &mut Route) {
+        if route.stops.len() < 4 {
+            return;
+        }
+
+        while let Some((start, end, cost)) = self.find_reversal(&route.stops) {
+            route.reverse_stops(start, end, cost);
+        }
     }
 }
 
@@ -70,8 +108,8 @@ impl LocalSearcher<VrpSolution> for TwoOptSearcher {
 
         solution.total_distance = solution
             .routes
-            .iter()
-            .map(|(_, route)| route.total_distance())
+            .values()
+            .map(|route| route.total_distance())
             .sum();
     }
 }
@@ -0,0 +1,31 @@
+use crate::solvers::solution::Solution;
+
+use super::local_searcher::LocalSearcher;
+
+pub struct VariableNeighborhoodDescent<S: Solution> {
+    local_searchers: Vec<Box<dyn LocalSearcher<S>>>,
+}
+
+impl<S: Solution> VariableNeighborhoodDescent<S> {
+    pub fn new(local_searchers: Vec<Box<dyn LocalSearcher<S>>>) -> Self {
+        Self { local_searchers }
+    }
+}
+
+impl<S: Solution> LocalSearcher<S> for VariableNeighborhoodDescent<S> {
+    fn run(&self, target: &mut S) {
+        let mut searcher_index = 0;
+
+        while let Some(local_searcher) = self.local_searchers.get(searcher_index) {
+            let cost_before = target.get_cost();
+
+            local_searcher.run(target);
+
+            if target.get_cost() < cost_before {
+                searcher_index = 0;
+            } else {
+                searcher_index += 1;
+            }
+        }
+    }
+}
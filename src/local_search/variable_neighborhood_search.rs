@@ -0,0 +1,50 @@
+use crate::solvers::solution::Solution;
+
+use super::{local_searcher::LocalSearcher, shaker::Shaker};
+
+pub struct VariableNeighborhoodSearch<S: Solution + Clone> {
+    max_iterations: u32,
+    max_neighborhood_size: usize,
+    shaker: Box<dyn Shaker<S>>,
+    local_searcher: Box<dyn LocalSearcher<S>>,
+}
+
+impl<S: Solution + Clone> VariableNeighborhoodSearch<S> {
+    pub fn new(
+        max_iterations: u32,
+        max_neighborhood_size: usize,
+        shaker: Box<dyn Shaker<S>>,
+        local_searcher: Box<dyn LocalSearcher<S>>,
+    ) -> Self {
+        Self {
+            shaker,
+            max_iterations,
+            local_searcher,
+            max_neighborhood_size,
+        }
+    }
+}
+
+impl<S: Solution + Clone> LocalSearcher<S> for VariableNeighborhoodSearch<S> {
+    fn run(&self, target: &mut S) {
+        self.local_searcher.run(target);
+
+        for _ in 0..self.max_iterations {
+            let mut neighborhood_size = 1;
+
+            while neighborhood_size <= self.max_neighborhood_size {
+                let mut candidate = target.clone();
+
+                self.shaker.shake(&mut candidate, neighborhood_size);
+                self.local_searcher.run(&mut candidate);
+
+                if candidate.is_better_than(target) {
+                    *target = candidate;
+                    neighborhood_size = 1;
+                } else {
+                    neighborhood_size += 1;
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,11 @@
 use std::{fs, iter::zip, str::Lines};
 
 use crate::{
-    domain::{stop::Stop, vehicle::Vehicle},
+    domain::{
+        coordinate::{Coordinate, CoordinateMap},
+        stop::Stop,
+        vehicle::Vehicle,
+    },
     parsers::vrp_parser::{VrpInputs, VrpParser},
     services::distance::distance_service::DistanceMatrix,
 };
@@ -59,6 +63,18 @@ impl CvrpLibParser {
             .collect()
     }
 
+    pub(super) fn generate_coordinates(nodes: &[Node]) -> CoordinateMap {
+        nodes
+            .iter()
+            .map(|node| {
+                (
+                    node.id,
+                    Coordinate::new(node.x_position as f32, node.y_position as f32),
+                )
+            })
+            .collect()
+    }
+
     pub(super) fn parse_header<'a>(lines: &mut Lines<'a>) -> Header<'a> {
         Header {
             name: Self::get_header_value(lines),
@@ -134,11 +150,13 @@ impl VrpParser for CvrpLibParser {
             .collect();
 
         let distances: DistanceMatrix = Self::generate_distance_matrix(&nodes);
+        let coordinates: CoordinateMap = Self::generate_coordinates(&nodes);
 
         VrpInputs {
             stops,
             vehicles,
             distances,
+            coordinates,
         }
     }
 }
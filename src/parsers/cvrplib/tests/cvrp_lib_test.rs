@@ -1,5 +1,6 @@
 use rstest::rstest;
 
+use crate::domain::coordinate::Coordinate;
 use crate::parsers::{
     cvrplib::{
         cvrplib_parser::CvrpLibParser,
@@ -100,4 +101,27 @@ fn test_parse_the_whole_file() {
     assert_eq!(vrp_inputs.stops.len(), 32);
     assert_eq!(vrp_inputs.vehicles.len(), 5);
     assert_eq!(vrp_inputs.distances.len(), 1024);
+    assert_eq!(vrp_inputs.coordinates.len(), 32);
+}
+
+#[rstest]
+fn test_can_generate_coordinates() {
+    let nodes = vec![
+        Node {
+            id: 1,
+            x_position: 82,
+            y_position: 76,
+        },
+        Node {
+            id: 2,
+            x_position: 10,
+            y_position: 15,
+        },
+    ];
+
+    let coordinates = CvrpLibParser::generate_coordinates(&nodes);
+
+    assert_eq!(coordinates.len(), 2);
+    assert_eq!(coordinates[&1], Coordinate::new(82.0, 76.0));
+    assert_eq!(coordinates[&2], Coordinate::new(10.0, 15.0));
 }
@@ -1,5 +1,5 @@
 use crate::{
-    domain::{stop::Stop, vehicle::Vehicle},
+    domain::{coordinate::CoordinateMap, stop::Stop, vehicle::Vehicle},
     services::distance::distance_service::DistanceMatrix,
 };
 
@@ -7,6 +7,7 @@ pub struct VrpInputs {
     pub vehicles: Vec<Vehicle>,
     pub stops: Vec<Stop>,
     pub distances: DistanceMatrix,
+    pub coordinates: CoordinateMap,
 }
 
 pub trait VrpParser {
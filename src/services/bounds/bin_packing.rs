@@ -0,0 +1,27 @@
+pub(super) fn calculate_minimum_vehicles(demands: &[u32], capacities: &[u32]) -> usize {
+    if demands.is_empty() {
+        return 0;
+    }
+
+    let total_demand: u32 = demands.iter().sum();
+    let max_capacity = capacities.first().copied().unwrap_or_default();
+
+    let mut covered_capacity = 0;
+    let mut vehicles_by_demand = capacities.len() + 1;
+
+    for (index, capacity) in capacities.iter().enumerate() {
+        covered_capacity += capacity;
+
+        if covered_capacity >= total_demand {
+            vehicles_by_demand = index + 1;
+            break;
+        }
+    }
+
+    let large_demands = demands
+        .iter()
+        .filter(|demand| 2 * **demand > max_capacity)
+        .count();
+
+    vehicles_by_demand.max(large_demands).max(1)
+}
@@ -0,0 +1,110 @@
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
+};
+
+use super::{
+    bin_packing::calculate_minimum_vehicles, lp_relaxation::calculate_lp_relaxation_bound,
+    spanning_forest::calculate_spanning_forest_bound,
+};
+
+pub struct LowerBounds {
+    pub minimum_vehicles: usize,
+    pub spanning_forest: f32,
+    pub lp_relaxation: Option<f32>,
+}
+
+impl LowerBounds {
+    pub fn get_best(&self) -> f32 {
+        self.spanning_forest
+            .max(self.lp_relaxation.unwrap_or_default())
+    }
+}
+
+pub struct LowerBoundService {
+    demands: Vec<u32>,
+    capacities: Vec<u32>,
+    distances: Vec<Vec<f32>>,
+    symmetric_distances: Vec<Vec<f32>>,
+}
+
+impl LowerBoundService {
+    pub fn new(stops: Vec<Stop>, vehicles: Vec<Vehicle>, distances: &DistanceMatrix) -> Self {
+        let depot = stops[0];
+        let distance_service = DistanceService::new(stops.clone(), distances);
+
+        let mut capacities: Vec<u32> = vehicles
+            .iter()
+            .map(|vehicle| vehicle.capacity.saturating_sub(2 * depot.usage + 1))
+            .collect();
+        capacities.sort_by(|capacity1, capacity2| capacity2.cmp(capacity1));
+
+        let max_capacity = capacities.first().copied().unwrap_or_default();
+
+        let locations: Vec<Stop> = stops
+            .into_iter()
+            .enumerate()
+            .filter(|(index, stop)| *index == 0 || stop.usage <= max_capacity)
+            .map(|(_, stop)| stop)
+            .collect();
+
+        let distances: Vec<Vec<f32>> = locations
+            .iter()
+            .map(|from| {
+                locations
+                    .iter()
+                    .map(|to| distance_service.get_distance(from, to))
+                    .collect()
+            })
+            .collect();
+
+        let symmetric_distances = (0..locations.len())
+            .map(|from| {
+                (0..locations.len())
+                    .map(|to| distances[from][to].min(distances[to][from]))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            demands: locations.iter().skip(1).map(|stop| stop.usage).collect(),
+            capacities,
+            distances,
+            symmetric_distances,
+        }
+    }
+
+    pub fn calculate_minimum_vehicles(&self) -> usize {
+        calculate_minimum_vehicles(&self.demands, &self.capacities)
+    }
+
+    pub fn calculate_spanning_forest_bound(&self) -> f32 {
+        let customers: Vec<usize> = (1..=self.demands.len()).collect();
+        let minimum_routes = self.calculate_minimum_vehicles();
+
+        calculate_spanning_forest_bound(
+            &self.symmetric_distances,
+            &customers,
+            minimum_routes,
+            self.capacities.len().max(minimum_routes),
+        )
+    }
+
+    pub fn calculate_lp_relaxation_bound(&self) -> Option<f32> {
+        let minimum_routes = self.calculate_minimum_vehicles();
+
+        calculate_lp_relaxation_bound(
+            &self.distances,
+            minimum_routes,
+            self.capacities.len().max(minimum_routes),
+        )
+    }
+
+    pub fn calculate(&self) -> LowerBounds {
+        LowerBounds {
+            minimum_vehicles: self.calculate_minimum_vehicles(),
+            spanning_forest: self.calculate_spanning_forest_bound(),
+            lp_relaxation: self.calculate_lp_relaxation_bound(),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use super::simplex::{Constraint, LinearProgram};
+
+pub(super) fn calculate_lp_relaxation_bound(
+    distances: &[Vec<f32>],
+    minimum_routes: usize,
+    maximum_routes: usize,
+) -> Option<f32> {
+    let number_of_nodes = distances.len();
+
+    if number_of_nodes < 2 {
+        return Some(0.0);
+    }
+
+    let arcs: Vec<(usize, usize)> = (0..number_of_nodes)
+        .flat_map(|from| (0..number_of_nodes).map(move |to| (from, to)))
+        .filter(|(from, to)| from != to)
+        .collect();
+
+    let surplus = arcs.len();
+    let slack = arcs.len() + 1;
+
+    let mut costs: Vec<f64> = arcs
+        .iter()
+        .map(|(from, to)| distances[*from][*to] as f64)
+        .collect();
+    costs.extend([0.0, 0.0]);
+
+    let mut constraints: Vec<Constraint> = (1..number_of_nodes)
+        .flat_map(|customer| {
+            let entering = arcs
+                .iter()
+                .enumerate()
+                .filter(|(_, (_, to))| *to == customer)
+                .map(|(variable, _)| (variable, 1.0))
+                .collect();
+
+            let leaving = arcs
+                .iter()
+                .enumerate()
+                .filter(|(_, (from, _))| *from == customer)
+                .map(|(variable, _)| (variable, 1.0))
+                .collect();
+
+            [
+                Constraint {
+                    coefficients: entering,
+                    rhs: 1.0,
+                },
+                Constraint {
+                    coefficients: leaving,
+                    rhs: 1.0,
+                },
+            ]
+        })
+        .collect();
+
+    let depot_departures: Vec<(usize, f64)> = arcs
+        .iter()
+        .enumerate()
+        .filter(|(_, (from, _))| *from == 0)
+        .map(|(variable, _)| (variable, 1.0))
+        .collect();
+
+    let depot_arrivals = arcs
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, to))| *to == 0)
+        .map(|(variable, _)| (variable, -1.0));
+
+    constraints.push(Constraint {
+        coefficients: depot_departures
+            .iter()
+            .copied()
+            .chain(depot_arrivals)
+            .collect(),
+        rhs: 0.0,
+    });
+
+    constraints.push(Constraint {
+        coefficients: depot_departures
+            .iter()
+            .copied()
+            .chain([(surplus, -1.0)])
+            .collect(),
+        rhs: minimum_routes as f64,
+    });
+
+    constraints.push(Constraint {
+        coefficients: depot_departures
+            .iter()
+            .copied()
+            .chain([(slack, 1.0)])
+            .collect(),
+        rhs: maximum_routes as f64,
+    });
+
+    LinearProgram { costs, constraints }
+        .minimize()
+        .map(|value| value as f32)
+}
@@ -0,0 +1,6 @@
+mod bin_packing;
+pub mod lower_bound_service;
+mod lp_relaxation;
+mod simplex;
+mod spanning_forest;
+mod tests;
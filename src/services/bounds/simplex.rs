@@ -0,0 +1,198 @@
+const EPSILON: f64 = 1e-9;
+const MAX_PIVOTS: usize = 100_000;
+
+pub(super) struct Constraint {
+    pub(super) coefficients: Vec<(usize, f64)>,
+    pub(super) rhs: f64,
+}
+
+pub(super) struct LinearProgram {
+    pub(super) costs: Vec<f64>,
+    pub(super) constraints: Vec<Constraint>,
+}
+
+struct Tableau {
+    rows: Vec<Vec<f64>>,
+    objective: Vec<f64>,
+    basis: Vec<usize>,
+    number_of_variables: usize,
+}
+
+impl Tableau {
+    fn new(linear_program: &LinearProgram) -> Self {
+        let number_of_variables = linear_program.costs.len();
+        let number_of_rows = linear_program.constraints.len();
+        let width = number_of_variables + number_of_rows + 1;
+
+        let rows = linear_program
+            .constraints
+            .iter()
+            .enumerate()
+            .map(|(index, constraint)| {
+                let sign = match constraint.rhs < 0.0 {
+                    true => -1.0,
+                    false => 1.0,
+                };
+
+                let mut row = vec![0.0; width];
+
+                for (variable, coefficient) in constraint.coefficients.iter() {
+                    row[*variable] += sign * coefficient;
+                }
+
+                row[number_of_variables + index] = 1.0;
+                row[width - 1] = sign * constraint.rhs;
+
+                row
+            })
+            .collect();
+
+        Self {
+            rows,
+            objective: vec![0.0; width],
+            basis: (number_of_variables..number_of_variables + number_of_rows).collect(),
+            number_of_variables,
+        }
+    }
+
+    fn rhs(&self) -> usize {
+        self.objective.len() - 1
+    }
+
+    fn is_artificial(&self, column: usize) -> bool {
+        column >= self.number_of_variables
+    }
+
+    fn set_objective(&mut self, costs: &[f64]) {
+        let rhs = self.rhs();
+
+        self.objective = costs.to_vec();
+        self.objective.resize(rhs + 1, 0.0);
+
+        for (row, basic) in self.rows.iter().zip(self.basis.iter()) {
+            let cost = self.objective[*basic];
+
+            if cost == 0.0 {
+                continue;
+            }
+
+            for (column, value) in row.iter().enumerate() {
+                self.objective[column] -= cost * value;
+            }
+        }
+    }
+
+    fn pivot(&mut self, pivot_row: usize, pivot_column: usize) {
+        let pivot = self.rows[pivot_row][pivot_column];
+
+        for value in self.rows[pivot_row].iter_mut() {
+            *value /= pivot;
+        }
+
+        let pivot_values = self.rows[pivot_row].clone();
+
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            let factor = row[pivot_column];
+
+            if index == pivot_row || factor == 0.0 {
+                continue;
+            }
+
+            for (value, pivot_value) in row.iter_mut().zip(pivot_values.iter()) {
+                *value -= factor * pivot_value;
+            }
+        }
+
+        let factor = self.objective[pivot_column];
+
+        for (value, pivot_value) in self.objective.iter_mut().zip(pivot_values.iter()) {
+            *value -= factor * pivot_value;
+        }
+
+        self.basis[pivot_row] = pivot_column;
+    }
+
+    fn choose_entering_column(&self, allow_artificials: bool) -> Option<usize> {
+        (0..self.rhs())
+            .filter(|column| allow_artificials || !self.is_artificial(*column))
+            .filter(|column| self.objective[*column] < -EPSILON)
+            .min_by(|column1, column2| {
+                self.objective[*column1].total_cmp(&self.objective[*column2])
+            })
+    }
+
+    fn choose_leaving_row(&self, column: usize) -> Option<usize> {
+        let rhs = self.rhs();
+
+        (0..self.rows.len())
+            .filter(|row| self.rows[*row][column] > EPSILON)
+            .min_by(|row1, row2| {
+                let ratio1 = self.rows[*row1][rhs] / self.rows[*row1][column];
+                let ratio2 = self.rows[*row2][rhs] / self.rows[*row2][column];
+
+                ratio1
+                    .total_cmp(&ratio2)
+                    .then(self.basis[*row1].cmp(&self.basis[*row2]))
+            })
+    }
+
+    fn optimize(&mut self, allow_artificials: bool) -> Option<()> {
+        for _ in 0..MAX_PIVOTS {
+            let column = match self.choose_entering_column(allow_artificials) {
+                Some(column) => column,
+                None => return Some(()),
+            };
+
+            let row = self.choose_leaving_row(column)?;
+            self.pivot(row, column);
+        }
+
+        None
+    }
+
+    fn drive_out_artificials(&mut self) {
+        for row in 0..self.rows.len() {
+            if !self.is_artificial(self.basis[row]) {
+                continue;
+            }
+
+            let column = (0..self.number_of_variables)
+                .find(|column| self.rows[row][*column].abs() > EPSILON);
+
+            if let Some(column) = column {
+                self.pivot(row, column);
+            }
+        }
+    }
+
+    fn get_objective_value(&self) -> f64 {
+        -self.objective[self.rhs()]
+    }
+}
+
+impl LinearProgram {
+    pub(super) fn minimize(&self) -> Option<f64> {
+        let mut tableau = Tableau::new(self);
+        let number_of_variables = self.costs.len();
+
+        let artificial_costs: Vec<f64> = (0..number_of_variables + self.constraints.len())
+            .map(|column| match column < number_of_variables {
+                true => 0.0,
+                false => 1.0,
+            })
+            .collect();
+
+        tableau.set_objective(&artificial_costs);
+        tableau.optimize(true)?;
+
+        if tableau.get_objective_value() > EPSILON * (1 + self.constraints.len()) as f64 {
+            return None;
+        }
+
+        tableau.drive_out_artificials();
+        tableau.set_objective(&self.costs);
+        tableau.optimize(false)?;
+
+        Some(tableau.get_objective_value())
+    }
+}
@@ -0,0 +1,64 @@
+pub(super) fn calculate_spanning_tree_edges(costs: &[Vec<f32>], nodes: &[usize]) -> Vec<f32> {
+    if nodes.is_empty() {
+        return Vec::new();
+    }
+
+    let mut in_tree = vec![false; nodes.len()];
+    let mut cheapest_edges = vec![f32::MAX; nodes.len()];
+    let mut edges = Vec::with_capacity(nodes.len() - 1);
+
+    cheapest_edges[0] = 0.0;
+
+    for _ in 0..nodes.len() {
+        let next = (0..nodes.len())
+            .filter(|index| !in_tree[*index])
+            .min_by(|index1, index2| cheapest_edges[*index1].total_cmp(&cheapest_edges[*index2]))
+            .expect("there should be a node outside of the tree");
+
+        if in_tree.iter().any(|in_tree| *in_tree) {
+            edges.push(cheapest_edges[next]);
+        }
+
+        in_tree[next] = true;
+
+        for (index, node) in nodes.iter().enumerate() {
+            if !in_tree[index] {
+                cheapest_edges[index] = cheapest_edges[index].min(costs[nodes[next]][*node]);
+            }
+        }
+    }
+
+    edges
+}
+
+pub(super) fn calculate_spanning_forest_bound(
+    costs: &[Vec<f32>],
+    customers: &[usize],
+    minimum_routes: usize,
+    maximum_routes: usize,
+) -> f32 {
+    if customers.is_empty() {
+        return 0.0;
+    }
+
+    let mut tree_edges = calculate_spanning_tree_edges(costs, customers);
+    tree_edges.sort_by(|edge1, edge2| edge2.total_cmp(edge1));
+
+    let mut depot_edges: Vec<f32> = customers
+        .iter()
+        .flat_map(|customer| [costs[0][*customer]; 2])
+        .collect();
+    depot_edges.sort_by(|edge1, edge2| edge1.total_cmp(edge2));
+
+    let minimum_routes = minimum_routes.clamp(1, customers.len());
+    let maximum_routes = maximum_routes.clamp(minimum_routes, customers.len());
+
+    (minimum_routes..=maximum_routes)
+        .map(|routes| {
+            let forest_cost: f32 = tree_edges.iter().skip(routes - 1).sum();
+            let depot_cost: f32 = depot_edges.iter().take(2 * routes).sum();
+
+            forest_cost + depot_cost
+        })
+        .fold(f32::MAX, f32::min)
+}
@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use rstest::rstest;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::{
+        bounds::lower_bound_service::LowerBoundService, distance::distance_service::DistanceMatrix,
+    },
+    solvers::{
+        bounded::bounded_solver::BoundedSolver,
+        branch_and_bound::branch_and_bound_solver::{
+            BranchAndBoundParameters, BranchAndBoundSolver,
+        },
+        greedy::vrp_greedy_solver::VrpGreedySolver,
+        solver::Solver,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn test_minimum_vehicles_come_from_bin_packing(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let loaded_stops = vec![
+        stops[0],
+        Stop::new(1, 5),
+        Stop::new(2, 5),
+        Stop::new(3, 5),
+        stops[4],
+    ];
+    let vehicles = vec![
+        Vehicle::new(0, 10),
+        Vehicle::new(1, 10),
+        Vehicle::new(2, 10),
+    ];
+
+    let lower_bound_service = LowerBoundService::new(loaded_stops, vehicles, &distances);
+
+    assert_eq!(lower_bound_service.calculate_minimum_vehicles(), 3);
+}
+
+#[rstest]
+fn test_lower_bounds_do_not_exceed_the_optimum(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let lower_bound_service = LowerBoundService::new(stops.clone(), vehicle_factory(2), &distances);
+
+    let lower_bounds = lower_bound_service.calculate();
+
+    assert_eq!(lower_bounds.minimum_vehicles, 1);
+    assert_eq!(lower_bounds.spanning_forest, 7.0);
+    assert_eq!(lower_bounds.lp_relaxation, Some(8.0));
+    assert_eq!(lower_bounds.get_best(), 8.0);
+
+    let mut solver = BranchAndBoundSolver::new(
+        stops,
+        vehicle_factory(2),
+        &distances,
+        BranchAndBoundParameters {
+            node_limit: 10_000,
+            time_limit: Duration::from_secs(10),
+        },
+    );
+
+    let solution = solver.solve();
+
+    assert!(lower_bounds.get_best() <= solution.total_distance);
+    assert_eq!(solution.get_gap(), Some(0.0));
+}
+
+#[rstest]
+fn test_bounded_solver_reports_the_gap(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let lower_bound = LowerBoundService::new(stops.clone(), vehicle_factory(2), &distances)
+        .calculate()
+        .get_best();
+
+    let greedy_solver = VrpGreedySolver::new(vehicle_factory(2), &distances, stops);
+    let mut solver = BoundedSolver::new(Box::new(greedy_solver), lower_bound);
+
+    let solution = solver.solve();
+
+    assert_eq!(solution.lower_bound, Some(8.0));
+    assert_eq!(solution.get_gap(), Some(0.2));
+}
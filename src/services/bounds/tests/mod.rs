@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod lower_bound_service_test;
+#[cfg(test)]
+mod simplex_test;
@@ -0,0 +1,68 @@
+use rstest::rstest;
+
+use crate::services::bounds::simplex::{Constraint, LinearProgram};
+
+#[rstest]
+fn test_simplex_finds_the_optimal_value() {
+    let linear_program = LinearProgram {
+        costs: vec![-3.0, -5.0, 0.0, 0.0, 0.0],
+        constraints: vec![
+            Constraint {
+                coefficients: vec![(0, 1.0), (2, 1.0)],
+                rhs: 4.0,
+            },
+            Constraint {
+                coefficients: vec![(1, 2.0), (3, 1.0)],
+                rhs: 12.0,
+            },
+            Constraint {
+                coefficients: vec![(0, 3.0), (1, 2.0), (4, 1.0)],
+                rhs: 18.0,
+            },
+        ],
+    };
+
+    let value = linear_program.minimize().unwrap();
+
+    assert!((value + 36.0).abs() < 1e-6);
+}
+
+#[rstest]
+fn test_simplex_detects_infeasible_programs() {
+    let linear_program = LinearProgram {
+        costs: vec![1.0, 1.0],
+        constraints: vec![
+            Constraint {
+                coefficients: vec![(0, 1.0), (1, 1.0)],
+                rhs: 1.0,
+            },
+            Constraint {
+                coefficients: vec![(0, 1.0), (1, 1.0)],
+                rhs: 2.0,
+            },
+        ],
+    };
+
+    assert!(linear_program.minimize().is_none());
+}
+
+#[rstest]
+fn test_simplex_handles_redundant_constraints() {
+    let linear_program = LinearProgram {
+        costs: vec![2.0, 1.0],
+        constraints: vec![
+            Constraint {
+                coefficients: vec![(0, 1.0), (1, 1.0)],
+                rhs: 1.0,
+            },
+            Constraint {
+                coefficients: vec![(0, 2.0), (1, 2.0)],
+                rhs: 2.0,
+            },
+        ],
+    };
+
+    let value = linear_program.minimize().unwrap();
+
+    assert!((value - 1.0).abs() < 1e-6);
+}
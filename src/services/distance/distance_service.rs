@@ -41,6 +41,10 @@ impl<'a> DistanceService {
     }
 
     pub fn get_distance(&self, from: &Stop, to: &Stop) -> f32 {
+        if from.id == to.id {
+            return 0.0;
+        }
+
         self.distances
             .get(&(from.id, to.id))
             .unwrap_or_else(|| {
@@ -52,10 +56,20 @@ impl<'a> DistanceService {
             .distance
     }
 
+    pub fn is_symmetric(&self) -> bool {
+        self.distances
+            .iter()
+            .all(|((source_stop_id, destination_stop_id), entry)| {
+                self.distances
+                    .get(&(*destination_stop_id, *source_stop_id))
+                    .is_some_and(|reverse_entry| reverse_entry.distance == entry.distance)
+            })
+    }
+
     pub(super) fn get_distances_from(
         &'a self,
         stop: &'a Stop,
-    ) -> impl Iterator<Item = &DistanceMatrixEntry> {
+    ) -> impl Iterator<Item = &'a DistanceMatrixEntry> {
         self.distances
             .iter()
             .filter(|x| x.0 .0 == stop.id)
@@ -66,7 +80,7 @@ impl<'a> DistanceService {
         &'a self,
         stop: &'a Stop,
         filter: impl Fn(&Stop) -> bool,
-    ) -> Option<&Stop> {
+    ) -> Option<&'a Stop> {
         self.get_distances_from(stop)
             .filter(|entry| filter(&entry.destination))
             .min_by(|stop1, stop2| {
@@ -85,7 +99,7 @@ impl<'a> DistanceService {
         stop: &'a Stop,
         k: usize,
         filter: impl Fn(&Stop) -> bool,
-    ) -> Vec<&Stop> {
+    ) -> Vec<&'a Stop> {
         let mut stops = self
             .get_distances_from(stop)
             .filter(|entry| filter(&entry.destination))
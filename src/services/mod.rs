@@ -1,2 +1,3 @@
+pub mod bounds;
 pub mod distance;
 pub mod route;
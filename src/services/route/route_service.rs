@@ -1,8 +1,5 @@
 use rand::{seq::IteratorRandom, Rng};
-use std::{
-    collections::{BTreeMap, HashMap},
-    rc::Rc,
-};
+use std::{collections::BTreeMap, rc::Rc};
 
 use crate::{
     domain::{
@@ -12,7 +9,7 @@ use crate::{
     services::distance::distance_service::DistanceService,
 };
 
-pub type StopMap = HashMap<u32, Stop>;
+pub type StopMap = BTreeMap<u32, Stop>;
 pub type RouteMap = BTreeMap<u32, Route>;
 
 pub struct RouteService {
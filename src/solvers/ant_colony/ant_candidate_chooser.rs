@@ -0,0 +1,84 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rand::Rng;
+
+use crate::solvers::sequential::sequential_solver::{CandidateChooser, SequentialSolverParameters};
+
+use super::pheromone_matrix::PheromoneMatrix;
+
+pub(super) struct AntCandidateChooser<R: Rng + ?Sized> {
+    pub(super) pheromone_influence: f32,
+    pub(super) distance_influence: f32,
+    pub(super) pheromones: Rc<RefCell<PheromoneMatrix>>,
+    pub(super) rng: RefCell<R>,
+}
+
+impl<R: Rng + ?Sized> AntCandidateChooser<R> {
+    fn calculate_attractiveness(&self, pheromone: f32, distance: f32) -> f32 {
+        pheromone.powf(self.pheromone_influence)
+            * (1.0 / distance.max(f32::EPSILON)).powf(self.distance_influence)
+    }
+}
+
+impl<P, R> CandidateChooser<P> for AntCandidateChooser<R>
+where
+    P: SequentialSolverParameters<CandidateId = u32, Cost = f32> + ?Sized,
+    R: Rng + ?Sized,
+{
+    fn get_best_candidate(
+        &self,
+        candidates: Box<dyn Iterator<Item = (u32, f32)> + '_>,
+    ) -> Option<u32> {
+        CandidateChooser::<P>::get_best_candidate_from(self, None, candidates)
+    }
+
+    fn get_best_candidate_from(
+        &self,
+        last_candidate: Option<u32>,
+        candidates: Box<dyn Iterator<Item = (u32, f32)> + '_>,
+    ) -> Option<u32> {
+        let pheromones = self.pheromones.borrow();
+
+        let mut candidates: Vec<(u32, f32)> = candidates
+            .map(|(candidate_id, distance)| {
+                let pheromone = match last_candidate {
+                    Some(last_candidate) => pheromones.get_pheromone(last_candidate, candidate_id),
+                    None => 1.0,
+                };
+
+                (
+                    candidate_id,
+                    self.calculate_attractiveness(pheromone, distance),
+                )
+            })
+            .collect();
+
+        candidates.sort_unstable_by_key(|(candidate_id, _)| *candidate_id);
+
+        let total_attractiveness: f32 = candidates
+            .iter()
+            .map(|(_, attractiveness)| attractiveness)
+            .sum();
+
+        if !total_attractiveness.is_normal() {
+            return candidates
+                .iter()
+                .max_by(|(_, attractiveness1), (_, attractiveness2)| {
+                    attractiveness1.total_cmp(attractiveness2)
+                })
+                .map(|(candidate_id, _)| *candidate_id);
+        }
+
+        let mut threshold = self.rng.borrow_mut().gen_range(0.0..total_attractiveness);
+
+        for (candidate_id, attractiveness) in candidates.iter() {
+            if threshold < *attractiveness {
+                return Some(*candidate_id);
+            }
+
+            threshold -= attractiveness;
+        }
+
+        candidates.last().map(|(candidate_id, _)| *candidate_id)
+    }
+}
@@ -0,0 +1,138 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rand::Rng;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    local_search::{local_searcher::LocalSearcher, two_opt::TwoOptSearcher},
+    services::{
+        distance::distance_service::{DistanceMatrix, DistanceService},
+        route::route_service::RouteService,
+    },
+    solvers::{
+        solver::Solver, vrp_sequential::vrp_sequential_solver::VrpSequentialSolver,
+        vrp_solution::VrpSolution,
+    },
+};
+
+use super::{ant_candidate_chooser::AntCandidateChooser, pheromone_matrix::PheromoneMatrix};
+
+pub struct AntColonySolverParameters {
+    pub number_of_ants: usize,
+    pub max_iterations: u32,
+    pub pheromone_influence: f32,
+    pub distance_influence: f32,
+    pub evaporation_rate: f32,
+    pub improve_best_ant: bool,
+}
+
+pub struct AntColonySolver {
+    number_of_stops: usize,
+    parameters: AntColonySolverParameters,
+    pheromones: Rc<RefCell<PheromoneMatrix>>,
+    sequential_solver: VrpSequentialSolver,
+    local_searcher: Box<dyn LocalSearcher<VrpSolution>>,
+}
+
+impl Solver<VrpSolution> for AntColonySolver {
+    fn solve(&mut self) -> VrpSolution {
+        let mut best_solution = VrpSolution::default();
+
+        self.pheromones.borrow_mut().reset();
+
+        for _ in 0..self.parameters.max_iterations {
+            let mut iteration_best = match self.run_ants() {
+                Some(solution) => solution,
+                None => break,
+            };
+
+            if self.parameters.improve_best_ant {
+                self.local_searcher.run(&mut iteration_best);
+            }
+
+            if iteration_best.is_better_than(&best_solution) {
+                best_solution = iteration_best.clone();
+            }
+
+            self.update_pheromones(&iteration_best, &best_solution);
+        }
+
+        best_solution
+    }
+}
+
+impl AntColonySolver {
+    pub fn new<R: Rng + 'static>(
+        stops: Vec<Stop>,
+        vehicles: Vec<Vehicle>,
+        distances: &DistanceMatrix,
+        parameters: AntColonySolverParameters,
+        rng: R,
+    ) -> Self {
+        let distance_service = Rc::new(DistanceService::new(stops.clone(), distances));
+        let pheromones = Rc::new(RefCell::new(PheromoneMatrix::new(1.0)));
+
+        let candidate_chooser = Box::new(AntCandidateChooser {
+            pheromone_influence: parameters.pheromone_influence,
+            distance_influence: parameters.distance_influence,
+            pheromones: pheromones.clone(),
+            rng: RefCell::new(rng),
+        });
+
+        Self {
+            parameters,
+            pheromones,
+            number_of_stops: stops.len(),
+            local_searcher: Box::new(TwoOptSearcher::new(distance_service.clone())),
+            sequential_solver: VrpSequentialSolver {
+                candidate_chooser,
+                route_service: RouteService::new(stops, vehicles, distance_service),
+            },
+        }
+    }
+
+    fn run_ants(&mut self) -> Option<VrpSolution> {
+        let mut iteration_best: Option<VrpSolution> = None;
+
+        for _ in 0..self.parameters.number_of_ants {
+            let solution = self.sequential_solver.solve();
+
+            let is_better = match &iteration_best {
+                Some(best) => solution.is_better_than(best),
+                None => true,
+            };
+
+            if is_better {
+                iteration_best = Some(solution);
+            }
+        }
+
+        iteration_best
+    }
+
+    fn update_pheromones(&self, iteration_best: &VrpSolution, best_solution: &VrpSolution) {
+        let evaporation_rate = self.parameters.evaporation_rate;
+        let mut pheromones = self.pheromones.borrow_mut();
+
+        if best_solution.total_distance > 0.0 {
+            let max_pheromone = 1.0 / (evaporation_rate * best_solution.total_distance);
+            let min_pheromone = max_pheromone / (2 * self.number_of_stops) as f32;
+
+            pheromones.set_limits(min_pheromone, max_pheromone);
+        }
+
+        pheromones.evaporate(evaporation_rate);
+
+        if iteration_best.total_distance <= 0.0 {
+            return;
+        }
+
+        let amount = 1.0 / iteration_best.total_distance;
+
+        for route in iteration_best.routes.values() {
+            for window in route.stops.windows(2) {
+                pheromones.deposit(window[0].id, window[1].id, amount);
+            }
+        }
+    }
+}
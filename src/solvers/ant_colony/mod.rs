@@ -0,0 +1,4 @@
+mod ant_candidate_chooser;
+pub mod ant_colony_solver;
+mod pheromone_matrix;
+mod tests;
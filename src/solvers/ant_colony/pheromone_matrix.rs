@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+type Trail = (u32, u32);
+
+pub(super) struct PheromoneMatrix {
+    initial_pheromone: f32,
+    untouched_pheromone: f32,
+    min_pheromone: f32,
+    max_pheromone: f32,
+    trails: HashMap<Trail, f32>,
+}
+
+impl PheromoneMatrix {
+    pub(super) fn new(initial_pheromone: f32) -> Self {
+        Self {
+            initial_pheromone,
+            untouched_pheromone: initial_pheromone,
+            min_pheromone: 0.0,
+            max_pheromone: f32::MAX,
+            trails: HashMap::new(),
+        }
+    }
+
+    pub(super) fn reset(&mut self) {
+        self.untouched_pheromone = self.initial_pheromone;
+        self.min_pheromone = 0.0;
+        self.max_pheromone = f32::MAX;
+        self.trails.clear();
+    }
+
+    pub(super) fn get_pheromone(&self, from: u32, to: u32) -> f32 {
+        self.trails
+            .get(&(from, to))
+            .copied()
+            .unwrap_or(self.untouched_pheromone)
+    }
+
+    pub(super) fn set_limits(&mut self, min_pheromone: f32, max_pheromone: f32) {
+        self.min_pheromone = min_pheromone;
+        self.max_pheromone = max_pheromone;
+        self.untouched_pheromone = self.untouched_pheromone.clamp(min_pheromone, max_pheromone);
+
+        for pheromone in self.trails.values_mut() {
+            *pheromone = pheromone.clamp(min_pheromone, max_pheromone);
+        }
+    }
+
+    pub(super) fn evaporate(&mut self, evaporation_rate: f32) {
+        let persistence = 1.0 - evaporation_rate;
+
+        self.untouched_pheromone = (self.untouched_pheromone * persistence).max(self.min_pheromone);
+
+        for pheromone in self.trails.values_mut() {
+            *pheromone = (*pheromone * persistence).max(self.min_pheromone);
+        }
+    }
+
+    pub(super) fn deposit(&mut self, from: u32, to: u32, amount: f32) {
+        let pheromone = (self.get_pheromone(from, to) + amount).min(self.max_pheromone);
+
+        self.trails.insert((from, to), pheromone);
+    }
+}
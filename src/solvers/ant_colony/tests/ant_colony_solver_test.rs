@@ -0,0 +1,89 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceMatrix,
+    solvers::{
+        ant_colony::{
+            ant_candidate_chooser::AntCandidateChooser,
+            ant_colony_solver::{AntColonySolver, AntColonySolverParameters},
+            pheromone_matrix::PheromoneMatrix,
+        },
+        sequential::sequential_solver::CandidateChooser,
+        solver::Solver,
+        vrp_sequential::vrp_sequential_solver::VrpSequentialSolver,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn test_ant_follows_the_strongest_trail() {
+    let pheromones = Rc::new(RefCell::new(PheromoneMatrix::new(0.0)));
+    pheromones.borrow_mut().deposit(0, 2, 1.0);
+
+    let candidate_chooser = AntCandidateChooser {
+        pheromone_influence: 1.0,
+        distance_influence: 1.0,
+        pheromones,
+        rng: RefCell::new(ChaCha8Rng::seed_from_u64(0)),
+    };
+
+    for _ in 0..10 {
+        let candidates = Box::new([(1, 1.0), (2, 5.0), (3, 1.0)].into_iter());
+
+        let chosen = CandidateChooser::<VrpSequentialSolver>::get_best_candidate_from(
+            &candidate_chooser,
+            Some(0),
+            candidates,
+        );
+
+        assert_eq!(chosen, Some(2));
+    }
+}
+
+#[rstest]
+fn test_ant_colony_solver_visits_every_stop(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let parameters = AntColonySolverParameters {
+        number_of_ants: 5,
+        max_iterations: 10,
+        pheromone_influence: 1.0,
+        distance_influence: 2.0,
+        evaporation_rate: 0.1,
+        improve_best_ant: true,
+    };
+
+    let mut solver = AntColonySolver::new(
+        stops,
+        vehicle_factory(2),
+        &distances,
+        parameters,
+        ChaCha8Rng::seed_from_u64(0),
+    );
+
+    let solution = solver.solve();
+
+    let mut visited_stops: Vec<u32> = solution
+        .routes
+        .values()
+        .flat_map(|route| {
+            route.stops[1..route.stops.len() - 1]
+                .iter()
+                .map(|stop| stop.id)
+        })
+        .collect();
+    visited_stops.sort_unstable();
+
+    assert_eq!(visited_stops, vec![1, 2, 3]);
+    assert_eq!(solution.total_distance, 10.0);
+}
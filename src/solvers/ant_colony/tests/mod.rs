@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod ant_colony_solver_test;
+#[cfg(test)]
+mod pheromone_matrix_test;
@@ -0,0 +1,38 @@
+use rstest::rstest;
+
+use crate::solvers::ant_colony::pheromone_matrix::PheromoneMatrix;
+
+#[rstest]
+fn test_pheromone_evaporates_and_accumulates() {
+    let mut pheromones = PheromoneMatrix::new(1.0);
+
+    pheromones.evaporate(0.5);
+    pheromones.deposit(0, 1, 2.0);
+
+    assert_eq!(pheromones.get_pheromone(0, 1), 2.5);
+    assert_eq!(pheromones.get_pheromone(1, 0), 0.5);
+
+    pheromones.evaporate(0.5);
+
+    assert_eq!(pheromones.get_pheromone(0, 1), 1.25);
+    assert_eq!(pheromones.get_pheromone(1, 0), 0.25);
+}
+
+#[rstest]
+fn test_pheromone_stays_within_limits() {
+    let mut pheromones = PheromoneMatrix::new(1.0);
+
+    pheromones.deposit(0, 1, 10.0);
+    pheromones.set_limits(0.5, 4.0);
+
+    assert_eq!(pheromones.get_pheromone(0, 1), 4.0);
+
+    pheromones.evaporate(0.9);
+
+    assert_eq!(pheromones.get_pheromone(0, 1), 0.5);
+    assert_eq!(pheromones.get_pheromone(2, 3), 0.5);
+
+    pheromones.reset();
+
+    assert_eq!(pheromones.get_pheromone(0, 1), 1.0);
+}
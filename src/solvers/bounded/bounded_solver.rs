@@ -0,0 +1,21 @@
+use crate::solvers::{solver::Solver, vrp_solution::VrpSolution};
+
+pub struct BoundedSolver {
+    lower_bound: f32,
+    solver: Box<dyn Solver<VrpSolution>>,
+}
+
+impl BoundedSolver {
+    pub fn new(solver: Box<dyn Solver<VrpSolution>>, lower_bound: f32) -> Self {
+        Self {
+            solver,
+            lower_bound,
+        }
+    }
+}
+
+impl Solver<VrpSolution> for BoundedSolver {
+    fn solve(&mut self) -> VrpSolution {
+        self.solver.solve().with_lower_bound(self.lower_bound)
+    }
+}
@@ -0,0 +1 @@
+pub mod bounded_solver;
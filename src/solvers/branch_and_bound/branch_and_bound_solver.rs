@@ -0,0 +1,345 @@
+use std::{
+    cmp::Reverse,
+    rc::Rc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::{
+        distance::distance_service::{DistanceMatrix, DistanceService},
+        route::route_service::RouteMap,
+    },
+    solvers::{solver::Solver, vrp_solution::VrpSolution},
+};
+
+use super::search_state::SearchState;
+
+pub struct BranchAndBoundParameters {
+    pub node_limit: u64,
+    pub time_limit: Duration,
+}
+
+pub struct BranchAndBoundSolver {
+    depot: Stop,
+    customers: Vec<Stop>,
+    unassigned_stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    capacities: Vec<u32>,
+    distances: Vec<Vec<f32>>,
+    distance_service: Rc<DistanceService>,
+    parameters: BranchAndBoundParameters,
+    explored_nodes: u64,
+    started_at: Instant,
+    is_interrupted: bool,
+    root_bound: f32,
+    best_cost: f32,
+    best_routes: Option<Vec<Vec<usize>>>,
+}
+
+impl Solver<VrpSolution> for BranchAndBoundSolver {
+    fn solve(&mut self) -> VrpSolution {
+        let demand = self.customers.iter().map(|customer| customer.usage).sum();
+        let mut state = SearchState::new(self.customers.len(), self.vehicles.len(), demand);
+
+        self.explored_nodes = 0;
+        self.started_at = Instant::now();
+        self.is_interrupted = false;
+        self.best_cost = f32::MAX;
+        self.best_routes = None;
+        self.root_bound = self.calculate_lower_bound(&state, 0.0);
+
+        if !self.vehicles.is_empty() {
+            self.search(&mut state, 0.0);
+        }
+
+        self.get_solution()
+    }
+}
+
+impl BranchAndBoundSolver {
+    pub fn new(
+        stops: Vec<Stop>,
+        mut vehicles: Vec<Vehicle>,
+        distances: &DistanceMatrix,
+        parameters: BranchAndBoundParameters,
+    ) -> Self {
+        let depot = stops[0];
+        let distance_service = Rc::new(DistanceService::new(stops.clone(), distances));
+
+        vehicles.sort_by_key(|vehicle| Reverse(vehicle.capacity));
+
+        let capacities: Vec<u32> = vehicles
+            .iter()
+            .map(|vehicle| vehicle.capacity.saturating_sub(2 * depot.usage + 1))
+            .collect();
+
+        let max_capacity = capacities.first().copied().unwrap_or_default();
+
+        let (customers, unassigned_stops): (Vec<Stop>, Vec<Stop>) = stops
+            .into_iter()
+            .skip(1)
+            .partition(|stop| stop.usage <= max_capacity);
+
+        let locations: Vec<Stop> = [depot].into_iter().chain(customers.clone()).collect();
+
+        let distances = locations
+            .iter()
+            .map(|from| {
+                locations
+                    .iter()
+                    .map(|to| distance_service.get_distance(from, to))
+                    .collect()
+            })
+            .collect();
+
+        Self {
+            depot,
+            customers,
+            unassigned_stops,
+            vehicles,
+            capacities,
+            distances,
+            distance_service,
+            parameters,
+            explored_nodes: 0,
+            started_at: Instant::now(),
+            is_interrupted: false,
+            root_bound: 0.0,
+            best_cost: f32::MAX,
+            best_routes: None,
+        }
+    }
+
+    pub fn is_optimal(&self) -> bool {
+        self.best_routes.is_some() && !self.is_interrupted && self.unassigned_stops.is_empty()
+    }
+
+    pub fn get_explored_nodes(&self) -> u64 {
+        self.explored_nodes
+    }
+
+    pub fn get_optimality_gap(&self) -> f32 {
+        if self.best_routes.is_none() || !self.unassigned_stops.is_empty() {
+            return f32::INFINITY;
+        }
+
+        if !self.is_interrupted || self.best_cost <= 0.0 {
+            return 0.0;
+        }
+
+        ((self.best_cost - self.root_bound) / self.best_cost).max(0.0)
+    }
+
+    fn get_demand(&self, customer: usize) -> u32 {
+        self.customers[customer - 1].usage
+    }
+
+    fn should_stop(&mut self) -> bool {
+        if self.is_interrupted {
+            return true;
+        }
+
+        self.explored_nodes += 1;
+
+        self.is_interrupted = self.explored_nodes > self.parameters.node_limit
+            || self.started_at.elapsed() > self.parameters.time_limit;
+
+        self.is_interrupted
+    }
+
+    fn search(&mut self, state: &mut SearchState, cost: f32) {
+        if self.should_stop() {
+            return;
+        }
+
+        if state.remaining_customers == 0 {
+            let total_cost = cost + self.distances[state.current][0];
+
+            if total_cost < self.best_cost {
+                self.best_cost = total_cost;
+                self.best_routes = Some(state.routes.clone());
+            }
+
+            return;
+        }
+
+        if self.calculate_lower_bound(state, cost) >= self.best_cost {
+            return;
+        }
+
+        let mut candidates: Vec<usize> = state
+            .get_unvisited()
+            .filter(|customer| self.can_visit(state, *customer))
+            .collect();
+
+        candidates.sort_by(|customer1, customer2| {
+            self.distances[state.current][*customer1]
+                .total_cmp(&self.distances[state.current][*customer2])
+        });
+
+        for customer in candidates {
+            let demand = self.get_demand(customer);
+            let distance = self.distances[state.current][customer];
+
+            state.visit(customer, demand);
+            self.search(state, cost + distance);
+            state.unvisit(demand);
+
+            if self.is_interrupted {
+                return;
+            }
+        }
+
+        if state.is_route_empty() || state.vehicle_index + 1 >= self.vehicles.len() {
+            return;
+        }
+
+        let distance = self.distances[state.current][0];
+        let load = state.open_next_route();
+
+        self.search(state, cost + distance);
+
+        state.reopen_previous_route(load);
+    }
+
+    fn can_visit(&self, state: &SearchState, customer: usize) -> bool {
+        let vehicle_index = state.vehicle_index;
+
+        if state.load + self.get_demand(customer) > self.capacities[vehicle_index] {
+            return false;
+        }
+
+        if !state.is_route_empty() || vehicle_index == 0 {
+            return true;
+        }
+
+        if self.capacities[vehicle_index] != self.capacities[vehicle_index - 1] {
+            return true;
+        }
+
+        state.routes[vehicle_index - 1]
+            .first()
+            .is_none_or(|first_customer| customer > *first_customer)
+    }
+
+    fn count_required_routes(&self, state: &SearchState) -> Option<usize> {
+        let (uncovered_demand, first_available_vehicle) = match state.is_route_empty() {
+            true => (state.remaining_demand.max(1), state.vehicle_index),
+            false => (
+                state
+                    .remaining_demand
+                    .saturating_sub(self.capacities[state.vehicle_index] - state.load),
+                state.vehicle_index + 1,
+            ),
+        };
+
+        if uncovered_demand == 0 {
+            return Some(0);
+        }
+
+        let available_vehicles = self.vehicles.len() - first_available_vehicle;
+        let max_capacity = self
+            .capacities
+            .get(first_available_vehicle)
+            .copied()
+            .unwrap_or_default();
+
+        if max_capacity == 0 {
+            return None;
+        }
+
+        let required_routes = uncovered_demand.div_ceil(max_capacity) as usize;
+
+        match required_routes <= available_vehicles {
+            true => Some(required_routes),
+            false => None,
+        }
+    }
+
+    pub(super) fn calculate_lower_bound(&self, state: &SearchState, cost: f32) -> f32 {
+        let unvisited: Vec<usize> = state.get_unvisited().collect();
+
+        if unvisited.is_empty() {
+            return cost + self.distances[state.current][0];
+        }
+
+        let required_routes = match self.count_required_routes(state) {
+            Some(required_routes) => required_routes,
+            None => return f32::INFINITY,
+        };
+
+        let entering_cost: f32 = unvisited
+            .iter()
+            .map(|customer| {
+                unvisited
+                    .iter()
+                    .filter(|other| *other != customer)
+                    .chain([0, state.current].iter())
+                    .map(|other| self.distances[*other][*customer])
+                    .fold(f32::MAX, f32::min)
+            })
+            .sum();
+
+        let returning_cost = unvisited
+            .iter()
+            .map(|customer| self.distances[*customer][0])
+            .fold(f32::MAX, f32::min);
+
+        let closing_cost = match state.is_route_empty() {
+            true => 0.0,
+            false => returning_cost.min(self.distances[state.current][0]),
+        };
+
+        cost + entering_cost + closing_cost + required_routes as f32 * returning_cost
+    }
+
+    fn get_solution(&self) -> VrpSolution {
+        let best_routes = match &self.best_routes {
+            Some(best_routes) => best_routes,
+            None => {
+                return VrpSolution::default().with_unassigned_stops(
+                    [self.customers.as_slice(), self.unassigned_stops.as_slice()].concat(),
+                )
+            }
+        };
+
+        let routes: RouteMap = self
+            .vehicles
+            .iter()
+            .zip(best_routes.iter())
+            .map(|(vehicle, customers)| {
+                let mut route = Route::new(Vehicle::new(vehicle.id, vehicle.capacity));
+
+                let stops = [self.depot]
+                    .into_iter()
+                    .chain(
+                        customers
+                            .iter()
+                            .map(|customer| self.customers[customer - 1]),
+                    )
+                    .chain([self.depot])
+                    .collect();
+
+                route
+                    .replace_stops(stops, &self.distance_service)
+                    .unwrap_or_else(|_| {
+                        panic!("the vehicle {} should support the load", vehicle.id)
+                    });
+
+                (vehicle.id, route)
+            })
+            .collect();
+
+        let total_distance = routes.values().map(|route| route.total_distance()).sum();
+
+        let lower_bound = match self.is_interrupted {
+            true => self.root_bound.min(total_distance),
+            false => total_distance,
+        };
+
+        VrpSolution::new(&routes, total_distance)
+            .with_lower_bound(lower_bound)
+            .with_unassigned_stops(self.unassigned_stops.clone())
+    }
+}
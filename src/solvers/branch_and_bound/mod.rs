@@ -0,0 +1,3 @@
+pub mod branch_and_bound_solver;
+mod search_state;
+mod tests;
@@ -0,0 +1,79 @@
+pub(super) struct SearchState {
+    pub(super) routes: Vec<Vec<usize>>,
+    pub(super) visited: Vec<bool>,
+    pub(super) vehicle_index: usize,
+    pub(super) current: usize,
+    pub(super) load: u32,
+    pub(super) remaining_demand: u32,
+    pub(super) remaining_customers: usize,
+}
+
+impl SearchState {
+    pub(super) fn new(number_of_customers: usize, number_of_vehicles: usize, demand: u32) -> Self {
+        Self {
+            routes: vec![Vec::new(); number_of_vehicles],
+            visited: vec![false; number_of_customers + 1],
+            vehicle_index: 0,
+            current: 0,
+            load: 0,
+            remaining_demand: demand,
+            remaining_customers: number_of_customers,
+        }
+    }
+
+    pub(super) fn is_route_empty(&self) -> bool {
+        self.routes[self.vehicle_index].is_empty()
+    }
+
+    pub(super) fn get_unvisited(&self) -> impl Iterator<Item = usize> + '_ {
+        self.visited
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, visited)| !**visited)
+            .map(|(customer, _)| customer)
+    }
+
+    pub(super) fn visit(&mut self, customer: usize, demand: u32) {
+        self.visited[customer] = true;
+        self.routes[self.vehicle_index].push(customer);
+        self.current = customer;
+        self.load += demand;
+        self.remaining_demand -= demand;
+        self.remaining_customers -= 1;
+    }
+
+    pub(super) fn unvisit(&mut self, demand: u32) {
+        let customer = self.routes[self.vehicle_index]
+            .pop()
+            .expect("the route should hold the last visited customer");
+
+        self.visited[customer] = false;
+        self.current = self.routes[self.vehicle_index]
+            .last()
+            .copied()
+            .unwrap_or_default();
+        self.load -= demand;
+        self.remaining_demand += demand;
+        self.remaining_customers += 1;
+    }
+
+    pub(super) fn open_next_route(&mut self) -> u32 {
+        let load = self.load;
+
+        self.vehicle_index += 1;
+        self.current = 0;
+        self.load = 0;
+
+        load
+    }
+
+    pub(super) fn reopen_previous_route(&mut self, load: u32) {
+        self.vehicle_index -= 1;
+        self.load = load;
+        self.current = self.routes[self.vehicle_index]
+            .last()
+            .copied()
+            .unwrap_or_default();
+    }
+}
@@ -0,0 +1,131 @@
+use std::time::Duration;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::distance::distance_service::DistanceMatrix,
+    solvers::{
+        branch_and_bound::branch_and_bound_solver::{
+            BranchAndBoundParameters, BranchAndBoundSolver,
+        },
+        grasp::vrp_grasp_solver::{GraspSolverParameters, VrpGraspSolver},
+        greedy::vrp_greedy_solver::VrpGreedySolver,
+        solver::Solver,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+fn parameters() -> BranchAndBoundParameters {
+    BranchAndBoundParameters {
+        node_limit: 10_000,
+        time_limit: Duration::from_secs(10),
+    }
+}
+
+#[rstest]
+fn test_branch_and_bound_beats_the_heuristics(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let stops = vec![stops[0], stops[1], stops[2], stops[3], Stop::new(4, 1)];
+
+    let mut greedy_solver = VrpGreedySolver::new(vehicle_factory(2), &distances, stops.clone());
+
+    let mut grasp_solver = VrpGraspSolver::new(
+        stops.clone(),
+        vehicle_factory(2),
+        &distances,
+        GraspSolverParameters {
+            rcl_size: 3,
+            max_improvement_times: 3,
+        },
+        ChaCha8Rng::seed_from_u64(0),
+    );
+
+    let mut solver = BranchAndBoundSolver::new(stops, vehicle_factory(2), &distances, parameters());
+
+    let solution = solver.solve();
+
+    assert!(solver.is_optimal());
+    assert_eq!(solver.get_optimality_gap(), 0.0);
+    assert_eq!(solution.total_distance, 9.0);
+    assert!(solution.total_distance <= greedy_solver.solve().total_distance);
+    assert!(solution.total_distance <= grasp_solver.solve().total_distance);
+}
+
+#[rstest]
+fn test_branch_and_bound_respects_vehicle_capacities(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let loaded_stops = vec![
+        stops[0],
+        Stop::new(1, 4),
+        Stop::new(2, 4),
+        Stop::new(3, 4),
+        Stop::new(4, 1),
+    ];
+    let vehicles = vec![Vehicle::new(0, 10), Vehicle::new(1, 10)];
+
+    let mut solver = BranchAndBoundSolver::new(loaded_stops, vehicles, &distances, parameters());
+
+    let solution = solver.solve();
+
+    assert!(solver.is_optimal());
+    assert_eq!(solution.total_distance, 11.5);
+
+    for route in solution.routes.values() {
+        let load: u32 = route.stops.iter().map(|stop| stop.usage).sum();
+
+        assert!(load < route.vehicle.capacity);
+    }
+}
+
+#[rstest]
+fn test_branch_and_bound_reports_interrupted_searches(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let parameters = BranchAndBoundParameters {
+        node_limit: 4,
+        time_limit: Duration::from_secs(10),
+    };
+
+    let stops = vec![stops[0], stops[1], stops[2], stops[3], Stop::new(4, 1)];
+
+    let mut solver = BranchAndBoundSolver::new(stops, vehicle_factory(2), &distances, parameters);
+
+    solver.solve();
+
+    assert!(!solver.is_optimal());
+    assert!(solver.get_optimality_gap() > 0.0);
+    assert_eq!(solver.get_explored_nodes(), 5);
+}
+
+#[rstest]
+fn test_branch_and_bound_reports_customers_no_vehicle_can_serve(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let mut solver = BranchAndBoundSolver::new(stops, vehicle_factory(2), &distances, parameters());
+
+    let solution = solver.solve();
+
+    let unassigned_stops: Vec<u32> = solution
+        .unassigned_stops
+        .iter()
+        .map(|stop| stop.id)
+        .collect();
+
+    assert_eq!(unassigned_stops, vec![4]);
+    assert_eq!(solution.total_distance, 8.0);
+    assert!(!solution.is_feasible());
+    assert!(!solver.is_optimal());
+    assert_eq!(solver.get_optimality_gap(), f32::INFINITY);
+}
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod branch_and_bound_solver_test;
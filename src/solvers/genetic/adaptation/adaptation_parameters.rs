@@ -0,0 +1,102 @@
+use super::generation_parameters::{GenerationFeedback, GenerationParameters};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum AdaptationStrategy {
+    #[default]
+    Fixed,
+    /// Multiplies the rates by `factor` when more than `target_success_rate` of the operator
+    /// applications improved an individual, and divides them by `factor` otherwise.
+    SuccessBased {
+        target_success_rate: f32,
+        factor: f32,
+    },
+    /// Raises the mutation rates and shrinks the elite while the population diversity is
+    /// below `target_diversity`, and does the opposite above it.
+    DiversityBased { target_diversity: f32, factor: f32 },
+    /// Each individual carries its own rates, inherited from its parents and perturbed by a
+    /// log-uniform step of at most `learning_rate`.
+    SelfAdaptive { learning_rate: f32 },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdaptationParameters {
+    pub strategy: AdaptationStrategy,
+    pub min_rate: f32,
+    pub max_rate: f32,
+    pub min_elite_size: usize,
+    pub max_elite_size: usize,
+}
+
+impl Default for AdaptationParameters {
+    fn default() -> Self {
+        Self {
+            strategy: Default::default(),
+            min_rate: 0.001,
+            max_rate: 0.5,
+            min_elite_size: 1,
+            max_elite_size: 10,
+        }
+    }
+}
+
+impl AdaptationParameters {
+    pub(crate) fn adapt(
+        &self,
+        parameters: &mut GenerationParameters,
+        feedback: &GenerationFeedback,
+    ) {
+        match self.strategy {
+            AdaptationStrategy::Fixed | AdaptationStrategy::SelfAdaptive { .. } => (),
+            AdaptationStrategy::SuccessBased {
+                target_success_rate,
+                factor,
+            } => {
+                let scale = |success_rate: f32| match success_rate > target_success_rate {
+                    true => factor,
+                    false => 1.0 / factor,
+                };
+
+                if let Some(success_rate) = feedback.mutation.get_rate() {
+                    self.scale_mutation_rates(parameters, scale(success_rate));
+                }
+
+                if let Some(success_rate) = feedback.local_search.get_rate() {
+                    parameters.local_search_rate =
+                        self.clamp_rate(parameters.local_search_rate * scale(success_rate));
+                }
+            }
+            AdaptationStrategy::DiversityBased {
+                target_diversity,
+                factor,
+            } => {
+                let scale = match feedback.diversity < target_diversity {
+                    true => factor,
+                    false => 1.0 / factor,
+                };
+
+                self.scale_mutation_rates(parameters, scale);
+                parameters.local_search_rate =
+                    self.clamp_rate(parameters.local_search_rate / scale);
+
+                parameters.elite_size = match feedback.diversity < target_diversity {
+                    true => parameters.elite_size.saturating_sub(1),
+                    false => parameters.elite_size + 1,
+                }
+                .clamp(
+                    self.min_elite_size,
+                    self.max_elite_size.max(self.min_elite_size),
+                );
+            }
+        }
+    }
+
+    pub(crate) fn clamp_rate(&self, rate: f32) -> f32 {
+        rate.clamp(self.min_rate, self.max_rate.max(self.min_rate))
+    }
+
+    fn scale_mutation_rates(&self, parameters: &mut GenerationParameters, scale: f32) {
+        for rate in parameters.mutation_rates.iter_mut() {
+            *rate = self.clamp_rate(*rate * scale);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use super::super::genetic_solver::GeneticSolverParameters;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GenerationParameters {
+    pub mutation_rates: Vec<f32>,
+    pub local_search_rate: f32,
+    pub elite_size: usize,
+}
+
+impl From<&GeneticSolverParameters> for GenerationParameters {
+    fn from(parameters: &GeneticSolverParameters) -> Self {
+        Self {
+            mutation_rates: parameters
+                .mutations
+                .iter()
+                .map(|mutation| mutation.probability)
+                .collect(),
+            local_search_rate: parameters.local_search_rate,
+            elite_size: parameters.elite_size,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct SuccessCounter {
+    pub(crate) attempts: u32,
+    pub(crate) successes: u32,
+}
+
+impl SuccessCounter {
+    pub(crate) fn record(&mut self, succeeded: bool) {
+        self.attempts += 1;
+        self.successes += succeeded as u32;
+    }
+
+    pub(crate) fn get_rate(&self) -> Option<f32> {
+        match self.attempts {
+            0 => None,
+            attempts => Some(self.successes as f32 / attempts as f32),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct GenerationFeedback {
+    pub(crate) mutation: SuccessCounter,
+    pub(crate) local_search: SuccessCounter,
+    pub(crate) diversity: f32,
+}
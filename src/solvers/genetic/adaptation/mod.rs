@@ -0,0 +1,4 @@
+pub mod adaptation_parameters;
+pub mod generation_parameters;
+pub(crate) mod self_adaptive_rates;
+mod tests;
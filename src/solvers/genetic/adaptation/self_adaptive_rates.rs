@@ -0,0 +1,81 @@
+use rand::Rng;
+
+use super::{
+    adaptation_parameters::AdaptationParameters, generation_parameters::GenerationParameters,
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct SelfAdaptiveRates {
+    pub(crate) mutation_rates: Vec<f32>,
+    pub(crate) local_search_rate: f32,
+}
+
+impl From<&GenerationParameters> for SelfAdaptiveRates {
+    fn from(parameters: &GenerationParameters) -> Self {
+        Self {
+            mutation_rates: parameters.mutation_rates.clone(),
+            local_search_rate: parameters.local_search_rate,
+        }
+    }
+}
+
+impl SelfAdaptiveRates {
+    pub(crate) fn inherit<R: Rng + ?Sized>(
+        parent1: &Self,
+        parent2: &Self,
+        learning_rate: f32,
+        adaptation: &AdaptationParameters,
+        rng: &mut R,
+    ) -> Self {
+        let learning_rate = learning_rate.abs();
+
+        let mut perturb = |rate1: f32, rate2: f32| {
+            let step = rng.gen_range(-learning_rate..=learning_rate).exp();
+            adaptation.clamp_rate((rate1 + rate2) / 2.0 * step)
+        };
+
+        Self {
+            mutation_rates: parent1
+                .mutation_rates
+                .iter()
+                .zip(parent2.mutation_rates.iter())
+                .map(|(rate1, rate2)| perturb(*rate1, *rate2))
+                .collect(),
+            local_search_rate: perturb(parent1.local_search_rate, parent2.local_search_rate),
+        }
+    }
+
+    pub(crate) fn average<'a, I>(rates: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = &'a Self>,
+    {
+        let mut total: Option<Self> = None;
+        let mut count = 0;
+
+        for rate in rates {
+            count += 1;
+
+            total = Some(match total {
+                None => rate.clone(),
+                Some(total) => Self {
+                    mutation_rates: total
+                        .mutation_rates
+                        .iter()
+                        .zip(rate.mutation_rates.iter())
+                        .map(|(total, rate)| total + rate)
+                        .collect(),
+                    local_search_rate: total.local_search_rate + rate.local_search_rate,
+                },
+            });
+        }
+
+        total.map(|total| Self {
+            mutation_rates: total
+                .mutation_rates
+                .iter()
+                .map(|rate| rate / count as f32)
+                .collect(),
+            local_search_rate: total.local_search_rate / count as f32,
+        })
+    }
+}
@@ -0,0 +1,121 @@
+use rstest::{fixture, rstest};
+
+use crate::solvers::genetic::adaptation::{
+    adaptation_parameters::{AdaptationParameters, AdaptationStrategy},
+    generation_parameters::{GenerationFeedback, GenerationParameters, SuccessCounter},
+};
+
+#[fixture]
+fn generation_parameters() -> GenerationParameters {
+    GenerationParameters {
+        mutation_rates: vec![0.1, 0.002],
+        local_search_rate: 0.2,
+        elite_size: 3,
+    }
+}
+
+fn feedback(mutation: SuccessCounter, diversity: f32) -> GenerationFeedback {
+    GenerationFeedback {
+        mutation,
+        local_search: Default::default(),
+        diversity,
+    }
+}
+
+#[rstest]
+#[case::no_attempts(SuccessCounter { attempts: 0, successes: 0 }, None)]
+#[case::some_successes(SuccessCounter { attempts: 4, successes: 1 }, Some(0.25))]
+fn test_success_counter_rate(#[case] counter: SuccessCounter, #[case] expected: Option<f32>) {
+    assert_eq!(counter.get_rate(), expected);
+}
+
+#[rstest]
+fn test_fixed_strategy_keeps_the_parameters(generation_parameters: GenerationParameters) {
+    let adaptation = AdaptationParameters::default();
+    let mut parameters = generation_parameters.clone();
+
+    adaptation.adapt(
+        &mut parameters,
+        &feedback(
+            SuccessCounter {
+                attempts: 2,
+                successes: 2,
+            },
+            0.0,
+        ),
+    );
+
+    assert_eq!(parameters, generation_parameters);
+}
+
+#[rstest]
+#[case::successful(SuccessCounter { attempts: 4, successes: 2 }, vec![0.2, 0.004])]
+#[case::unsuccessful(SuccessCounter { attempts: 4, successes: 0 }, vec![0.05, 0.001])]
+#[case::not_applied(SuccessCounter { attempts: 0, successes: 0 }, vec![0.1, 0.002])]
+fn test_success_based_strategy_scales_the_mutation_rates(
+    generation_parameters: GenerationParameters,
+    #[case] mutation: SuccessCounter,
+    #[case] expected: Vec<f32>,
+) {
+    let adaptation = AdaptationParameters {
+        strategy: AdaptationStrategy::SuccessBased {
+            target_success_rate: 0.2,
+            factor: 2.0,
+        },
+        ..Default::default()
+    };
+    let mut parameters = generation_parameters;
+
+    adaptation.adapt(&mut parameters, &feedback(mutation, 0.0));
+
+    assert_eq!(parameters.mutation_rates, expected);
+    assert_eq!(parameters.local_search_rate, 0.2);
+    assert_eq!(parameters.elite_size, 3);
+}
+
+#[rstest]
+#[case::low_diversity(0.1, vec![0.2, 0.004], 0.1, 2)]
+#[case::high_diversity(0.9, vec![0.05, 0.001], 0.4, 4)]
+fn test_diversity_based_strategy_follows_the_diversity(
+    generation_parameters: GenerationParameters,
+    #[case] diversity: f32,
+    #[case] expected_mutation_rates: Vec<f32>,
+    #[case] expected_local_search_rate: f32,
+    #[case] expected_elite_size: usize,
+) {
+    let adaptation = AdaptationParameters {
+        strategy: AdaptationStrategy::DiversityBased {
+            target_diversity: 0.5,
+            factor: 2.0,
+        },
+        ..Default::default()
+    };
+    let mut parameters = generation_parameters;
+
+    adaptation.adapt(&mut parameters, &feedback(Default::default(), diversity));
+
+    assert_eq!(parameters.mutation_rates, expected_mutation_rates);
+    assert_eq!(parameters.local_search_rate, expected_local_search_rate);
+    assert_eq!(parameters.elite_size, expected_elite_size);
+}
+
+#[rstest]
+fn test_adaptation_respects_the_bounds(generation_parameters: GenerationParameters) {
+    let adaptation = AdaptationParameters {
+        strategy: AdaptationStrategy::DiversityBased {
+            target_diversity: 0.5,
+            factor: 10.0,
+        },
+        max_rate: 0.3,
+        min_elite_size: 3,
+        ..Default::default()
+    };
+    let mut parameters = generation_parameters;
+
+    adaptation.adapt(&mut parameters, &feedback(Default::default(), 0.0));
+
+    assert_eq!(parameters.mutation_rates[0], 0.3);
+    assert!((parameters.mutation_rates[1] - 0.02).abs() < 1e-6);
+    assert!((parameters.local_search_rate - 0.02).abs() < 1e-6);
+    assert_eq!(parameters.elite_size, 3);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod adaptation_parameters_test;
+#[cfg(test)]
+mod self_adaptive_rates_test;
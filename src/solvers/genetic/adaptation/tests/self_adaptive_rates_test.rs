@@ -0,0 +1,61 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::solvers::genetic::adaptation::{
+    adaptation_parameters::AdaptationParameters, self_adaptive_rates::SelfAdaptiveRates,
+};
+
+fn rates(mutation_rates: Vec<f32>, local_search_rate: f32) -> SelfAdaptiveRates {
+    SelfAdaptiveRates {
+        mutation_rates,
+        local_search_rate,
+    }
+}
+
+#[rstest]
+fn test_offspring_inherit_the_average_rates_without_learning() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let offspring = SelfAdaptiveRates::inherit(
+        &rates(vec![0.1, 0.2], 0.1),
+        &rates(vec![0.3, 0.4], 0.3),
+        0.0,
+        &Default::default(),
+        &mut rng,
+    );
+
+    assert_eq!(offspring, rates(vec![0.2, 0.3], 0.2));
+}
+
+#[rstest]
+fn test_inherited_rates_stay_within_the_bounds() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let adaptation = AdaptationParameters::default();
+
+    for _ in 0..50 {
+        let offspring = SelfAdaptiveRates::inherit(
+            &rates(vec![0.5], 0.001),
+            &rates(vec![0.5], 0.001),
+            3.0,
+            &adaptation,
+            &mut rng,
+        );
+
+        assert!(offspring
+            .mutation_rates
+            .iter()
+            .chain([offspring.local_search_rate].iter())
+            .all(|rate| (adaptation.min_rate..=adaptation.max_rate).contains(rate)));
+    }
+}
+
+#[rstest]
+fn test_averages_the_rates() {
+    let population_rates = [rates(vec![0.1, 0.5], 0.2), rates(vec![0.3, 0.1], 0.4)];
+
+    let average = SelfAdaptiveRates::average(population_rates.iter());
+
+    assert_eq!(SelfAdaptiveRates::average([].iter()), None);
+    assert_eq!(average, Some(rates(vec![0.2, 0.3], 0.3)));
+}
@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        gene_sequence,
+        individual::{Chromosome, Gene, Individual},
+    },
+};
+
+use super::crossover_operator::CrossoverOperator;
+
+#[derive(Clone)]
+pub struct BestCostRouteCrossover {
+    max_of_tries: u8,
+}
+
+impl<R: Rng + ?Sized> CrossoverOperator<R> for BestCostRouteCrossover {
+    fn run(
+        &self,
+        parent1: Individual,
+        parent2: Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> Option<Individual> {
+        let candidates: Vec<&Chromosome> = parent1
+            .chromosomes
+            .iter()
+            .filter(|chromosome| !gene_sequence::get_customers(chromosome).is_empty())
+            .collect();
+
+        let mut genes = gene_sequence::get_customers(candidates.choose(rng)?);
+        genes.shuffle(rng);
+
+        Self::reinsert_genes(parent2, &genes, distance_service)
+    }
+
+    fn max_of_tries(&self) -> u8 {
+        self.max_of_tries
+    }
+}
+
+impl BestCostRouteCrossover {
+    pub fn new(max_of_tries: u8) -> Self {
+        Self { max_of_tries }
+    }
+
+    pub(super) fn reinsert_genes(
+        parent: Individual,
+        genes: &[Gene],
+        distance_service: &DistanceService,
+    ) -> Option<Individual> {
+        let removed: HashSet<Gene> = genes.iter().copied().collect();
+        let mut chromosomes = parent.chromosomes;
+
+        gene_sequence::remove_genes(&mut chromosomes, &removed, distance_service)?;
+        gene_sequence::insert_at_cheapest_positions(&mut chromosomes, genes, distance_service)?;
+
+        Some(Individual::new(chromosomes))
+    }
+}
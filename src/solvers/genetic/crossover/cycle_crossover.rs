@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        gene_sequence,
+        individual::{Gene, Individual},
+    },
+};
+
+use super::crossover_operator::CrossoverOperator;
+
+#[derive(Clone)]
+pub struct CycleCrossover {
+    max_of_tries: u8,
+}
+
+impl<R: Rng + ?Sized> CrossoverOperator<R> for CycleCrossover {
+    fn run(
+        &self,
+        parent1: Individual,
+        parent2: Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> Option<Individual> {
+        let genes1 = gene_sequence::flatten(&parent1);
+        let genes2 = gene_sequence::flatten(&parent2);
+
+        if genes1.is_empty() || genes1.len() != genes2.len() {
+            return None;
+        }
+
+        let genes = match rng.gen_bool(0.5) {
+            true => Self::combine_cycles(&genes1, &genes2),
+            false => Self::combine_cycles(&genes2, &genes1),
+        };
+
+        gene_sequence::rebuild(&parent1, &genes, distance_service)
+    }
+
+    fn max_of_tries(&self) -> u8 {
+        self.max_of_tries
+    }
+}
+
+impl CycleCrossover {
+    pub fn new(max_of_tries: u8) -> Self {
+        Self { max_of_tries }
+    }
+
+    pub(super) fn combine_cycles(parent1: &[Gene], parent2: &[Gene]) -> Vec<Gene> {
+        let positions: HashMap<Gene, usize> = parent1
+            .iter()
+            .enumerate()
+            .map(|(index, gene)| (*gene, index))
+            .collect();
+
+        let mut offspring: Vec<Option<Gene>> = vec![None; parent1.len()];
+        let mut from_first_parent = true;
+
+        for start in 0..parent1.len() {
+            if offspring[start].is_some() {
+                continue;
+            }
+
+            let mut index = start;
+
+            while offspring[index].is_none() {
+                offspring[index] = match from_first_parent {
+                    true => Some(parent1[index]),
+                    false => Some(parent2[index]),
+                };
+
+                index = positions[&parent2[index]];
+            }
+
+            from_first_parent = !from_first_parent;
+        }
+
+        offspring.into_iter().flatten().collect()
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use rand::{
+    seq::{IteratorRandom, SliceRandom},
+    Rng,
+};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        gene_sequence,
+        individual::{Gene, Individual},
+    },
+};
+
+use super::crossover_operator::CrossoverOperator;
+
+type AdjacencyMap = HashMap<Gene, Vec<Gene>>;
+
+#[derive(Clone)]
+pub struct EdgeRecombinationCrossover {
+    max_of_tries: u8,
+}
+
+impl<R: Rng + ?Sized> CrossoverOperator<R> for EdgeRecombinationCrossover {
+    fn run(
+        &self,
+        parent1: Individual,
+        parent2: Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> Option<Individual> {
+        let genes1 = gene_sequence::flatten(&parent1);
+        let genes2 = gene_sequence::flatten(&parent2);
+
+        if genes1.is_empty() || genes1.len() != genes2.len() {
+            return None;
+        }
+
+        let adjacency = Self::build_adjacency(&parent1, &parent2);
+        let genes = Self::recombine(&genes1, adjacency, rng);
+
+        gene_sequence::rebuild(&parent1, &genes, distance_service)
+    }
+
+    fn max_of_tries(&self) -> u8 {
+        self.max_of_tries
+    }
+}
+
+impl EdgeRecombinationCrossover {
+    pub fn new(max_of_tries: u8) -> Self {
+        Self { max_of_tries }
+    }
+
+    pub(super) fn build_adjacency(parent1: &Individual, parent2: &Individual) -> AdjacencyMap {
+        let mut adjacency = AdjacencyMap::new();
+
+        for chromosome in parent1.chromosomes.iter().chain(parent2.chromosomes.iter()) {
+            let customers = gene_sequence::get_customers(chromosome);
+
+            for gene in customers.iter() {
+                adjacency.entry(*gene).or_default();
+            }
+
+            for pair in customers.windows(2) {
+                Self::add_edge(&mut adjacency, pair[0], pair[1]);
+                Self::add_edge(&mut adjacency, pair[1], pair[0]);
+            }
+        }
+
+        adjacency
+    }
+
+    fn add_edge(adjacency: &mut AdjacencyMap, from: Gene, to: Gene) {
+        let neighbors = adjacency.entry(from).or_default();
+
+        if !neighbors.contains(&to) {
+            neighbors.push(to);
+        }
+    }
+
+    pub(super) fn recombine<R: Rng + ?Sized>(
+        parent_genes: &[Gene],
+        mut adjacency: AdjacencyMap,
+        rng: &mut R,
+    ) -> Vec<Gene> {
+        let mut genes = Vec::with_capacity(parent_genes.len());
+        let mut remaining: Vec<Gene> = parent_genes.to_vec();
+        let mut current = parent_genes.first().copied();
+
+        while let Some(gene) = current {
+            genes.push(gene);
+            remaining.retain(|remaining_gene| *remaining_gene != gene);
+
+            let neighbors = adjacency.remove(&gene).unwrap_or_default();
+
+            for neighbor_list in adjacency.values_mut() {
+                neighbor_list.retain(|neighbor| *neighbor != gene);
+            }
+
+            let fewest_edges = neighbors
+                .iter()
+                .filter_map(|neighbor| adjacency.get(neighbor).map(|edges| edges.len()))
+                .min();
+
+            current = match fewest_edges {
+                Some(fewest_edges) => neighbors
+                    .iter()
+                    .filter(|neighbor| adjacency[*neighbor].len() == fewest_edges)
+                    .choose(rng)
+                    .copied(),
+                None => remaining.choose(rng).copied(),
+            };
+        }
+
+        genes
+    }
+}
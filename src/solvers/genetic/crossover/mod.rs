@@ -1,5 +1,10 @@
+pub mod best_cost_route_crossover;
 pub(crate) mod crossover_operator;
+pub mod cycle_crossover;
+pub mod edge_recombination_crossover;
 pub(crate) mod offspring;
 pub mod order_crossover;
 mod parent_slice;
-mod tests;
+pub mod partially_mapped_crossover;
+pub mod selective_route_exchange_crossover;
+pub(super) mod tests;
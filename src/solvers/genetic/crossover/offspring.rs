@@ -13,6 +13,8 @@ pub(crate) struct Offspring<'a, R: Rng + ?Sized, T: CrossoverOperator<R> + ?Size
     pub(super) parent1: Individual,
     pub(super) parent2: Individual,
     pub(crate) individual: Individual,
+    /// Children the operator produced, each of which had its fitness computed.
+    pub(crate) evaluations: u32,
     _t: PhantomData<(&'a T, R)>,
 }
 
@@ -23,6 +25,7 @@ impl<'a, R: Rng + ?Sized, T: CrossoverOperator<R> + ?Sized> Offspring<'a, R, T>
             parent2,
             crossover_op,
             individual: Default::default(),
+            evaluations: 0,
             _t: PhantomData,
         }
     }
@@ -40,11 +43,17 @@ impl<'a, R: Rng + ?Sized, T: CrossoverOperator<R> + ?Sized> Offspring<'a, R, T>
                 distance_service,
             );
 
-            if individual.is_none() {
-                continue;
-            }
+            let individual = match individual {
+                Some(individual) => individual,
+                None => continue,
+            };
+
+            self.evaluations += 1;
 
-            self.individual = individual.unwrap();
+            self.individual = match individual.is_feasible() {
+                true => individual,
+                false => continue,
+            };
 
             if self.has_evolved() {
                 return Some(());
@@ -54,6 +63,29 @@ impl<'a, R: Rng + ?Sized, T: CrossoverOperator<R> + ?Sized> Offspring<'a, R, T>
         None
     }
 
+    /// Keeps the first child the operator produces, even if it is infeasible or worse than
+    /// its parents.
+    pub(crate) fn try_to_generate(
+        &mut self,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> Option<()> {
+        for _ in 0..self.crossover_op.max_of_tries() {
+            if let Some(individual) = self.crossover_op.run(
+                self.parent1.clone(),
+                self.parent2.clone(),
+                rng,
+                distance_service,
+            ) {
+                self.evaluations += 1;
+                self.individual = individual;
+                return Some(());
+            }
+        }
+
+        None
+    }
+
     pub(crate) fn has_evolved(&self) -> bool {
         if self.individual.fitness >= self.parent1.fitness {
             return false;
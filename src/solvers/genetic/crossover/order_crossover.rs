@@ -25,7 +25,7 @@ impl<R: Rng + ?Sized> CrossoverOperator<R> for OrderCrossover {
         let mut offspring_chromosomes: Vec<Chromosome> = Vec::new();
 
         for chromosome in parent2.chromosomes {
-            let merged_chromosome = parent_slice.merge_into(chromosome, distance_service)?;
+            let merged_chromosome = parent_slice.merge_into(chromosome, distance_service);
             offspring_chromosomes.push(merged_chromosome);
         }
 
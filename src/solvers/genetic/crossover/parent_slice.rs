@@ -84,16 +84,26 @@ impl ParentSlice {
             self.slice.first().expect(EMPTY_PARENT_SLICE_MESSAGE),
         );
 
-        let new_distance_after = match genes.len() == 1 {
-            true => 0.0,
+        let is_depot_only = genes.len() == 1;
+        let depot = genes[beginning_of_slice];
+
+        let new_distance_after = match is_depot_only {
+            true => distance_service
+                .get_distance(self.slice.last().expect(EMPTY_PARENT_SLICE_MESSAGE), &depot),
             false => distance_service.get_distance(
                 self.slice.last().expect(EMPTY_PARENT_SLICE_MESSAGE),
                 &genes[end_of_slice],
             ),
         };
 
+        let mut slice = self.slice;
+
+        if is_depot_only {
+            slice.push(depot);
+        }
+
         individual.chromosomes[insertion_point.0].add_multiple_stops_at(
-            self.slice,
+            slice,
             end_of_slice,
             self.cost + new_distance_before + new_distance_after - current_distance,
         );
@@ -126,29 +136,24 @@ impl ParentSlice {
         &self,
         chromosome: Chromosome,
         distance_service: &DistanceService,
-    ) -> Option<Chromosome> {
+    ) -> Chromosome {
         let offspring_vehicle = Vehicle::new(chromosome.vehicle.id, chromosome.vehicle.capacity);
         let mut offspring_chromosome = Chromosome::new(offspring_vehicle);
 
-        offspring_chromosome.add_stop(chromosome.stops[0], 0.0).ok();
-
-        let unrepeated_genes: Vec<Gene> = Self::drop_gene_duplicates(&chromosome, &self.gene_set);
+        let mut unrepeated_genes: Vec<Gene> =
+            Self::drop_gene_duplicates(&chromosome, &self.gene_set);
 
         if unrepeated_genes.len() == 2 {
-            return Some(offspring_chromosome);
+            unrepeated_genes.pop();
         }
 
-        unrepeated_genes
-            .windows(2)
-            .map(|window| {
-                (
-                    window[1],
-                    distance_service.get_distance(&window[0], &window[1]),
-                )
-            })
-            .try_for_each(|(gene, distance)| offspring_chromosome.add_stop(gene, distance))
-            .ok()?;
-
-        Some(offspring_chromosome)
+        if offspring_chromosome
+            .replace_stops(unrepeated_genes.clone(), distance_service)
+            .is_err()
+        {
+            offspring_chromosome.replace_stops_unchecked(unrepeated_genes, distance_service);
+        }
+
+        offspring_chromosome
     }
 }
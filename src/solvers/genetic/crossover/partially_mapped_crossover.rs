@@ -0,0 +1,82 @@
+use std::{collections::HashMap, ops::Range};
+
+use rand::Rng;
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        gene_sequence,
+        individual::{Gene, Individual},
+    },
+};
+
+use super::crossover_operator::CrossoverOperator;
+
+#[derive(Clone)]
+pub struct PartiallyMappedCrossover {
+    max_of_tries: u8,
+}
+
+impl<R: Rng + ?Sized> CrossoverOperator<R> for PartiallyMappedCrossover {
+    fn run(
+        &self,
+        parent1: Individual,
+        parent2: Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> Option<Individual> {
+        let genes1 = gene_sequence::flatten(&parent1);
+        let genes2 = gene_sequence::flatten(&parent2);
+
+        if genes1.len() < 2 || genes1.len() != genes2.len() {
+            return None;
+        }
+
+        let start = rng.gen_range(0..genes1.len() - 1);
+        let end = rng.gen_range(start + 1..=genes1.len());
+
+        let genes = Self::map_partially(&genes1, &genes2, start..end);
+
+        gene_sequence::rebuild(&parent1, &genes, distance_service)
+    }
+
+    fn max_of_tries(&self) -> u8 {
+        self.max_of_tries
+    }
+}
+
+impl PartiallyMappedCrossover {
+    pub fn new(max_of_tries: u8) -> Self {
+        Self { max_of_tries }
+    }
+
+    pub(super) fn map_partially(
+        parent1: &[Gene],
+        parent2: &[Gene],
+        segment: Range<usize>,
+    ) -> Vec<Gene> {
+        let mapping: HashMap<Gene, Gene> = parent1[segment.clone()]
+            .iter()
+            .zip(parent2[segment.clone()].iter())
+            .map(|(gene1, gene2)| (*gene1, *gene2))
+            .collect();
+
+        parent2
+            .iter()
+            .enumerate()
+            .map(|(index, gene)| {
+                if segment.contains(&index) {
+                    return parent1[index];
+                }
+
+                let mut gene = *gene;
+
+                while let Some(mapped_gene) = mapping.get(&gene) {
+                    gene = *mapped_gene;
+                }
+
+                gene
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,118 @@
+use std::{collections::HashSet, slice};
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        gene_sequence,
+        individual::{Gene, Individual},
+    },
+};
+
+use super::crossover_operator::CrossoverOperator;
+
+#[derive(Clone)]
+pub struct SelectiveRouteExchangeCrossover {
+    max_of_tries: u8,
+}
+
+impl<R: Rng + ?Sized> CrossoverOperator<R> for SelectiveRouteExchangeCrossover {
+    fn run(
+        &self,
+        parent1: Individual,
+        parent2: Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> Option<Individual> {
+        let candidates: Vec<usize> = (0..parent1.chromosomes.len())
+            .filter(|index| !gene_sequence::get_customers(&parent1.chromosomes[*index]).is_empty())
+            .collect();
+
+        if candidates.is_empty() {
+            return None;
+        }
+
+        let max_of_routes = (candidates.len() / 2).max(1);
+        let number_of_routes = rng.gen_range(1..=max_of_routes);
+
+        let selected: Vec<usize> = candidates
+            .choose_multiple(rng, number_of_routes)
+            .copied()
+            .collect();
+
+        Self::exchange_routes(&parent1, parent2, &selected, distance_service)
+    }
+
+    fn max_of_tries(&self) -> u8 {
+        self.max_of_tries
+    }
+}
+
+impl SelectiveRouteExchangeCrossover {
+    pub fn new(max_of_tries: u8) -> Self {
+        Self { max_of_tries }
+    }
+
+    pub(super) fn exchange_routes(
+        parent1: &Individual,
+        parent2: Individual,
+        selected: &[usize],
+        distance_service: &DistanceService,
+    ) -> Option<Individual> {
+        let depot = gene_sequence::get_depot(parent1);
+        let mut chromosomes = parent2.chromosomes;
+        let mut replaced: Vec<usize> = Vec::with_capacity(selected.len());
+
+        for index in selected {
+            let donor: HashSet<Gene> = gene_sequence::get_customers(&parent1.chromosomes[*index])
+                .into_iter()
+                .collect();
+
+            let closest = (0..chromosomes.len())
+                .filter(|candidate| !replaced.contains(candidate))
+                .max_by_key(|candidate| {
+                    gene_sequence::get_customers(&chromosomes[*candidate])
+                        .iter()
+                        .filter(|gene| donor.contains(gene))
+                        .count()
+                })?;
+
+            replaced.push(closest);
+        }
+
+        let donated: Vec<Vec<Gene>> = selected
+            .iter()
+            .map(|index| gene_sequence::get_customers(&parent1.chromosomes[*index]))
+            .collect();
+
+        let donated_genes: HashSet<Gene> = donated.iter().flatten().copied().collect();
+
+        let missing: Vec<Gene> = replaced
+            .iter()
+            .flat_map(|index| gene_sequence::get_customers(&chromosomes[*index]))
+            .filter(|gene| !donated_genes.contains(gene))
+            .collect();
+
+        for (index, customers) in replaced.iter().zip(donated.iter()) {
+            let vehicle = chromosomes[*index].vehicle;
+
+            chromosomes[*index] =
+                gene_sequence::build_chromosome(&vehicle, depot, customers, distance_service)?;
+        }
+
+        for (index, chromosome) in chromosomes.iter_mut().enumerate() {
+            if !replaced.contains(&index) {
+                gene_sequence::remove_genes(
+                    slice::from_mut(chromosome),
+                    &donated_genes,
+                    distance_service,
+                )?;
+            }
+        }
+
+        gene_sequence::insert_at_cheapest_positions(&mut chromosomes, &missing, distance_service)?;
+
+        Some(Individual::new(chromosomes))
+    }
+}
@@ -0,0 +1,49 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::crossover::{
+        best_cost_route_crossover::BestCostRouteCrossover,
+        offspring::Offspring,
+        tests::order_crossover::fixtures::parents_fixture::{parents, Parents},
+    },
+    tests::fixtures::services_fixture::distance_service,
+};
+
+#[rstest]
+fn test_reinserts_the_genes_at_the_cheapest_positions(
+    parents: Parents,
+    distance_service: DistanceService,
+) {
+    let offspring =
+        BestCostRouteCrossover::reinsert_genes(parents.1, &[Stop::new(3, 0)], &distance_service)
+            .unwrap();
+
+    let ids: Vec<u32> = offspring.chromosomes[0]
+        .stops
+        .iter()
+        .map(|gene| gene.id)
+        .collect();
+
+    assert_eq!(ids, vec![0, 2, 3, 1, 0]);
+    assert_eq!(offspring.chromosomes[1].stops.len(), 1);
+    assert_eq!(offspring.fitness, 8.0);
+}
+
+#[rstest]
+fn test_offspring_can_evolve(parents: Parents, distance_service: DistanceService) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let crossover_op = BestCostRouteCrossover::new(100);
+
+    let mut offspring = Offspring::new(parents.0.clone(), parents.1.clone(), &crossover_op);
+
+    offspring
+        .try_to_evolve(&mut rng, &distance_service)
+        .unwrap();
+
+    assert!(offspring.individual.fitness < parents.0.fitness);
+    assert!(offspring.individual.fitness < parents.1.fitness);
+}
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod best_cost_route_crossover_test;
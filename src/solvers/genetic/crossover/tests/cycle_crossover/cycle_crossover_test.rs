@@ -0,0 +1,52 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::crossover::{
+        crossover_operator::CrossoverOperator,
+        cycle_crossover::CycleCrossover,
+        tests::order_crossover::fixtures::parents_fixture::{parents, Parents},
+    },
+    tests::fixtures::{
+        services_fixture::distance_service,
+        stops_fixture::{ids, unloaded_stops},
+    },
+};
+
+#[rstest]
+fn test_alternates_the_parents_between_cycles() {
+    let parent1 = unloaded_stops(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let parent2 = unloaded_stops(&[8, 5, 2, 1, 3, 6, 4, 7]);
+
+    let offspring = CycleCrossover::combine_cycles(&parent1, &parent2);
+
+    assert_eq!(ids(&offspring), vec![1, 5, 2, 4, 3, 6, 7, 8]);
+}
+
+#[rstest]
+fn test_keeps_every_gene_at_a_position_of_one_parent() {
+    let parent1 = unloaded_stops(&[3, 1, 4, 2, 6, 5]);
+    let parent2 = unloaded_stops(&[1, 3, 2, 4, 5, 6]);
+
+    let offspring = CycleCrossover::combine_cycles(&parent1, &parent2);
+
+    for (index, gene) in offspring.iter().enumerate() {
+        assert!(*gene == parent1[index] || *gene == parent2[index]);
+    }
+}
+
+#[rstest]
+fn test_can_generate_a_offspring(parents: Parents, distance_service: DistanceService) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let crossover_op = CycleCrossover::new(0);
+
+    let offspring = crossover_op
+        .run(parents.0, parents.1, &mut rng, &distance_service)
+        .unwrap();
+
+    assert_eq!(offspring.chromosomes.len(), 2);
+    assert_eq!(offspring.chromosomes[1].stops.len(), 3);
+    assert_eq!(offspring.fitness, 14.0);
+}
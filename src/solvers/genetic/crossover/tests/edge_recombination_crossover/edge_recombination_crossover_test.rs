@@ -0,0 +1,57 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::crossover::{
+        crossover_operator::CrossoverOperator,
+        edge_recombination_crossover::EdgeRecombinationCrossover,
+        tests::order_crossover::fixtures::parents_fixture::{parents, Parents},
+    },
+    tests::fixtures::services_fixture::distance_service,
+};
+
+#[rstest]
+fn test_recombination_follows_the_shared_edges(parents: Parents) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let adjacency = EdgeRecombinationCrossover::build_adjacency(&parents.0, &parents.0);
+    let genes = vec![Stop::new(1, 0), Stop::new(2, 0), Stop::new(3, 0)];
+
+    let offspring = EdgeRecombinationCrossover::recombine(&genes, adjacency, &mut rng);
+
+    let ids: Vec<u32> = offspring.iter().map(|gene| gene.id).collect();
+
+    assert_eq!(ids[..2], [1, 2]);
+    assert_eq!(ids.len(), 3);
+}
+
+#[rstest]
+fn test_adjacency_merges_the_edges_of_both_parents(parents: Parents) {
+    let adjacency = EdgeRecombinationCrossover::build_adjacency(&parents.0, &parents.1);
+
+    assert!(adjacency[&Stop::new(1, 0)] == vec![Stop::new(2, 0)]);
+    assert!(adjacency[&Stop::new(2, 0)] == vec![Stop::new(1, 0)]);
+    assert!(adjacency[&Stop::new(3, 0)].is_empty());
+}
+
+#[rstest]
+fn test_can_generate_a_offspring(parents: Parents, distance_service: DistanceService) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let crossover_op = EdgeRecombinationCrossover::new(0);
+
+    let offspring = crossover_op
+        .run(parents.0, parents.1, &mut rng, &distance_service)
+        .unwrap();
+
+    let customers: usize = offspring
+        .chromosomes
+        .iter()
+        .map(|chromosome| chromosome.stops.iter().filter(|gene| gene.id != 0).count())
+        .sum();
+
+    assert_eq!(customers, 3);
+    assert_ne!(offspring.fitness, 0.0);
+}
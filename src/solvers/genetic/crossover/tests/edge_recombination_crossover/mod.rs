@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod edge_recombination_crossover_test;
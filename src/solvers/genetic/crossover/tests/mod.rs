@@ -1 +1,6 @@
-mod order_crossover;
+mod best_cost_route_crossover;
+mod cycle_crossover;
+mod edge_recombination_crossover;
+pub(crate) mod order_crossover;
+mod partially_mapped_crossover;
+mod selective_route_exchange_crossover;
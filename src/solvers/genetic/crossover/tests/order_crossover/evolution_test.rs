@@ -43,4 +43,22 @@ fn test_offspring_can_evolve(parents: Parents, distance_service: DistanceService
 
     assert!(offspring.individual.fitness < parents.0.fitness);
     assert!(offspring.individual.fitness < parents.1.fitness);
+    assert!((1..=100).contains(&offspring.evaluations));
+}
+
+#[rstest]
+fn test_generated_offspring_counts_one_evaluation(
+    parents: Parents,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let crossover_op = OrderCrossover::new(100);
+
+    let mut offspring = Offspring::new(parents.0.clone(), parents.1.clone(), &crossover_op);
+
+    offspring
+        .try_to_generate(&mut rng, &distance_service)
+        .unwrap();
+
+    assert_eq!(offspring.evaluations, 1);
 }
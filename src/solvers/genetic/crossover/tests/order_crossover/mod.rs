@@ -4,4 +4,4 @@ mod parent_slice_test;
 #[cfg(test)]
 mod evolution_test;
 
-mod fixtures;
+pub(crate) mod fixtures;
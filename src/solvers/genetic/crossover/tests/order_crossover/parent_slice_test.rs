@@ -4,7 +4,9 @@ use crate::solvers::genetic::crossover::tests::order_crossover::fixtures::parent
     parent_slice_factory, ParentSliceFactory,
 };
 use crate::solvers::genetic::individual::Individual;
-use crate::solvers::genetic::tests::fixtures::{individual_factory, IndividualFactory};
+use crate::solvers::genetic::tests::fixtures::{
+    build_unchecked_individual, individual_factory, IndividualFactory,
+};
 use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
 use crate::tests::fixtures::services_fixture::distance_service;
 use crate::tests::fixtures::stops_fixture::stops;
@@ -59,9 +61,7 @@ fn test_can_generate_offspring_chromosome(
     let (_, parent1_slice) = parent_slice_factory(2);
     let parent2 = individual_factory(1);
 
-    let chromosome = parent1_slice
-        .merge_into(parent2.chromosomes[0].clone(), &distance_service)
-        .unwrap();
+    let chromosome = parent1_slice.merge_into(parent2.chromosomes[0].clone(), &distance_service);
 
     assert_eq!(chromosome.stops.len(), 3);
 }
@@ -75,9 +75,7 @@ fn test_can_generate_offspring_dropping_all_genes(
     let (_, parent1_slice) = parent_slice_factory(3);
     let parent2 = individual_factory(1);
 
-    let chromosome = parent1_slice
-        .merge_into(parent2.chromosomes[0].clone(), &distance_service)
-        .unwrap();
+    let chromosome = parent1_slice.merge_into(parent2.chromosomes[0].clone(), &distance_service);
 
     assert_eq!(chromosome.stops.len(), 1);
 }
@@ -125,3 +123,26 @@ fn test_can_insert_parent_slice_with_correct_fitness(
             .floor()
     );
 }
+
+#[rstest]
+fn test_merge_keeps_an_overloaded_chromosome(distance_service: DistanceService) {
+    let genes = [
+        Stop::new(0, 0),
+        Stop::new(1, 6),
+        Stop::new(2, 6),
+        Stop::new(3, 6),
+    ];
+    let parent2 = build_unchecked_individual(
+        vec![(10, vec![genes[0], genes[1], genes[2], genes[3], genes[0]])],
+        &distance_service,
+    );
+
+    let slice = ParentSlice::new(vec![genes[2]], &distance_service);
+
+    let chromosome = slice.merge_into(parent2.chromosomes[0].clone(), &distance_service);
+
+    let ids: Vec<u32> = chromosome.stops.iter().map(|gene| gene.id).collect();
+
+    assert_eq!(ids, vec![0, 1, 3, 0]);
+    assert_eq!(chromosome.total_distance(), 8.0);
+}
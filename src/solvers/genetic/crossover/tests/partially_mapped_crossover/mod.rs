@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod partially_mapped_crossover_test;
@@ -0,0 +1,58 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::crossover::{
+        crossover_operator::CrossoverOperator,
+        partially_mapped_crossover::PartiallyMappedCrossover,
+        tests::order_crossover::fixtures::parents_fixture::{parents, Parents},
+    },
+    tests::fixtures::{
+        services_fixture::distance_service,
+        stops_fixture::{ids, unloaded_stops},
+    },
+};
+
+#[rstest]
+fn test_maps_the_genes_outside_of_the_segment() {
+    let parent1 = unloaded_stops(&[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let parent2 = unloaded_stops(&[4, 5, 2, 1, 8, 7, 6, 9, 3]);
+
+    let offspring = PartiallyMappedCrossover::map_partially(&parent1, &parent2, 3..7);
+
+    assert_eq!(ids(&offspring), vec![1, 8, 2, 4, 5, 6, 7, 9, 3]);
+}
+
+#[rstest]
+fn test_copies_the_first_parent_when_the_segment_covers_it() {
+    let parent1 = unloaded_stops(&[1, 2, 3, 4]);
+    let parent2 = unloaded_stops(&[4, 3, 2, 1]);
+
+    let offspring = PartiallyMappedCrossover::map_partially(&parent1, &parent2, 0..4);
+
+    assert_eq!(ids(&offspring), ids(&parent1));
+}
+
+#[rstest]
+fn test_can_generate_a_offspring(parents: Parents, distance_service: DistanceService) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let crossover_op = PartiallyMappedCrossover::new(0);
+
+    let offspring = crossover_op
+        .run(parents.0, parents.1, &mut rng, &distance_service)
+        .unwrap();
+
+    let mut customers: Vec<u32> = offspring
+        .chromosomes
+        .iter()
+        .flat_map(|chromosome| chromosome.stops.iter())
+        .map(|gene| gene.id)
+        .filter(|id| *id != 0)
+        .collect();
+    customers.sort();
+
+    assert_eq!(customers, vec![1, 2, 3]);
+    assert_ne!(offspring.fitness, 0.0);
+}
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod selective_route_exchange_crossover_test;
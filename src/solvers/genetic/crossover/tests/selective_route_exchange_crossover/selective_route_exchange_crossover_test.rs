@@ -0,0 +1,73 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        crossover::{
+            crossover_operator::CrossoverOperator,
+            selective_route_exchange_crossover::SelectiveRouteExchangeCrossover,
+            tests::order_crossover::fixtures::parents_fixture::{parents, Parents},
+        },
+        individual::Individual,
+    },
+    tests::fixtures::{
+        routes_fixture::{route_factory, RouteFactory},
+        services_fixture::distance_service,
+        stops_fixture::{ids, stops},
+    },
+};
+
+#[rstest]
+fn test_exchanges_the_most_similar_route(parents: Parents, distance_service: DistanceService) {
+    let offspring = SelectiveRouteExchangeCrossover::exchange_routes(
+        &parents.0,
+        parents.1,
+        &[0],
+        &distance_service,
+    )
+    .unwrap();
+
+    assert_eq!(ids(&offspring.chromosomes[0].stops), vec![0, 1, 2, 0]);
+    assert_eq!(ids(&offspring.chromosomes[1].stops), vec![0, 3, 0]);
+}
+
+#[rstest]
+fn test_reinserts_the_customers_left_out_by_the_exchange(
+    parents: Parents,
+    stops: Vec<Stop>,
+    route_factory: RouteFactory,
+    distance_service: DistanceService,
+) {
+    let parent2 = Individual::new(vec![
+        route_factory(vec![stops[0], stops[1], stops[3], stops[0]]),
+        route_factory(vec![stops[0], stops[2], stops[0]]),
+    ]);
+
+    let offspring = SelectiveRouteExchangeCrossover::exchange_routes(
+        &parents.0,
+        parent2,
+        &[1],
+        &distance_service,
+    )
+    .unwrap();
+
+    assert_eq!(ids(&offspring.chromosomes[0].stops), vec![0, 1, 3, 0]);
+    assert_eq!(ids(&offspring.chromosomes[1].stops), vec![0, 2, 0]);
+    assert_eq!(offspring.fitness, 10.0);
+}
+
+#[rstest]
+fn test_can_generate_a_offspring(parents: Parents, distance_service: DistanceService) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let crossover_op = SelectiveRouteExchangeCrossover::new(0);
+
+    let offspring = crossover_op
+        .run(parents.0, parents.1, &mut rng, &distance_service)
+        .unwrap();
+
+    assert_eq!(offspring.chromosomes.len(), 2);
+    assert_eq!(offspring.fitness, 14.0);
+}
@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use crate::{
+    domain::stop::Stop,
+    solvers::genetic::{gene_sequence, individual::Individual},
+};
+
+/// The stops before and after each customer of a solution, with every route starting and ending
+/// at the depot. Built once per solution so that comparing it with many others stays cheap.
+#[derive(Clone)]
+pub(crate) struct Neighbors {
+    depot: u32,
+    links: HashMap<u32, (u32, u32)>,
+}
+
+impl Neighbors {
+    /// `routes` hold the customers of each route, without the depot.
+    pub(crate) fn new(routes: &[Vec<Stop>], depot: &Stop) -> Self {
+        let links = routes
+            .iter()
+            .flat_map(|route| {
+                route.iter().enumerate().map(|(index, stop)| {
+                    let predecessor = match index {
+                        0 => depot.id,
+                        _ => route[index - 1].id,
+                    };
+
+                    let successor = route.get(index + 1).map_or(depot.id, |next| next.id);
+
+                    (stop.id, (predecessor, successor))
+                })
+            })
+            .collect();
+
+        Self {
+            links,
+            depot: depot.id,
+        }
+    }
+
+    /// Edges of `self` missing from `other` per customer, with routes taken as undirected.
+    /// Identical solutions are at distance zero.
+    pub(crate) fn broken_pairs_distance(&self, other: &Neighbors) -> f32 {
+        if self.links.is_empty() {
+            return 0.0;
+        }
+
+        let broken_pairs = self
+            .links
+            .iter()
+            .map(|(stop, (predecessor1, successor1))| {
+                let (predecessor2, successor2) = match other.links.get(stop) {
+                    Some(links) => links,
+                    None => return 2,
+                };
+
+                let broken_successor = successor1 != successor2 && successor1 != predecessor2;
+                let broken_depot_link = *predecessor1 == self.depot
+                    && *predecessor2 != self.depot
+                    && *successor2 != self.depot;
+
+                broken_successor as usize + broken_depot_link as usize
+            })
+            .sum::<usize>();
+
+        broken_pairs as f32 / self.links.len() as f32
+    }
+}
+
+impl From<&Individual> for Neighbors {
+    fn from(individual: &Individual) -> Self {
+        let routes: Vec<Vec<Stop>> = individual
+            .chromosomes
+            .iter()
+            .map(gene_sequence::get_customers)
+            .collect();
+
+        Self::new(&routes, &gene_sequence::get_depot(individual))
+    }
+}
+
+pub fn broken_pairs_distance(individual1: &Individual, individual2: &Individual) -> f32 {
+    Neighbors::from(individual1).broken_pairs_distance(&Neighbors::from(individual2))
+}
+
+/// Distances between every pair of solutions; row `i` holds the distances from solution `i`.
+pub(crate) fn calculate_distances(neighbors: &[&Neighbors]) -> Vec<Vec<f32>> {
+    neighbors
+        .iter()
+        .map(|neighbors1| {
+            neighbors
+                .iter()
+                .map(|neighbors2| neighbors1.broken_pairs_distance(neighbors2))
+                .collect()
+        })
+        .collect()
+}
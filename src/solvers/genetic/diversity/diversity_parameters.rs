@@ -0,0 +1,28 @@
+#[derive(Clone, Debug, PartialEq)]
+pub enum DiversityRecovery {
+    /// Replaces this share of the non-elite individuals with random immigrants.
+    Immigrants { ratio: f32 },
+    /// Replaces every non-elite individual with random immigrants.
+    Restart,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiversityParameters {
+    pub duplicate_threshold: Option<f32>,
+    pub biased_fitness: bool,
+    pub close_individuals: usize,
+    pub min_diversity: Option<f32>,
+    pub recovery: DiversityRecovery,
+}
+
+impl Default for DiversityParameters {
+    fn default() -> Self {
+        Self {
+            duplicate_threshold: None,
+            biased_fitness: false,
+            close_individuals: 3,
+            min_diversity: None,
+            recovery: DiversityRecovery::Immigrants { ratio: 0.2 },
+        }
+    }
+}
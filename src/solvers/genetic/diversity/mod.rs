@@ -0,0 +1,4 @@
+pub mod broken_pairs_distance;
+pub mod diversity_parameters;
+pub(crate) mod population_diversity;
+mod tests;
@@ -0,0 +1,121 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{gene_sequence, individual::Individual},
+};
+
+use super::broken_pairs_distance::Neighbors;
+
+const MAX_IMMIGRATION_ATTEMPTS: usize = 10;
+
+pub(crate) fn is_duplicate<'a, I>(individual: &Individual, others: I, threshold: f32) -> bool
+where
+    I: IntoIterator<Item = &'a Individual>,
+{
+    let neighbors = Neighbors::from(individual);
+
+    others
+        .into_iter()
+        .any(|other| neighbors.broken_pairs_distance(&Neighbors::from(other)) <= threshold)
+}
+
+pub(crate) fn calculate_average_distance(individuals: &[Individual]) -> f32 {
+    let neighbors: Vec<Neighbors> = individuals.iter().map(Neighbors::from).collect();
+
+    let mut total_distance = 0.0;
+    let mut pairs = 0;
+
+    for (index, neighbors1) in neighbors.iter().enumerate() {
+        for neighbors2 in neighbors.iter().skip(index + 1) {
+            total_distance += neighbors1.broken_pairs_distance(neighbors2);
+            pairs += 1;
+        }
+    }
+
+    match pairs {
+        0 => 0.0,
+        _ => total_distance / pairs as f32,
+    }
+}
+
+/// Average distance from each solution to its `close_individuals` closest ones.
+fn calculate_contributions(distances: &[Vec<f32>], close_individuals: usize) -> Vec<f32> {
+    let number_of_closest = close_individuals.min(distances.len().saturating_sub(1));
+
+    if number_of_closest == 0 {
+        return vec![0.0; distances.len()];
+    }
+
+    distances
+        .iter()
+        .enumerate()
+        .map(|(index, row)| {
+            let mut closest: Vec<f32> = row
+                .iter()
+                .enumerate()
+                .filter(|(other_index, _)| *other_index != index)
+                .map(|(_, distance)| *distance)
+                .collect();
+
+            closest.sort_by(f32::total_cmp);
+
+            closest[..number_of_closest].iter().sum::<f32>() / number_of_closest as f32
+        })
+        .collect()
+}
+
+fn rank(values: &[f32], descending: bool) -> Vec<f32> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+
+    order.sort_by(|index1, index2| match descending {
+        true => values[*index2].total_cmp(&values[*index1]),
+        false => values[*index1].total_cmp(&values[*index2]),
+    });
+
+    let last_rank = (values.len() as f32 - 1.0).max(1.0);
+    let mut ranks = vec![0.0; values.len()];
+
+    for (rank, index) in order.into_iter().enumerate() {
+        ranks[index] = rank as f32 / last_rank;
+    }
+
+    ranks
+}
+
+/// Ranks each solution by cost and by diversity contribution, weighting the latter by the share
+/// of the population outside of the elite. `distances` is the matrix returned by
+/// `broken_pairs_distance::calculate_distances`. Lower values are better.
+pub(crate) fn calculate_biased_fitnesses(
+    costs: &[f32],
+    distances: &[Vec<f32>],
+    elite_size: usize,
+    close_individuals: usize,
+) -> Vec<f32> {
+    let contributions = calculate_contributions(distances, close_individuals);
+    let diversity_weight = 1.0 - elite_size as f32 / costs.len().max(1) as f32;
+
+    rank(costs, false)
+        .into_iter()
+        .zip(rank(&contributions, true))
+        .map(|(cost_rank, diversity_rank)| cost_rank + diversity_weight.max(0.0) * diversity_rank)
+        .collect()
+}
+
+pub(crate) fn generate_immigrant<R: Rng + ?Sized>(
+    template: &Individual,
+    rng: &mut R,
+    distance_service: &DistanceService,
+) -> Option<Individual> {
+    let mut genes = gene_sequence::flatten(template);
+
+    for _ in 0..MAX_IMMIGRATION_ATTEMPTS {
+        genes.shuffle(rng);
+
+        if let Some(immigrant) = gene_sequence::rebuild(template, &genes, distance_service) {
+            return Some(immigrant);
+        }
+    }
+
+    None
+}
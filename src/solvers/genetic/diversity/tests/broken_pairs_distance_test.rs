@@ -0,0 +1,56 @@
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    solvers::genetic::{
+        diversity::broken_pairs_distance::broken_pairs_distance,
+        tests::fixtures::{individual_builder, IndividualBuilder},
+    },
+    tests::fixtures::stops_fixture::stops,
+};
+
+#[rstest]
+fn test_identical_individuals_are_at_distance_zero(
+    stops: Vec<Stop>,
+    individual_builder: IndividualBuilder,
+) {
+    let individual = individual_builder(vec![
+        (10, vec![stops[0], stops[1], stops[2], stops[0]]),
+        (10, vec![stops[0], stops[3], stops[0]]),
+    ]);
+
+    assert_eq!(broken_pairs_distance(&individual, &individual.clone()), 0.0);
+}
+
+#[rstest]
+fn test_reversed_routes_keep_their_edges(stops: Vec<Stop>, individual_builder: IndividualBuilder) {
+    let individual1 = individual_builder(vec![
+        (10, vec![stops[0], stops[1], stops[2], stops[3], stops[0]]),
+        (10, vec![stops[0]]),
+    ]);
+
+    let individual2 = individual_builder(vec![
+        (10, vec![stops[0]]),
+        (10, vec![stops[0], stops[3], stops[2], stops[1], stops[0]]),
+    ]);
+
+    assert_eq!(broken_pairs_distance(&individual1, &individual2), 0.0);
+}
+
+#[rstest]
+fn test_counts_the_broken_edges_per_customer(
+    stops: Vec<Stop>,
+    individual_builder: IndividualBuilder,
+) {
+    let individual1 = individual_builder(vec![(
+        10,
+        vec![stops[0], stops[1], stops[2], stops[3], stops[0]],
+    )]);
+
+    let individual2 = individual_builder(vec![
+        (10, vec![stops[0], stops[1], stops[0]]),
+        (10, vec![stops[0], stops[2], stops[3], stops[0]]),
+    ]);
+
+    assert_eq!(broken_pairs_distance(&individual1, &individual2), 1.0 / 3.0);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod broken_pairs_distance_test;
+#[cfg(test)]
+mod population_diversity_test;
@@ -0,0 +1,105 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        diversity::{
+            broken_pairs_distance::{broken_pairs_distance, calculate_distances, Neighbors},
+            population_diversity,
+        },
+        gene_sequence,
+        individual::Individual,
+        tests::fixtures::{individual_builder, IndividualBuilder},
+    },
+    tests::fixtures::{services_fixture::distance_service, stops_fixture::stops},
+};
+
+fn build_individuals(stops: &[Stop], individual_builder: &IndividualBuilder) -> Vec<Individual> {
+    let individual = individual_builder(vec![
+        (10, vec![stops[0], stops[1], stops[3], stops[2], stops[0]]),
+        (10, vec![stops[0]]),
+    ]);
+
+    let different = individual_builder(vec![
+        (10, vec![stops[0], stops[1], stops[0]]),
+        (10, vec![stops[0], stops[2], stops[3], stops[0]]),
+    ]);
+
+    vec![individual.clone(), individual, different]
+}
+
+#[rstest]
+fn test_detects_duplicates(stops: Vec<Stop>, individual_builder: IndividualBuilder) {
+    let individuals = build_individuals(&stops, &individual_builder);
+
+    assert!(population_diversity::is_duplicate(
+        &individuals[0],
+        &individuals[1..],
+        0.0
+    ));
+    assert!(!population_diversity::is_duplicate(
+        &individuals[2],
+        &individuals[..2],
+        0.0
+    ));
+}
+
+#[rstest]
+fn test_average_distance_between_pairs(stops: Vec<Stop>, individual_builder: IndividualBuilder) {
+    let individuals = build_individuals(&stops, &individual_builder);
+    let distance = broken_pairs_distance(&individuals[0], &individuals[2]);
+
+    let average_distance = population_diversity::calculate_average_distance(&individuals);
+
+    assert!(distance > 0.0);
+    assert!((average_distance - 2.0 * distance / 3.0).abs() < 1e-6);
+}
+
+#[rstest]
+fn test_biased_fitness_favours_diverse_individuals(
+    stops: Vec<Stop>,
+    individual_builder: IndividualBuilder,
+) {
+    let individuals = build_individuals(&stops, &individual_builder);
+
+    assert!(individuals[2].fitness > individuals[1].fitness);
+
+    let costs: Vec<f32> = individuals
+        .iter()
+        .map(|individual| individual.fitness)
+        .collect();
+    let neighbors: Vec<Neighbors> = individuals.iter().map(Neighbors::from).collect();
+    let distances = calculate_distances(&neighbors.iter().collect::<Vec<_>>());
+
+    let biased_fitnesses =
+        population_diversity::calculate_biased_fitnesses(&costs, &distances, 1, 1);
+
+    assert!(biased_fitnesses[0] < biased_fitnesses[2]);
+    assert!(biased_fitnesses[2] < biased_fitnesses[1]);
+}
+
+#[rstest]
+fn test_immigrants_keep_every_customer(
+    stops: Vec<Stop>,
+    individual_builder: IndividualBuilder,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let individuals = build_individuals(&stops, &individual_builder);
+
+    let immigrant =
+        population_diversity::generate_immigrant(&individuals[0], &mut rng, &distance_service)
+            .unwrap();
+
+    let mut customers: Vec<u32> = gene_sequence::flatten(&immigrant)
+        .iter()
+        .map(|gene| gene.id)
+        .collect();
+    customers.sort();
+
+    assert_eq!(customers, vec![1, 2, 3]);
+    assert_eq!(immigrant.chromosomes.len(), 2);
+}
@@ -0,0 +1,263 @@
+use std::{cmp::Reverse, collections::HashSet};
+
+use crate::{
+    domain::{route::Route, vehicle::Vehicle},
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::individual::{Chromosome, Gene, Individual},
+};
+
+pub(super) fn get_depot(individual: &Individual) -> Gene {
+    individual.chromosomes[0].stops[0]
+}
+
+pub(super) fn get_customers(chromosome: &Chromosome) -> Vec<Gene> {
+    let depot = chromosome.stops[0];
+
+    chromosome
+        .stops
+        .iter()
+        .filter(|gene| **gene != depot)
+        .copied()
+        .collect()
+}
+
+pub(super) fn flatten(individual: &Individual) -> Vec<Gene> {
+    individual
+        .chromosomes
+        .iter()
+        .flat_map(get_customers)
+        .collect()
+}
+
+pub(super) fn calculate_load(genes: &[Gene], depot: &Gene) -> u32 {
+    2 * depot.usage + genes.iter().map(|gene| gene.usage).sum::<u32>()
+}
+
+pub(super) fn calculate_overload(chromosome: &Chromosome) -> u32 {
+    let load = calculate_load(&get_customers(chromosome), &chromosome.stops[0]);
+
+    chromosome.vehicle.calculate_excess_load(load)
+}
+
+pub(super) fn build_chromosome(
+    vehicle: &Vehicle,
+    depot: Gene,
+    customers: &[Gene],
+    distance_service: &DistanceService,
+) -> Option<Chromosome> {
+    let mut chromosome = Route::new(Vehicle::new(vehicle.id, vehicle.capacity));
+
+    let stops = match customers.is_empty() {
+        true => vec![depot],
+        false => [&[depot], customers, &[depot]].concat(),
+    };
+
+    chromosome.replace_stops(stops, distance_service).ok()?;
+
+    Some(chromosome)
+}
+
+pub(super) fn rebuild(
+    template: &Individual,
+    genes: &[Gene],
+    distance_service: &DistanceService,
+) -> Option<Individual> {
+    let depot = get_depot(template);
+    let sizes: Vec<usize> = template
+        .chromosomes
+        .iter()
+        .map(|chromosome| get_customers(chromosome).len())
+        .collect();
+
+    let mut routes: Vec<Vec<Gene>> = vec![Vec::new(); sizes.len()];
+    let mut route_index = 0;
+
+    for gene in genes {
+        loop {
+            let route = &routes[route_index];
+            let is_last = route_index == sizes.len() - 1;
+            let capacity = template.chromosomes[route_index].vehicle.capacity;
+
+            let is_full = !is_last && route.len() >= sizes[route_index];
+            let fits = calculate_load(route, &depot) + gene.usage < capacity;
+
+            if !is_full && fits {
+                break;
+            }
+
+            if is_last {
+                return None;
+            }
+
+            route_index += 1;
+        }
+
+        routes[route_index].push(*gene);
+    }
+
+    let chromosomes = template
+        .chromosomes
+        .iter()
+        .zip(routes.iter())
+        .map(|(chromosome, customers)| {
+            build_chromosome(&chromosome.vehicle, depot, customers, distance_service)
+        })
+        .collect::<Option<Vec<Chromosome>>>()?;
+
+    Some(Individual::new(chromosomes))
+}
+
+/// Fails when a chromosome stays overloaded without the removed genes, which only happens to
+/// infeasible individuals.
+pub(super) fn remove_genes(
+    chromosomes: &mut [Chromosome],
+    genes: &HashSet<Gene>,
+    distance_service: &DistanceService,
+) -> Option<()> {
+    for chromosome in chromosomes.iter_mut() {
+        let depot = chromosome.stops[0];
+
+        let customers: Vec<Gene> = get_customers(chromosome)
+            .into_iter()
+            .filter(|gene| !genes.contains(gene))
+            .collect();
+
+        *chromosome = build_chromosome(&chromosome.vehicle, depot, &customers, distance_service)?;
+    }
+
+    Some(())
+}
+
+pub(super) fn insert_at_cheapest_positions(
+    chromosomes: &mut [Chromosome],
+    genes: &[Gene],
+    distance_service: &DistanceService,
+) -> Option<()> {
+    for gene in genes {
+        let mut best_insertion: Option<(usize, usize, f32)> = None;
+
+        for (chromosome_index, chromosome) in chromosomes.iter().enumerate() {
+            let depot = chromosome.stops[0];
+            let customers = get_customers(chromosome);
+
+            if calculate_load(&customers, &depot) + gene.usage >= chromosome.vehicle.capacity {
+                continue;
+            }
+
+            for position in 0..=customers.len() {
+                let previous = match position {
+                    0 => &depot,
+                    _ => &customers[position - 1],
+                };
+
+                let next = customers.get(position).unwrap_or(&depot);
+
+                let cost = distance_service.get_distance(previous, gene)
+                    + distance_service.get_distance(gene, next)
+                    - distance_service.get_distance(previous, next);
+
+                match best_insertion {
+                    Some((_, _, best_cost)) if best_cost <= cost => (),
+                    _ => best_insertion = Some((chromosome_index, position, cost)),
+                }
+            }
+        }
+
+        let (chromosome_index, position, _) = best_insertion?;
+        let chromosome = &chromosomes[chromosome_index];
+
+        let mut customers = get_customers(chromosome);
+        customers.insert(position, *gene);
+
+        chromosomes[chromosome_index] = build_chromosome(
+            &chromosome.vehicle,
+            chromosome.stops[0],
+            &customers,
+            distance_service,
+        )?;
+    }
+
+    Some(())
+}
+
+pub(super) fn replace_customers(
+    chromosome: &mut Chromosome,
+    customers: &[Gene],
+    distance_service: &DistanceService,
+) -> bool {
+    let depot = chromosome.stops[0];
+
+    match build_chromosome(&chromosome.vehicle, depot, customers, distance_service) {
+        Some(new_chromosome) => {
+            *chromosome = new_chromosome;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Rebuilds both chromosomes with their new customers, or leaves the individual untouched when
+/// either of them cannot carry its customers.
+pub(super) fn replace_customers_of_pair(
+    individual: &mut Individual,
+    (index1, customers1): (usize, &[Gene]),
+    (index2, customers2): (usize, &[Gene]),
+    distance_service: &DistanceService,
+) -> bool {
+    let rebuild = |index: usize, customers: &[Gene]| {
+        let chromosome = &individual.chromosomes[index];
+        build_chromosome(
+            &chromosome.vehicle,
+            chromosome.stops[0],
+            customers,
+            distance_service,
+        )
+    };
+
+    let (chromosome1, chromosome2) =
+        match (rebuild(index1, customers1), rebuild(index2, customers2)) {
+            (Some(chromosome1), Some(chromosome2)) => (chromosome1, chromosome2),
+            _ => return false,
+        };
+
+    individual.chromosomes[index1] = chromosome1;
+    individual.chromosomes[index2] = chromosome2;
+
+    true
+}
+
+pub(super) fn fits(chromosome: &Chromosome, customers: &[Gene]) -> bool {
+    calculate_load(customers, &chromosome.stops[0]) < chromosome.vehicle.capacity
+}
+
+/// Pairs the heaviest routes with the largest vehicles; vehicles left over get an empty
+/// chromosome.
+pub(super) fn assign_to_vehicles(
+    depot: Gene,
+    mut routes: Vec<Vec<Gene>>,
+    vehicles: &[Vehicle],
+    distance_service: &DistanceService,
+) -> Option<Individual> {
+    if routes.len() > vehicles.len() {
+        return None;
+    }
+
+    routes.sort_by_key(|route| Reverse(calculate_load(route, &depot)));
+
+    let mut vehicle_order: Vec<usize> = (0..vehicles.len()).collect();
+    vehicle_order.sort_by_key(|index| Reverse(vehicles[*index].capacity));
+
+    let mut assigned: Vec<Vec<Gene>> = vec![Vec::new(); vehicles.len()];
+
+    for (route, vehicle_index) in routes.into_iter().zip(vehicle_order) {
+        assigned[vehicle_index] = route;
+    }
+
+    let chromosomes = vehicles
+        .iter()
+        .zip(assigned.iter())
+        .map(|(vehicle, customers)| build_chromosome(vehicle, depot, customers, distance_service))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Individual::new(chromosomes))
+}
@@ -1,6 +1,6 @@
-use std::rc::Rc;
+use std::{cell::RefCell, rc::Rc, time::Instant};
 
-use rand::{seq::SliceRandom, Rng};
+use rand::Rng;
 
 use crate::{
     domain::stop::Stop,
@@ -9,73 +9,206 @@ use crate::{
         distance::distance_service::{DistanceMatrix, DistanceService},
         route::route_service::RouteMap,
     },
-    solvers::{solver::Solver, vrp_solution::VrpSolution},
-    stop_swapper::StopSwapper,
+    solvers::{set_partitioning::route_pool::RoutePool, solver::Solver, vrp_solution::VrpSolution},
 };
 
 use super::{
+    adaptation::{
+        adaptation_parameters::{AdaptationParameters, AdaptationStrategy},
+        generation_parameters::{GenerationFeedback, GenerationParameters},
+        self_adaptive_rates::SelfAdaptiveRates,
+    },
     crossover::{crossover_operator::CrossoverOperator, offspring::Offspring},
+    diversity::{
+        broken_pairs_distance::{self, Neighbors},
+        diversity_parameters::{DiversityParameters, DiversityRecovery},
+        population_diversity,
+    },
     individual::Individual,
+    infeasibility::{infeasibility_parameters::InfeasibilityParameters, repair},
+    mutation::{
+        mutation_operator::MutationOperator,
+        mutation_strategy::{MutationStrategy, WeightedMutation},
+        swap_mutation::SwapMutation,
+    },
     population::Population,
+    replacement::replacement_strategy::ReplacementStrategy,
+    selection::{selection_operator::SelectionOperator, selection_strategy::SelectionStrategy},
+    termination::termination_criteria::{SearchProgress, StopReason, TerminationCriteria},
 };
 
+pub(crate) const MAX_PARENT_DRAWS: usize = 3;
+
 pub struct GeneticSolverParameters {
     pub elite_size: usize,
-    pub mutation_rate: f32,
+    pub mutations: Vec<WeightedMutation>,
     pub max_generations: u32,
     pub local_search_rate: f32,
+    pub selection: SelectionStrategy,
+    pub replacement: ReplacementStrategy,
+    pub diversity: DiversityParameters,
+    pub adaptation: AdaptationParameters,
+    pub termination: TerminationCriteria,
+    pub infeasibility: Option<InfeasibilityParameters>,
+}
+
+impl Default for GeneticSolverParameters {
+    fn default() -> Self {
+        Self {
+            elite_size: 5,
+            mutations: vec![WeightedMutation::new(
+                MutationStrategy::Swap(SwapMutation),
+                0.01,
+            )],
+            max_generations: 10,
+            local_search_rate: 0.01,
+            selection: Default::default(),
+            replacement: Default::default(),
+            diversity: Default::default(),
+            adaptation: Default::default(),
+            termination: Default::default(),
+            infeasibility: None,
+        }
+    }
 }
 
 pub struct GeneticSolver<'a, R: Rng + ?Sized> {
     parameters: GeneticSolverParameters,
     population: Population,
-    stop_swapper: StopSwapper,
+    infeasible_population: Population,
     current_generation: u32,
+    generations_without_improvement: u32,
+    evaluations: u32,
+    failed_evaluations: u32,
+    repaired_individuals: u32,
+    rejected_duplicates: u32,
+    diversity_history: Vec<f32>,
+    generation_parameters: GenerationParameters,
+    generation_feedback: GenerationFeedback,
+    parameter_history: Vec<GenerationParameters>,
+    start: Instant,
+    stop_reason: Option<StopReason>,
     initial_population: Population,
     pub solution: VrpSolution,
     best: Individual,
     crossover_op: &'a dyn CrossoverOperator<R>,
     local_search: TwoOptSearcher,
     distance_service: Rc<DistanceService>,
+    route_pool: Option<Rc<RefCell<RoutePool>>>,
     rng: Box<R>,
 }
 
 impl<'a, R: Rng + ?Sized> Solver<VrpSolution> for GeneticSolver<'a, R> {
     fn solve(&mut self) -> VrpSolution {
-        while !self.stop_condition_met() {
-            let parents = self.selection();
+        self.initialize();
 
-            let (parent1_index, parent1) = &parents[0];
-            let (parent2_index, parent2) = &parents[1];
+        while !self.stop_condition_met() {
+            self.evolve_generation();
+        }
 
-            let (offspring1, offspring2) = match self.crossover(parent1, parent2) {
-                Some(offsprings) => offsprings,
-                None => continue,
-            };
+        self.finish()
+    }
+}
 
-            self.population.individuals[*parent1_index] = offspring1;
-            self.population.individuals[*parent2_index] = offspring2;
+impl<'a, R: Rng + ?Sized> GeneticSolver<'a, R> {
+    pub(crate) fn new(
+        stops: Vec<Stop>,
+        distances: &DistanceMatrix,
+        population: Population,
+        parameters: GeneticSolverParameters,
+        crossover_op: &'a dyn CrossoverOperator<R>,
+        rng: Box<R>,
+    ) -> Self {
+        let distance_service = Rc::new(DistanceService::new(stops, distances));
 
-            self.mutation();
-            self.apply_local_search();
+        Self {
+            rng,
+            parameters,
+            crossover_op,
+            population: population.clone(),
+            infeasible_population: Default::default(),
+            initial_population: population,
+            best: Default::default(),
+            solution: Default::default(),
+            current_generation: Default::default(),
+            generations_without_improvement: Default::default(),
+            evaluations: Default::default(),
+            failed_evaluations: Default::default(),
+            repaired_individuals: Default::default(),
+            rejected_duplicates: Default::default(),
+            diversity_history: Default::default(),
+            generation_parameters: Default::default(),
+            generation_feedback: Default::default(),
+            parameter_history: Default::default(),
+            start: Instant::now(),
+            stop_reason: None,
+            route_pool: None,
+            distance_service: distance_service.clone(),
+            local_search: TwoOptSearcher::new(distance_service),
+        }
+    }
 
-            let best_in_generation = self
-                .population
+    pub(crate) fn initialize(&mut self) {
+        if let Some(best_in_population) =
+            self.population
                 .individuals
                 .iter()
                 .min_by(|individual_1, individual_2| {
                     individual_1.fitness.total_cmp(&individual_2.fitness)
                 })
-                .unwrap()
-                .clone();
+        {
+            if best_in_population.fitness < self.best.fitness {
+                self.best = best_in_population.clone();
+            }
+        }
 
-            if best_in_generation.fitness < self.best.fitness {
-                self.best = best_in_generation;
+        self.current_generation = 0;
+        self.generations_without_improvement = 0;
+        self.evaluations = 0;
+        self.failed_evaluations = 0;
+        self.repaired_individuals = 0;
+        self.rejected_duplicates = 0;
+        self.infeasible_population = Default::default();
+        self.diversity_history.clear();
+        self.parameter_history.clear();
+        self.start = Instant::now();
+        self.stop_reason = None;
+
+        self.generation_parameters = GenerationParameters::from(&self.parameters);
+
+        if let AdaptationStrategy::SelfAdaptive { .. } = self.parameters.adaptation.strategy {
+            let rates = SelfAdaptiveRates::from(&self.generation_parameters);
+
+            for individual in self.population.individuals.iter_mut() {
+                individual.rates = Some(rates.clone());
             }
+        }
+    }
+
+    pub(crate) fn evolve_generation(&mut self) {
+        let best_fitness = self.best.fitness;
+        let offspring = self.reproduce();
+
+        self.parameters.replacement.replace(
+            &mut self.population,
+            offspring,
+            self.generation_parameters.elite_size,
+        );
 
-            self.current_generation += 1;
+        self.collect_routes();
+        self.update_best();
+        self.manage_diversity();
+        self.adapt_parameters();
+
+        match self.best.fitness < best_fitness {
+            true => self.generations_without_improvement = 0,
+            false => self.generations_without_improvement += 1,
         }
 
+        self.current_generation += 1;
+    }
+
+    pub(crate) fn finish(&mut self) -> VrpSolution {
         let route_map: RouteMap = self
             .best
             .chromosomes
@@ -85,34 +218,52 @@ impl<'a, R: Rng + ?Sized> Solver<VrpSolution> for GeneticSolver<'a, R> {
             .collect();
 
         self.population = self.initial_population.clone();
+        self.infeasible_population = Default::default();
 
-        VrpSolution::new(&route_map, self.best.fitness)
+        VrpSolution::new(&route_map, self.best.fitness).with_stop_reason(self.stop_reason)
     }
-}
 
-impl<'a, R: Rng + ?Sized> GeneticSolver<'a, R> {
-    pub(crate) fn new(
-        stops: Vec<Stop>,
-        distances: &DistanceMatrix,
-        population: Population,
-        parameters: GeneticSolverParameters,
-        crossover_op: &'a dyn CrossoverOperator<R>,
-        rng: Box<R>,
-    ) -> Self {
-        let distance_service = Rc::new(DistanceService::new(stops, distances));
+    pub(crate) fn select_emigrants(&self, number_of_migrants: usize) -> Population {
+        let mut individuals = self.population.individuals.clone();
+        Population::sort_individuals(&mut individuals);
+        individuals.truncate(number_of_migrants);
 
-        Self {
-            rng,
-            parameters,
-            crossover_op,
-            population: population.clone(),
-            initial_population: population,
-            best: Default::default(),
-            solution: Default::default(),
-            current_generation: Default::default(),
-            distance_service: distance_service.clone(),
-            local_search: TwoOptSearcher::new(distance_service.clone()),
-            stop_swapper: StopSwapper { distance_service },
+        Population::new(individuals)
+    }
+
+    pub(crate) fn accept_immigrants(&mut self, immigrants: Vec<Population>) -> usize {
+        let population_size = self.population.individuals.len();
+        let mut accepted = 0;
+
+        for immigrant in immigrants
+            .into_iter()
+            .flat_map(|population| population.individuals)
+        {
+            if !population_diversity::is_duplicate(&immigrant, &self.population.individuals, 0.0) {
+                self.population.individuals.push(immigrant);
+                accepted += 1;
+            }
+        }
+
+        self.population.sort_by_fitness();
+        self.population.individuals.truncate(population_size);
+        self.update_best();
+
+        accepted
+    }
+
+    pub(crate) fn set_route_pool(&mut self, route_pool: Rc<RefCell<RoutePool>>) {
+        self.route_pool = Some(route_pool);
+    }
+
+    pub(super) fn collect_routes(&self) {
+        let mut route_pool = match &self.route_pool {
+            Some(route_pool) => route_pool.borrow_mut(),
+            None => return,
+        };
+
+        for individual in self.population.individuals.iter() {
+            route_pool.add_routes(&individual.chromosomes);
         }
     }
 
@@ -120,35 +271,285 @@ impl<'a, R: Rng + ?Sized> GeneticSolver<'a, R> {
         self.population = population;
     }
 
-    pub(super) fn selection(&mut self) -> Vec<(usize, Individual)> {
-        self.population
-            .get_k_bests(self.parameters.elite_size)
-            .choose_multiple_weighted(&mut self.rng, 2, |individual| individual.fitness)
-            .unwrap_or_else(|err| match err {
-                rand::distributions::WeightedError::NoItem => {
-                    panic!("the candidate list should not be empty")
-                }
-                rand::distributions::WeightedError::InvalidWeight => {
-                    panic!("the weight value should be between 0 and f32::MAX")
-                }
-                rand::distributions::WeightedError::AllWeightsZero => {
-                    panic!("at least one weight must be a non-zero value")
+    pub(crate) fn get_current_generation(&self) -> u32 {
+        self.current_generation
+    }
+
+    pub(crate) fn get_evaluations(&self) -> u32 {
+        self.evaluations
+    }
+
+    pub(crate) fn get_stop_reason(&self) -> Option<StopReason> {
+        self.stop_reason
+    }
+
+    pub(crate) fn get_failed_evaluations(&self) -> u32 {
+        self.failed_evaluations
+    }
+
+    pub(crate) fn get_repaired_individuals(&self) -> u32 {
+        self.repaired_individuals
+    }
+
+    pub(crate) fn get_infeasible_individuals(&self) -> usize {
+        self.infeasible_population.individuals.len()
+    }
+
+    pub(crate) fn get_rejected_duplicates(&self) -> u32 {
+        self.rejected_duplicates
+    }
+
+    pub(crate) fn get_diversity_history(&self) -> &[f32] {
+        &self.diversity_history
+    }
+
+    pub(crate) fn get_parameter_history(&self) -> &[GenerationParameters] {
+        &self.parameter_history
+    }
+
+    pub(super) fn reproduce(&mut self) -> Vec<Individual> {
+        let number_of_offspring = self.parameters.replacement.count_offspring(
+            self.population.individuals.len(),
+            self.generation_parameters.elite_size,
+        );
+
+        let mut offspring = Vec::with_capacity(number_of_offspring + 1);
+
+        // The populations only change once the offspring are merged, so rank them once.
+        let fitnesses = self.calculate_selection_fitnesses();
+
+        for _ in 0..number_of_offspring.div_ceil(2) {
+            let parents = self.selection(&fitnesses);
+
+            if parents.len() < 2 {
+                self.failed_evaluations += 1;
+                continue;
+            }
+
+            match self.crossover(&parents[0], &parents[1]) {
+                Some((offspring1, offspring2)) => {
+                    offspring.push(offspring1);
+                    offspring.push(offspring2);
                 }
-                rand::distributions::WeightedError::TooMany => {
-                    panic!("the quantity of weights must be between 0 and u32::MAX")
+                None => self.failed_evaluations += 1,
+            }
+        }
+
+        offspring.truncate(number_of_offspring);
+
+        self.mutation(&mut offspring);
+        self.apply_local_search(&mut offspring);
+
+        let offspring = self.reject_duplicates(offspring);
+
+        self.separate_infeasible(offspring)
+    }
+
+    fn separate_infeasible(&mut self, offspring: Vec<Individual>) -> Vec<Individual> {
+        let parameters = match &self.parameters.infeasibility {
+            Some(parameters) => parameters.clone(),
+            None => return offspring,
+        };
+
+        let mut feasible = Vec::with_capacity(offspring.len());
+
+        for mut individual in offspring {
+            if individual.is_feasible() {
+                feasible.push(individual);
+                continue;
+            }
+
+            if self
+                .rng
+                .gen_bool(parameters.repair_rate.clamp(0.0, 1.0).into())
+            {
+                self.evaluations += 1;
+
+                if let Some(repaired) = repair::repair(&individual, &self.distance_service) {
+                    self.repaired_individuals += 1;
+                    feasible.push(repaired);
+                    continue;
                 }
+            }
+
+            parameters.penalize(&mut individual);
+            self.infeasible_population.individuals.push(individual);
+        }
+
+        self.infeasible_population.sort_by_fitness();
+        self.infeasible_population
+            .individuals
+            .truncate(parameters.subpopulation_size);
+
+        feasible
+    }
+
+    fn reject_duplicates(&mut self, offspring: Vec<Individual>) -> Vec<Individual> {
+        let threshold = match self.parameters.diversity.duplicate_threshold {
+            Some(threshold) => threshold,
+            None => return offspring,
+        };
+
+        let mut accepted: Vec<Individual> = Vec::with_capacity(offspring.len());
+
+        for individual in offspring {
+            let others = self.population.individuals.iter().chain(accepted.iter());
+
+            match population_diversity::is_duplicate(&individual, others, threshold) {
+                true => self.rejected_duplicates += 1,
+                false => accepted.push(individual),
+            }
+        }
+
+        accepted
+    }
+
+    fn manage_diversity(&mut self) {
+        let diversity =
+            population_diversity::calculate_average_distance(&self.population.individuals);
+        self.diversity_history.push(diversity);
+
+        match self.parameters.diversity.min_diversity {
+            Some(min_diversity) if diversity < min_diversity => self.inject_immigrants(),
+            _ => (),
+        }
+    }
+
+    fn inject_immigrants(&mut self) {
+        let population_size = self.population.individuals.len();
+        let replaceable = population_size.saturating_sub(self.generation_parameters.elite_size);
+
+        let number_of_immigrants = match self.parameters.diversity.recovery {
+            DiversityRecovery::Immigrants { ratio } => {
+                ((replaceable as f32 * ratio).ceil() as usize).min(replaceable)
+            }
+            DiversityRecovery::Restart => replaceable,
+        };
+
+        let template = match self.population.individuals.first() {
+            Some(template) => template.clone(),
+            None => return,
+        };
+
+        for index in (population_size - number_of_immigrants)..population_size {
+            if let Some(immigrant) = population_diversity::generate_immigrant(
+                &template,
+                self.rng.as_mut(),
+                &self.distance_service,
+            ) {
+                self.population.individuals[index] = immigrant;
+                self.evaluations += 1;
+            }
+        }
+
+        self.population.sort_by_fitness();
+    }
+
+    fn adapt_parameters(&mut self) {
+        let mut feedback = std::mem::take(&mut self.generation_feedback);
+        feedback.diversity = self.diversity_history.last().copied().unwrap_or_default();
+
+        let mut parameters = self.generation_parameters.clone();
+
+        if let Some(rates) = SelfAdaptiveRates::average(
+            self.population
+                .individuals
+                .iter()
+                .filter_map(|individual| individual.rates.as_ref()),
+        ) {
+            parameters.mutation_rates = rates.mutation_rates;
+            parameters.local_search_rate = rates.local_search_rate;
+        }
+
+        self.parameter_history.push(parameters);
+
+        self.parameters
+            .adaptation
+            .adapt(&mut self.generation_parameters, &feedback);
+    }
+
+    fn calculate_selection_fitnesses(&self) -> Vec<f32> {
+        let individuals = self
+            .population
+            .individuals
+            .iter()
+            .chain(self.infeasible_population.individuals.iter());
+
+        if !self.parameters.diversity.biased_fitness {
+            return individuals.map(|individual| individual.fitness).collect();
+        }
+
+        let (costs, neighbors): (Vec<f32>, Vec<Neighbors>) = individuals
+            .map(|individual| (individual.fitness, Neighbors::from(individual)))
+            .unzip();
+
+        let neighbors: Vec<&Neighbors> = neighbors.iter().collect();
+
+        population_diversity::calculate_biased_fitnesses(
+            &costs,
+            &broken_pairs_distance::calculate_distances(&neighbors),
+            self.generation_parameters.elite_size,
+            self.parameters.diversity.close_individuals,
+        )
+    }
+
+    pub(super) fn selection(&mut self, fitnesses: &[f32]) -> Vec<Individual> {
+        let feasible = &self.population.individuals;
+        let infeasible = &self.infeasible_population.individuals;
+
+        let mut parents = self
+            .parameters
+            .selection
+            .select(fitnesses, 2, self.rng.as_mut());
+
+        // Selection samples with replacement, and crossing a parent with itself only copies it.
+        for _ in 0..MAX_PARENT_DRAWS {
+            if fitnesses.len() < 2 || parents[0] != parents[1] {
+                break;
+            }
+
+            parents[1] = self
+                .parameters
+                .selection
+                .select(fitnesses, 1, self.rng.as_mut())[0];
+        }
+
+        parents
+            .into_iter()
+            .map(|index| match feasible.get(index) {
+                Some(individual) => individual.clone(),
+                None => infeasible[index - feasible.len()].clone(),
             })
-            .cloned()
-            .enumerate()
             .collect()
     }
 
-    pub(super) fn mutation(&mut self) {
-        let stop_swapper = &self.stop_swapper;
+    pub(super) fn mutation(&mut self, individuals: &mut [Individual]) {
+        for individual in individuals.iter_mut() {
+            let mut mutated = false;
+            let fitness = individual.fitness;
 
-        for individual in self.population.individuals.iter_mut() {
-            if self.rng.gen_bool(self.parameters.mutation_rate.into()) {
-                individual.swap_random_genes(stop_swapper, &mut self.rng);
+            let rates = match &individual.rates {
+                Some(rates) => rates.mutation_rates.clone(),
+                None => self.generation_parameters.mutation_rates.clone(),
+            };
+
+            for (mutation, rate) in self.parameters.mutations.iter().zip(rates) {
+                if !self.rng.gen_bool(rate.clamp(0.0, 1.0).into()) {
+                    continue;
+                }
+
+                mutated |=
+                    mutation
+                        .strategy
+                        .mutate(individual, self.rng.as_mut(), &self.distance_service);
+            }
+
+            if mutated {
+                individual.update_fitness();
+                self.evaluations += 1;
+                self.generation_feedback
+                    .mutation
+                    .record(individual.fitness < fitness);
             }
         }
     }
@@ -161,24 +562,88 @@ impl<'a, R: Rng + ?Sized> GeneticSolver<'a, R> {
         let mut offspring1 = Offspring::new(parent1.clone(), parent2.clone(), self.crossover_op);
         let mut offspring2 = Offspring::new(parent2.clone(), parent1.clone(), self.crossover_op);
 
-        offspring1.try_to_evolve(&mut self.rng, &self.distance_service)?;
-        offspring2.try_to_evolve(&mut self.rng, &self.distance_service)?;
+        let generated = match self.parameters.infeasibility {
+            Some(_) => offspring1
+                .try_to_generate(&mut self.rng, &self.distance_service)
+                .and_then(|_| offspring2.try_to_generate(&mut self.rng, &self.distance_service)),
+            None => offspring1
+                .try_to_evolve(&mut self.rng, &self.distance_service)
+                .and_then(|_| offspring2.try_to_evolve(&mut self.rng, &self.distance_service)),
+        };
+
+        // Children of a failed crossover were evaluated too, so they count as well.
+        self.evaluations += offspring1.evaluations + offspring2.evaluations;
+        generated?;
+
+        let mut offspring1 = offspring1.individual;
+        let mut offspring2 = offspring2.individual;
+
+        if let AdaptationStrategy::SelfAdaptive { learning_rate } =
+            self.parameters.adaptation.strategy
+        {
+            let default_rates = SelfAdaptiveRates::from(&self.generation_parameters);
+            let rates1 = parent1.rates.as_ref().unwrap_or(&default_rates);
+            let rates2 = parent2.rates.as_ref().unwrap_or(&default_rates);
+
+            for offspring in [&mut offspring1, &mut offspring2] {
+                offspring.rates = Some(SelfAdaptiveRates::inherit(
+                    rates1,
+                    rates2,
+                    learning_rate,
+                    &self.parameters.adaptation,
+                    self.rng.as_mut(),
+                ));
+            }
+        }
 
-        Some((offspring1.individual, offspring2.individual))
+        Some((offspring1, offspring2))
     }
 
-    pub(super) fn apply_local_search(&mut self) {
-        for individual in self.population.individuals.iter_mut() {
-            if self.rng.gen_bool(self.parameters.local_search_rate as f64) {
+    pub(super) fn apply_local_search(&mut self, individuals: &mut [Individual]) {
+        for individual in individuals.iter_mut() {
+            let rate = match &individual.rates {
+                Some(rates) => rates.local_search_rate,
+                None => self.generation_parameters.local_search_rate,
+            };
+
+            if self.rng.gen_bool(rate.clamp(0.0, 1.0) as f64) {
+                let fitness = individual.fitness;
+
                 for chromosome in individual.chromosomes.iter_mut() {
                     self.local_search.run(chromosome);
                 }
                 individual.update_fitness();
+                self.evaluations += 1;
+
+                self.generation_feedback
+                    .local_search
+                    .record(individual.fitness < fitness);
+            }
+        }
+    }
+
+    fn update_best(&mut self) {
+        if let Some(best_in_generation) = self.population.individuals.first() {
+            if best_in_generation.fitness < self.best.fitness {
+                self.best = best_in_generation.clone();
             }
         }
     }
 
-    fn stop_condition_met(&self) -> bool {
-        self.current_generation >= self.parameters.max_generations
+    pub(crate) fn stop_condition_met(&mut self) -> bool {
+        let progress = SearchProgress {
+            generation: self.current_generation,
+            generations_without_improvement: self.generations_without_improvement,
+            evaluations: self.evaluations,
+            best_fitness: self.best.fitness,
+            elapsed: self.start.elapsed(),
+        };
+
+        self.stop_reason = self
+            .parameters
+            .termination
+            .check(self.parameters.max_generations, &progress);
+
+        self.stop_reason.is_some()
     }
 }
@@ -1,11 +1,12 @@
-use rand::{seq::IteratorRandom, thread_rng, Rng};
+use rand::{seq::IteratorRandom, Rng};
 
 use crate::{
     domain::{route::Route, stop::Stop},
     services::route::route_service::RouteService,
-    stop_swapper::{neighborhood::Neighborhood, StopSwapper},
 };
 
+use super::{adaptation::self_adaptive_rates::SelfAdaptiveRates, gene_sequence};
+
 pub(super) type Gene = Stop;
 pub(super) type Chromosome = Route;
 pub(super) type GeneAddress = (usize, usize);
@@ -15,6 +16,7 @@ impl Default for Individual {
         Self {
             fitness: f32::MAX,
             chromosomes: Default::default(),
+            rates: None,
         }
     }
 }
@@ -23,6 +25,7 @@ impl Default for Individual {
 pub struct Individual {
     pub(super) fitness: f32,
     pub(super) chromosomes: Vec<Chromosome>,
+    pub(super) rates: Option<SelfAdaptiveRates>,
 }
 
 pub(crate) type RandomIndividualGeneratorParams<'a, 'b, R> = (&'a mut R, &'b mut RouteService);
@@ -65,6 +68,7 @@ impl Individual {
         Self {
             fitness,
             chromosomes,
+            rates: None,
         }
     }
 
@@ -75,6 +79,17 @@ impl Individual {
             .sum()
     }
 
+    pub(crate) fn get_overload(&self) -> u32 {
+        self.chromosomes
+            .iter()
+            .map(gene_sequence::calculate_overload)
+            .sum()
+    }
+
+    pub(crate) fn is_feasible(&self) -> bool {
+        self.get_overload() == 0
+    }
+
     pub(crate) fn update_fitness(&mut self) {
         self.fitness = Self::calculate_fitness(&self.chromosomes);
     }
@@ -105,32 +120,6 @@ impl Individual {
             .expect("the chromosome should not be empty")
     }
 
-    pub(crate) fn choose_random_gene_pair<R>(&self, rng: &mut R) -> (GeneAddress, GeneAddress)
-    where
-        R: Rng + ?Sized,
-    {
-        let (chromosome_index, chromosome): (usize, &Chromosome) = self
-            .chromosomes
-            .iter()
-            .enumerate()
-            .filter(|(_, chromosome)| chromosome.stops.len() > 3)
-            .choose(&mut thread_rng())
-            .expect("the chromosome should not be empty");
-
-        let addresses: Vec<GeneAddress> = chromosome
-            .stops
-            .iter()
-            .enumerate()
-            .skip(1)
-            .take(chromosome.stops.len() - 2)
-            .choose_multiple(rng, 2)
-            .iter()
-            .map(|(gene_index, _)| (chromosome_index, *gene_index))
-            .collect();
-
-        (addresses[0], addresses[1])
-    }
-
     pub(crate) fn choose_random_gene<R>(&self, rng: &mut R) -> GeneAddress
     where
         R: Rng + ?Sized,
@@ -157,36 +146,4 @@ impl Individual {
 
         (chromosome_index, gene_index)
     }
-
-    pub(crate) fn swap_random_genes<R>(&mut self, stop_swapper: &StopSwapper, rng: &mut R)
-    where
-        R: Rng + ?Sized,
-    {
-        let (address1, address2): (GeneAddress, GeneAddress) = self.choose_random_gene_pair(rng);
-
-        let neighborhood1 = Neighborhood::from((
-            self.chromosomes
-                .as_slice()
-                .get(address1.0)
-                .expect("the chromosome index must be inside of the bounds vector")
-                .stops
-                .as_slice(),
-            address1.1,
-            stop_swapper.distance_service.as_ref(),
-        ));
-
-        let neighborhood2 = Neighborhood::from((
-            self.chromosomes
-                .get(address2.0)
-                .expect("the chromosome index must be inside of the bounds vector")
-                .stops
-                .as_slice(),
-            address2.1,
-            stop_swapper.distance_service.as_ref(),
-        ));
-
-        let swap_cost = stop_swapper.calculate_swap_cost(&neighborhood1, &neighborhood2);
-
-        self.swap_genes(address1, address2, swap_cost);
-    }
 }
@@ -0,0 +1,30 @@
+use crate::solvers::genetic::individual::Individual;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct InfeasibilityParameters {
+    /// Added to the distance for each unit of load above the vehicle capacities.
+    pub penalty_factor: f32,
+    /// Infeasible offspring kept apart from the population. They are only a source of parents:
+    /// they are not repaired again, but their offspring get their own chance of repair.
+    pub subpopulation_size: usize,
+    /// Chance of repairing an infeasible offspring before it joins the subpopulation. Repair is
+    /// deterministic, so each offspring is tried at most once.
+    pub repair_rate: f32,
+}
+
+impl Default for InfeasibilityParameters {
+    fn default() -> Self {
+        Self {
+            penalty_factor: 10.0,
+            subpopulation_size: 10,
+            repair_rate: 0.5,
+        }
+    }
+}
+
+impl InfeasibilityParameters {
+    pub(crate) fn penalize(&self, individual: &mut Individual) {
+        individual.update_fitness();
+        individual.fitness += self.penalty_factor * individual.get_overload() as f32;
+    }
+}
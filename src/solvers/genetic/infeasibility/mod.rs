@@ -0,0 +1,3 @@
+pub mod infeasibility_parameters;
+pub(crate) mod repair;
+mod tests;
@@ -0,0 +1,68 @@
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        gene_sequence,
+        individual::{Chromosome, Gene, Individual},
+    },
+};
+
+/// Unloads each overloaded chromosome, dropping the customers whose removal saves the most
+/// distance, and reinserts them at their cheapest feasible positions.
+pub(crate) fn repair(
+    individual: &Individual,
+    distance_service: &DistanceService,
+) -> Option<Individual> {
+    let mut removed: Vec<Gene> = Vec::new();
+
+    let mut chromosomes = individual
+        .chromosomes
+        .iter()
+        .map(|chromosome| {
+            let depot = chromosome.stops[0];
+            let mut customers = gene_sequence::get_customers(chromosome);
+
+            while !customers.is_empty()
+                && gene_sequence::calculate_load(&customers, &depot) >= chromosome.vehicle.capacity
+            {
+                let index = find_most_expensive(&customers, depot, distance_service);
+                removed.push(customers.remove(index));
+            }
+
+            gene_sequence::build_chromosome(
+                &chromosome.vehicle,
+                depot,
+                &customers,
+                distance_service,
+            )
+        })
+        .collect::<Option<Vec<Chromosome>>>()?;
+
+    removed.sort_by_key(|gene| std::cmp::Reverse(gene.usage));
+
+    gene_sequence::insert_at_cheapest_positions(&mut chromosomes, &removed, distance_service)?;
+
+    Some(Individual::new(chromosomes))
+}
+
+fn find_most_expensive(
+    customers: &[Gene],
+    depot: Gene,
+    distance_service: &DistanceService,
+) -> usize {
+    let saving = |index: usize| {
+        let previous = match index {
+            0 => &depot,
+            _ => &customers[index - 1],
+        };
+
+        let next = customers.get(index + 1).unwrap_or(&depot);
+
+        distance_service.get_distance(previous, &customers[index])
+            + distance_service.get_distance(&customers[index], next)
+            - distance_service.get_distance(previous, next)
+    };
+
+    (0..customers.len())
+        .max_by(|index1, index2| saving(*index1).total_cmp(&saving(*index2)))
+        .unwrap_or_default()
+}
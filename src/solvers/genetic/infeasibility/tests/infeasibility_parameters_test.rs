@@ -0,0 +1,27 @@
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        infeasibility::infeasibility_parameters::InfeasibilityParameters,
+        tests::fixtures::build_unchecked_individual,
+    },
+    tests::fixtures::services_fixture::distance_service,
+};
+
+#[rstest]
+#[case::feasible(vec![Stop::new(0, 0), Stop::new(1, 4), Stop::new(3, 4), Stop::new(0, 0)], 8.0)]
+#[case::overloaded(vec![Stop::new(0, 0), Stop::new(1, 6), Stop::new(3, 6), Stop::new(0, 0)], 38.0)]
+fn test_penalizes_the_overload(
+    distance_service: DistanceService,
+    #[case] stops: Vec<Stop>,
+    #[case] expected: f32,
+) {
+    let parameters = InfeasibilityParameters::default();
+    let mut individual = build_unchecked_individual(vec![(10, stops)], &distance_service);
+
+    parameters.penalize(&mut individual);
+
+    assert_eq!(individual.fitness, expected);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod infeasibility_parameters_test;
+#[cfg(test)]
+mod repair_test;
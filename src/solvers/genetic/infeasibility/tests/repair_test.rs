@@ -0,0 +1,70 @@
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        infeasibility::repair,
+        tests::fixtures::{build_unchecked_individual, ids},
+    },
+    tests::fixtures::services_fixture::distance_service,
+};
+
+#[rstest]
+fn test_moves_the_excess_to_routes_with_room(distance_service: DistanceService) {
+    let genes = [
+        Stop::new(0, 0),
+        Stop::new(1, 4),
+        Stop::new(2, 4),
+        Stop::new(3, 4),
+    ];
+
+    let individual = build_unchecked_individual(
+        vec![
+            (10, vec![genes[0], genes[1], genes[2], genes[3], genes[0]]),
+            (10, vec![genes[0]]),
+        ],
+        &distance_service,
+    );
+
+    let repaired = repair::repair(&individual, &distance_service).unwrap();
+
+    assert!(!individual.is_feasible());
+    assert!(repaired.is_feasible());
+    assert_eq!(ids(&repaired), vec![vec![0, 2, 3, 0], vec![0, 1, 0]]);
+}
+
+#[rstest]
+fn test_keeps_feasible_individuals(distance_service: DistanceService) {
+    let genes = [Stop::new(0, 0), Stop::new(1, 4), Stop::new(2, 4)];
+
+    let individual = build_unchecked_individual(
+        vec![(10, vec![genes[0], genes[1], genes[2], genes[0]])],
+        &distance_service,
+    );
+
+    let repaired = repair::repair(&individual, &distance_service).unwrap();
+
+    assert_eq!(ids(&repaired), ids(&individual));
+    assert_eq!(repaired.fitness, individual.fitness);
+}
+
+#[rstest]
+fn test_fails_without_enough_capacity(distance_service: DistanceService) {
+    let genes = [
+        Stop::new(0, 0),
+        Stop::new(1, 6),
+        Stop::new(2, 6),
+        Stop::new(3, 6),
+    ];
+
+    let individual = build_unchecked_individual(
+        vec![
+            (10, vec![genes[0], genes[1], genes[2], genes[3], genes[0]]),
+            (10, vec![genes[0]]),
+        ],
+        &distance_service,
+    );
+
+    assert!(repair::repair(&individual, &distance_service).is_none());
+}
@@ -0,0 +1,83 @@
+use crate::domain::coordinate::CoordinateMap;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InitializationSource {
+    Random,
+    Greedy,
+    Grasp {
+        rcl_size: usize,
+        max_improvement_times: u8,
+    },
+    Savings,
+    /// Stops have no location, so the sweep needs the coordinates of the depot and customers.
+    Sweep(CoordinateMap),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WeightedSource {
+    pub source: InitializationSource,
+    pub ratio: f32,
+}
+
+impl WeightedSource {
+    pub fn new(source: InitializationSource, ratio: f32) -> Self {
+        Self { source, ratio }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// How the population is built. Its size comes from the solver the initializer is attached to.
+pub struct InitializationParameters {
+    pub sources: Vec<WeightedSource>,
+    pub remove_duplicates: bool,
+    pub max_attempts: u32,
+    pub local_search: bool,
+}
+
+impl Default for InitializationParameters {
+    fn default() -> Self {
+        Self {
+            sources: vec![WeightedSource::new(InitializationSource::Random, 1.0)],
+            remove_duplicates: true,
+            max_attempts: 3,
+            local_search: false,
+        }
+    }
+}
+
+impl InitializationParameters {
+    pub(crate) fn count_individuals(&self, population_size: usize) -> Vec<usize> {
+        let total_ratio: f32 = self
+            .sources
+            .iter()
+            .map(|source| source.ratio.max(0.0))
+            .sum();
+
+        if total_ratio <= 0.0 {
+            return vec![0; self.sources.len()];
+        }
+
+        let mut counts: Vec<usize> = self
+            .sources
+            .iter()
+            .map(|source| {
+                (source.ratio.max(0.0) / total_ratio * population_size as f32).floor() as usize
+            })
+            .collect();
+
+        let mut remainder = population_size.saturating_sub(counts.iter().sum());
+
+        for (count, source) in counts.iter_mut().zip(self.sources.iter()) {
+            if remainder == 0 {
+                break;
+            }
+
+            if source.ratio > 0.0 {
+                *count += 1;
+                remainder -= 1;
+            }
+        }
+
+        counts
+    }
+}
@@ -0,0 +1,5 @@
+pub mod initialization_parameters;
+pub mod population_initializer;
+mod savings;
+mod sweep;
+mod tests;
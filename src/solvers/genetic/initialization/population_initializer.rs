@@ -0,0 +1,206 @@
+use std::rc::Rc;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    local_search::two_opt::TwoOptSearcher,
+    services::{
+        distance::distance_service::{DistanceMatrix, DistanceService},
+        route::route_service::RouteService,
+    },
+    solvers::{
+        genetic::{
+            diversity::population_diversity, individual::Individual, population::Population,
+        },
+        grasp::vrp_grasp_solver::{GraspSolverParameters, VrpGraspSolver},
+        greedy::vrp_greedy_solver::VrpGreedySolver,
+        solver::Solver,
+        vrp_solution::VrpSolution,
+    },
+};
+
+use super::{
+    initialization_parameters::{InitializationParameters, InitializationSource},
+    savings, sweep,
+};
+
+enum Generator {
+    Random,
+    Solver(Box<dyn Solver<VrpSolution>>),
+    Savings,
+    Sweep(Vec<Stop>),
+}
+
+pub struct PopulationInitializer<R: Rng + ?Sized> {
+    parameters: InitializationParameters,
+    generators: Vec<Generator>,
+    depot: Stop,
+    customers: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    route_service: RouteService,
+    distance_service: Rc<DistanceService>,
+    local_search: TwoOptSearcher,
+    rejected_duplicates: u32,
+    rng: Box<R>,
+}
+
+impl<R: Rng + ?Sized> PopulationInitializer<R> {
+    pub fn new(
+        stops: Vec<Stop>,
+        vehicles: Vec<Vehicle>,
+        distances: &DistanceMatrix,
+        parameters: InitializationParameters,
+        mut rng: Box<R>,
+    ) -> Self {
+        let distance_service = Rc::new(DistanceService::new(stops.clone(), distances));
+        let depot = stops[0];
+        let customers = stops[1..].to_vec();
+
+        let generators =
+            parameters
+                .sources
+                .iter()
+                .map(|weighted_source| match &weighted_source.source {
+                    InitializationSource::Random => Generator::Random,
+                    InitializationSource::Greedy => Generator::Solver(Box::new(
+                        VrpGreedySolver::new(vehicles.clone(), distances, stops.clone()),
+                    )),
+                    InitializationSource::Grasp {
+                        rcl_size,
+                        max_improvement_times,
+                    } => Generator::Solver(Box::new(VrpGraspSolver::new(
+                        stops.clone(),
+                        vehicles.clone(),
+                        distances,
+                        GraspSolverParameters {
+                            rcl_size: *rcl_size,
+                            max_improvement_times: *max_improvement_times,
+                        },
+                        ChaCha8Rng::seed_from_u64(rng.gen()),
+                    ))),
+                    InitializationSource::Savings => Generator::Savings,
+                    InitializationSource::Sweep(coordinates) => {
+                        Generator::Sweep(sweep::order_by_angle(depot, &customers, coordinates))
+                    }
+                })
+                .collect();
+
+        Self {
+            generators,
+            depot,
+            customers,
+            parameters,
+            rng,
+            route_service: RouteService::new(stops, vehicles.clone(), distance_service.clone()),
+            local_search: TwoOptSearcher::new(distance_service.clone()),
+            distance_service,
+            vehicles,
+            rejected_duplicates: 0,
+        }
+    }
+
+    pub fn get_rejected_duplicates(&self) -> u32 {
+        self.rejected_duplicates
+    }
+
+    pub(crate) fn initialize(&mut self, population_size: usize) -> Population {
+        let mut individuals = Vec::with_capacity(population_size);
+
+        self.rejected_duplicates = 0;
+
+        for (index, count) in self
+            .parameters
+            .count_individuals(population_size)
+            .into_iter()
+            .enumerate()
+        {
+            self.generate_into(&mut individuals, Some(index), count);
+        }
+
+        let missing = population_size.saturating_sub(individuals.len());
+        self.generate_into(&mut individuals, None, missing);
+
+        Population::new(individuals)
+    }
+
+    fn generate_into(
+        &mut self,
+        individuals: &mut Vec<Individual>,
+        generator_index: Option<usize>,
+        count: usize,
+    ) {
+        let mut added = 0;
+        let mut consecutive_failures = 0;
+
+        while added < count && consecutive_failures < self.parameters.max_attempts.max(1) {
+            match self.generate(generator_index) {
+                Some(individual) if self.is_new(&individual, individuals) => {
+                    individuals.push(individual);
+                    added += 1;
+                    consecutive_failures = 0;
+                }
+                _ => consecutive_failures += 1,
+            }
+        }
+    }
+
+    fn is_new(&mut self, individual: &Individual, individuals: &[Individual]) -> bool {
+        if !self.parameters.remove_duplicates
+            || !population_diversity::is_duplicate(individual, individuals, 0.0)
+        {
+            return true;
+        }
+
+        self.rejected_duplicates += 1;
+        false
+    }
+
+    /// Builds an individual from the given generator, or a random one to top up the population.
+    fn generate(&mut self, generator_index: Option<usize>) -> Option<Individual> {
+        let generator = generator_index.map(|index| &mut self.generators[index]);
+
+        let mut individual = match generator {
+            None | Some(Generator::Random) => self.generate_random(),
+            Some(Generator::Solver(solver)) => {
+                let solution = solver.solve();
+
+                if solution.routes.is_empty() {
+                    return None;
+                }
+
+                Individual::new(solution.routes.values().cloned().collect())
+            }
+            Some(Generator::Savings) => savings::build_savings_individual(
+                self.depot,
+                &self.customers,
+                &self.vehicles,
+                &self.distance_service,
+            )?,
+            Some(Generator::Sweep(ordered_customers)) => sweep::build_sweep_individual(
+                self.depot,
+                ordered_customers,
+                self.rng.gen_range(0..ordered_customers.len().max(1)),
+                &self.vehicles,
+                &self.distance_service,
+            )?,
+        };
+
+        if self.parameters.local_search {
+            for chromosome in individual.chromosomes.iter_mut() {
+                self.local_search.run(chromosome);
+            }
+
+            individual.update_fitness();
+        }
+
+        Some(individual)
+    }
+
+    fn generate_random(&mut self) -> Individual {
+        self.route_service.reset();
+
+        Individual::from((self.rng.as_mut(), &mut self.route_service))
+    }
+}
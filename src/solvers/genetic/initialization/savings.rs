@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use crate::{
+    domain::vehicle::Vehicle,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        gene_sequence,
+        individual::{Gene, Individual},
+    },
+};
+
+/// Clarke-Wright savings: routes are only joined tail to head, so asymmetric distances are
+/// respected. Merging continues past non-positive savings while there are more routes than
+/// vehicles. Returns `None` when a customer does not fit in any vehicle.
+pub(super) fn build_savings_individual(
+    depot: Gene,
+    customers: &[Gene],
+    vehicles: &[Vehicle],
+    distance_service: &DistanceService,
+) -> Option<Individual> {
+    let max_capacity = vehicles.iter().map(|vehicle| vehicle.capacity).max()?;
+
+    if customers
+        .iter()
+        .any(|customer| gene_sequence::calculate_load(&[*customer], &depot) >= max_capacity)
+    {
+        return None;
+    }
+
+    let mut routes: Vec<Vec<Gene>> = customers.iter().map(|customer| vec![*customer]).collect();
+
+    let mut route_of: HashMap<u32, usize> = routes
+        .iter()
+        .enumerate()
+        .map(|(index, route)| (route[0].id, index))
+        .collect();
+
+    let mut number_of_routes = routes.len();
+
+    for (saving, from, to) in calculate_savings(depot, &routes, distance_service) {
+        if saving <= 0.0 && number_of_routes <= vehicles.len() {
+            break;
+        }
+
+        let from_route = route_of[&from.id];
+        let to_route = route_of[&to.id];
+
+        if from_route == to_route
+            || routes[from_route].last() != Some(&from)
+            || routes[to_route].first() != Some(&to)
+        {
+            continue;
+        }
+
+        let merged = [routes[from_route].as_slice(), routes[to_route].as_slice()].concat();
+
+        if gene_sequence::calculate_load(&merged, &depot) >= max_capacity {
+            continue;
+        }
+
+        for gene in routes[to_route].iter() {
+            route_of.insert(gene.id, from_route);
+        }
+
+        routes[from_route] = merged;
+        routes[to_route].clear();
+        number_of_routes -= 1;
+    }
+
+    routes.retain(|route| !route.is_empty());
+
+    gene_sequence::assign_to_vehicles(depot, routes, vehicles, distance_service)
+}
+
+fn calculate_savings(
+    depot: Gene,
+    routes: &[Vec<Gene>],
+    distance_service: &DistanceService,
+) -> Vec<(f32, Gene, Gene)> {
+    let customers: Vec<Gene> = routes.iter().flatten().copied().collect();
+    let mut savings = Vec::with_capacity(customers.len() * customers.len());
+
+    for from in customers.iter() {
+        for to in customers.iter().filter(|to| *to != from) {
+            let saving = distance_service.get_distance(from, &depot)
+                + distance_service.get_distance(&depot, to)
+                - distance_service.get_distance(from, to);
+
+            savings.push((saving, *from, *to));
+        }
+    }
+
+    savings.sort_by(|(saving1, from1, to1), (saving2, from2, to2)| {
+        saving2
+            .total_cmp(saving1)
+            .then(from1.id.cmp(&from2.id))
+            .then(to1.id.cmp(&to2.id))
+    });
+
+    savings
+}
@@ -0,0 +1,76 @@
+use crate::{
+    domain::{
+        coordinate::{Coordinate, CoordinateMap},
+        vehicle::Vehicle,
+    },
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        gene_sequence,
+        individual::{Gene, Individual},
+    },
+};
+
+/// Customers sorted by their polar angle around the depot. Customers without coordinates go
+/// last, by id.
+pub(super) fn order_by_angle(
+    depot: Gene,
+    customers: &[Gene],
+    coordinates: &CoordinateMap,
+) -> Vec<Gene> {
+    let origin = coordinates
+        .get(&depot.id)
+        .copied()
+        .unwrap_or(Coordinate::new(0.0, 0.0));
+
+    let angle = |gene: &Gene| {
+        coordinates
+            .get(&gene.id)
+            .map(|coordinate| coordinate.get_polar_angle(&origin))
+            .unwrap_or(f32::MAX)
+    };
+
+    let mut ordered = customers.to_vec();
+    ordered.sort_by(|gene1, gene2| {
+        angle(gene1)
+            .total_cmp(&angle(gene2))
+            .then(gene1.id.cmp(&gene2.id))
+    });
+
+    ordered
+}
+
+/// Fills routes in angular order starting from `start`. Returns `None` when a customer does not
+/// fit in any vehicle.
+pub(super) fn build_sweep_individual(
+    depot: Gene,
+    ordered_customers: &[Gene],
+    start: usize,
+    vehicles: &[Vehicle],
+    distance_service: &DistanceService,
+) -> Option<Individual> {
+    let max_capacity = vehicles.iter().map(|vehicle| vehicle.capacity).max()?;
+    let start = start.min(ordered_customers.len());
+
+    let mut routes: Vec<Vec<Gene>> = vec![Vec::new()];
+
+    for customer in ordered_customers[start..]
+        .iter()
+        .chain(ordered_customers[..start].iter())
+    {
+        if gene_sequence::calculate_load(&[*customer], &depot) >= max_capacity {
+            return None;
+        }
+
+        let current = routes.last_mut()?;
+        let load = gene_sequence::calculate_load(current, &depot) + customer.usage;
+
+        match load < max_capacity {
+            true => current.push(*customer),
+            false => routes.push(vec![*customer]),
+        }
+    }
+
+    routes.retain(|route| !route.is_empty());
+
+    gene_sequence::assign_to_vehicles(depot, routes, vehicles, distance_service)
+}
@@ -0,0 +1,26 @@
+use rstest::rstest;
+
+use crate::solvers::genetic::initialization::initialization_parameters::{
+    InitializationParameters, InitializationSource, WeightedSource,
+};
+
+fn parameters(ratios: &[f32]) -> InitializationParameters {
+    InitializationParameters {
+        sources: ratios
+            .iter()
+            .map(|ratio| WeightedSource::new(InitializationSource::Random, *ratio))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[case::single_source(&[1.0], vec![10])]
+#[case::exact_ratios(&[0.5, 0.3, 0.2], vec![5, 3, 2])]
+#[case::rounded_ratios(&[1.0, 1.0, 1.0], vec![4, 3, 3])]
+#[case::unnormalized_ratios(&[3.0, 1.0], vec![8, 2])]
+#[case::skipped_source(&[0.0, 1.0], vec![0, 10])]
+#[case::no_ratio(&[0.0, 0.0], vec![0, 0])]
+fn test_counts_individuals_per_source(#[case] ratios: &[f32], #[case] expected: Vec<usize>) {
+    assert_eq!(parameters(ratios).count_individuals(10), expected);
+}
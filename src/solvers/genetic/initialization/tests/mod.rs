@@ -0,0 +1,8 @@
+#[cfg(test)]
+mod initialization_parameters_test;
+#[cfg(test)]
+mod population_initializer_test;
+#[cfg(test)]
+mod savings_test;
+#[cfg(test)]
+mod sweep_test;
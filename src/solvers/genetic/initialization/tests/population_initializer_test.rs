@@ -0,0 +1,152 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::{
+        coordinate::{Coordinate, CoordinateMap},
+        stop::Stop,
+    },
+    services::distance::distance_service::DistanceMatrix,
+    solvers::genetic::{
+        diversity::broken_pairs_distance::broken_pairs_distance,
+        initialization::{
+            initialization_parameters::{
+                InitializationParameters, InitializationSource, WeightedSource,
+            },
+            population_initializer::PopulationInitializer,
+        },
+        population::Population,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+fn initialize(
+    stops: Vec<Stop>,
+    distances: &DistanceMatrix,
+    vehicle_factory: VehicleFactory,
+    population_size: usize,
+    parameters: InitializationParameters,
+) -> (Population, u32) {
+    let mut initializer = PopulationInitializer::new(
+        stops,
+        vehicle_factory(2),
+        distances,
+        parameters,
+        Box::new(ChaCha8Rng::seed_from_u64(0)),
+    );
+
+    let population = initializer.initialize(population_size);
+
+    (population, initializer.get_rejected_duplicates())
+}
+
+#[rstest]
+#[case::random(InitializationSource::Random)]
+#[case::greedy(InitializationSource::Greedy)]
+#[case::grasp(InitializationSource::Grasp { rcl_size: 2, max_improvement_times: 2 })]
+#[case::savings(InitializationSource::Savings)]
+#[case::sweep(InitializationSource::Sweep(CoordinateMap::from([
+    (0, Coordinate::new(0.0, 0.0)),
+    (1, Coordinate::new(1.0, 0.0)),
+    (2, Coordinate::new(-1.0, 0.0)),
+    (3, Coordinate::new(0.0, 1.0)),
+])))]
+fn test_can_initialize_from_any_source(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+    #[case] source: InitializationSource,
+) {
+    let (population, _) = initialize(
+        stops,
+        &distances,
+        vehicle_factory,
+        4,
+        InitializationParameters {
+            sources: vec![WeightedSource::new(source, 1.0)],
+            remove_duplicates: false,
+            ..Default::default()
+        },
+    );
+
+    assert_eq!(population.individuals.len(), 4);
+    assert!(population
+        .individuals
+        .iter()
+        .all(|individual| individual.fitness != f32::MAX));
+}
+
+#[rstest]
+fn test_removes_duplicates(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let (population, rejected_duplicates) = initialize(
+        stops,
+        &distances,
+        vehicle_factory,
+        6,
+        InitializationParameters {
+            sources: vec![
+                WeightedSource::new(InitializationSource::Greedy, 0.5),
+                WeightedSource::new(InitializationSource::Savings, 0.5),
+            ],
+            local_search: true,
+            ..Default::default()
+        },
+    );
+
+    let individuals = &population.individuals;
+
+    assert!(rejected_duplicates > 0);
+    assert!(!individuals.is_empty() && individuals.len() <= 6);
+
+    for (index, individual) in individuals.iter().enumerate() {
+        assert!(individuals[index + 1..]
+            .iter()
+            .all(|other| broken_pairs_distance(individual, other) > 0.0));
+    }
+}
+
+#[rstest]
+fn test_applies_local_search_to_the_random_top_up(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let initialize_with = |local_search| {
+        let mut initializer = PopulationInitializer::new(
+            stops.clone(),
+            vehicle_factory(1),
+            &distances,
+            InitializationParameters {
+                sources: vec![WeightedSource::new(InitializationSource::Random, 0.0)],
+                remove_duplicates: false,
+                local_search,
+                ..Default::default()
+            },
+            Box::new(ChaCha8Rng::seed_from_u64(0)),
+        );
+
+        initializer.initialize(10)
+    };
+
+    let improved = initialize_with(true);
+    let random = initialize_with(false);
+
+    let pairs: Vec<(f32, f32)> = improved
+        .individuals
+        .iter()
+        .zip(random.individuals.iter())
+        .map(|(improved, random)| (improved.fitness, random.fitness))
+        .collect();
+
+    assert_eq!(pairs.len(), 10);
+    assert!(pairs.iter().all(|(improved, random)| improved <= random));
+    assert!(pairs.iter().any(|(improved, random)| improved < random));
+}
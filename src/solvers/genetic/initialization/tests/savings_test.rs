@@ -0,0 +1,56 @@
+use rstest::rstest;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{initialization::savings, tests::fixtures::ids},
+    tests::fixtures::{services_fixture::distance_service, stops_fixture::stops},
+};
+
+#[rstest]
+fn test_merges_routes_by_savings(stops: Vec<Stop>, distance_service: DistanceService) {
+    let vehicles = vec![Vehicle::new(0, 10), Vehicle::new(1, 10)];
+
+    let individual =
+        savings::build_savings_individual(stops[0], &stops[1..4], &vehicles, &distance_service)
+            .unwrap();
+
+    assert_eq!(ids(&individual), vec![vec![0, 1, 3, 2, 0], vec![0]]);
+    assert_eq!(individual.fitness, 8.0);
+}
+
+#[rstest]
+fn test_gives_the_heaviest_route_to_the_largest_vehicle(
+    stops: Vec<Stop>,
+    distance_service: DistanceService,
+) {
+    let vehicles = vec![Vehicle::new(0, 10), Vehicle::new(1, 20)];
+    let customers = [Stop::new(1, 8), Stop::new(2, 8), Stop::new(3, 2)];
+
+    let individual =
+        savings::build_savings_individual(stops[0], &customers, &vehicles, &distance_service)
+            .unwrap();
+
+    assert_eq!(ids(&individual), vec![vec![0], vec![0, 1, 3, 2, 0]]);
+}
+
+#[rstest]
+fn test_fails_without_enough_vehicles(stops: Vec<Stop>, distance_service: DistanceService) {
+    let vehicles = vec![Vehicle::new(0, 10), Vehicle::new(1, 10)];
+    let customers = [Stop::new(1, 6), Stop::new(2, 6), Stop::new(3, 6)];
+
+    let individual =
+        savings::build_savings_individual(stops[0], &customers, &vehicles, &distance_service);
+
+    assert!(individual.is_none());
+}
+
+#[rstest]
+fn test_fails_when_a_customer_fits_no_vehicle(stops: Vec<Stop>, distance_service: DistanceService) {
+    let vehicles = vec![Vehicle::new(0, 10), Vehicle::new(1, 10)];
+
+    let individual =
+        savings::build_savings_individual(stops[0], &stops[1..], &vehicles, &distance_service);
+
+    assert!(individual.is_none());
+}
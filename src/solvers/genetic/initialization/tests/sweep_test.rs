@@ -0,0 +1,110 @@
+use rstest::{fixture, rstest};
+
+use crate::{
+    domain::{
+        coordinate::{Coordinate, CoordinateMap},
+        stop::Stop,
+        vehicle::Vehicle,
+    },
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{initialization::sweep, tests::fixtures::ids},
+    tests::fixtures::{services_fixture::distance_service, stops_fixture::stops},
+};
+
+#[fixture]
+fn coordinates() -> CoordinateMap {
+    CoordinateMap::from([
+        (0, Coordinate::new(0.0, 0.0)),
+        (1, Coordinate::new(1.0, 0.0)),
+        (2, Coordinate::new(-1.0, 0.0)),
+        (3, Coordinate::new(0.0, 1.0)),
+    ])
+}
+
+#[rstest]
+fn test_orders_customers_by_angle(stops: Vec<Stop>, coordinates: CoordinateMap) {
+    let ordered = sweep::order_by_angle(stops[0], &stops[1..], &coordinates);
+
+    let ordered_ids: Vec<u32> = ordered.iter().map(|stop| stop.id).collect();
+
+    assert_eq!(ordered_ids, vec![1, 3, 2, 4]);
+}
+
+#[rstest]
+#[case::first_customer(0, vec![vec![0, 1, 3, 2, 0], vec![0]])]
+#[case::rotated(1, vec![vec![0, 3, 2, 1, 0], vec![0]])]
+fn test_sweeps_from_the_start(
+    stops: Vec<Stop>,
+    coordinates: CoordinateMap,
+    distance_service: DistanceService,
+    #[case] start: usize,
+    #[case] expected: Vec<Vec<u32>>,
+) {
+    let vehicles = vec![Vehicle::new(0, 10), Vehicle::new(1, 10)];
+    let ordered = sweep::order_by_angle(stops[0], &stops[1..4], &coordinates);
+
+    let individual =
+        sweep::build_sweep_individual(stops[0], &ordered, start, &vehicles, &distance_service)
+            .unwrap();
+
+    assert_eq!(ids(&individual), expected);
+}
+
+#[rstest]
+fn test_opens_a_route_when_the_vehicle_is_full(
+    stops: Vec<Stop>,
+    distance_service: DistanceService,
+) {
+    let customers = [Stop::new(1, 6), Stop::new(3, 3), Stop::new(2, 6)];
+
+    let two_vehicles = vec![Vehicle::new(0, 10), Vehicle::new(1, 10)];
+    let three_vehicles = vec![
+        Vehicle::new(0, 10),
+        Vehicle::new(1, 10),
+        Vehicle::new(2, 10),
+    ];
+
+    let individual =
+        sweep::build_sweep_individual(stops[0], &customers, 0, &two_vehicles, &distance_service)
+            .unwrap();
+
+    assert_eq!(ids(&individual), vec![vec![0, 1, 3, 0], vec![0, 2, 0]]);
+
+    let individual = sweep::build_sweep_individual(
+        stops[0],
+        &[Stop::new(1, 6), Stop::new(2, 6), Stop::new(3, 6)],
+        0,
+        &two_vehicles,
+        &distance_service,
+    );
+
+    assert!(individual.is_none());
+
+    let individual = sweep::build_sweep_individual(
+        stops[0],
+        &[Stop::new(1, 6), Stop::new(2, 6), Stop::new(3, 6)],
+        0,
+        &three_vehicles,
+        &distance_service,
+    );
+
+    assert_eq!(
+        individual.map(|individual| individual.chromosomes.len()),
+        Some(3)
+    );
+}
+
+#[rstest]
+fn test_fails_when_a_customer_fits_no_vehicle(
+    stops: Vec<Stop>,
+    coordinates: CoordinateMap,
+    distance_service: DistanceService,
+) {
+    let vehicles = vec![Vehicle::new(0, 10), Vehicle::new(1, 10)];
+    let ordered = sweep::order_by_angle(stops[0], &stops[1..], &coordinates);
+
+    let individual =
+        sweep::build_sweep_individual(stops[0], &ordered, 0, &vehicles, &distance_service);
+
+    assert!(individual.is_none());
+}
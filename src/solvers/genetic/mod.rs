@@ -1,6 +1,15 @@
+mod gene_sequence;
 mod individual;
 
+pub mod adaptation;
 pub mod crossover;
+pub mod diversity;
 pub mod genetic_solver;
+pub mod infeasibility;
+pub mod initialization;
+pub mod mutation;
 pub mod population;
+pub mod replacement;
+pub mod selection;
+pub mod termination;
 mod tests;
@@ -0,0 +1,34 @@
+use rand::{seq::index::sample, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{gene_sequence, individual::Individual},
+};
+
+use super::mutation_operator::{choose_chromosome, MutationOperator};
+
+#[derive(Clone, Debug)]
+pub struct InsertionMutation;
+
+impl<R: Rng + ?Sized> MutationOperator<R> for InsertionMutation {
+    fn mutate(
+        &self,
+        individual: &mut Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> bool {
+        let chromosome_index = match choose_chromosome(individual, 2, rng) {
+            Some(chromosome_index) => chromosome_index,
+            None => return false,
+        };
+
+        let chromosome = &mut individual.chromosomes[chromosome_index];
+        let mut customers = gene_sequence::get_customers(chromosome);
+
+        let positions = sample(rng, customers.len(), 2);
+        let gene = customers.remove(positions.index(0));
+        customers.insert(positions.index(1), gene);
+
+        gene_sequence::replace_customers(chromosome, &customers, distance_service)
+    }
+}
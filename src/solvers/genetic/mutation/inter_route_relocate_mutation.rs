@@ -0,0 +1,53 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{gene_sequence, individual::Individual},
+};
+
+use super::mutation_operator::{choose_chromosome, MutationOperator};
+
+#[derive(Clone, Debug)]
+pub struct InterRouteRelocateMutation;
+
+impl<R: Rng + ?Sized> MutationOperator<R> for InterRouteRelocateMutation {
+    fn mutate(
+        &self,
+        individual: &mut Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> bool {
+        let source_index = match choose_chromosome(individual, 1, rng) {
+            Some(source_index) => source_index,
+            None => return false,
+        };
+
+        let mut source_customers =
+            gene_sequence::get_customers(&individual.chromosomes[source_index]);
+        let gene = source_customers.remove(rng.gen_range(0..source_customers.len()));
+
+        let mut targets: Vec<usize> = (0..individual.chromosomes.len())
+            .filter(|index| *index != source_index)
+            .collect();
+        targets.shuffle(rng);
+
+        for target_index in targets {
+            let target = &individual.chromosomes[target_index];
+            let mut target_customers = gene_sequence::get_customers(target);
+            target_customers.insert(rng.gen_range(0..=target_customers.len()), gene);
+
+            if !gene_sequence::fits(target, &target_customers) {
+                continue;
+            }
+
+            return gene_sequence::replace_customers_of_pair(
+                individual,
+                (source_index, &source_customers),
+                (target_index, &target_customers),
+                distance_service,
+            );
+        }
+
+        false
+    }
+}
@@ -0,0 +1,60 @@
+use rand::{seq::index::sample, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{gene_sequence, individual::Individual},
+};
+
+use super::mutation_operator::MutationOperator;
+
+const MAX_ATTEMPTS: usize = 20;
+
+#[derive(Clone, Debug)]
+pub struct InterRouteSwapMutation;
+
+impl<R: Rng + ?Sized> MutationOperator<R> for InterRouteSwapMutation {
+    fn mutate(
+        &self,
+        individual: &mut Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> bool {
+        let candidates: Vec<usize> = (0..individual.chromosomes.len())
+            .filter(|index| {
+                !gene_sequence::get_customers(&individual.chromosomes[*index]).is_empty()
+            })
+            .collect();
+
+        if candidates.len() < 2 {
+            return false;
+        }
+
+        for _ in 0..MAX_ATTEMPTS {
+            let chosen = sample(rng, candidates.len(), 2);
+            let (index1, index2) = (candidates[chosen.index(0)], candidates[chosen.index(1)]);
+
+            let mut customers1 = gene_sequence::get_customers(&individual.chromosomes[index1]);
+            let mut customers2 = gene_sequence::get_customers(&individual.chromosomes[index2]);
+
+            let position1 = rng.gen_range(0..customers1.len());
+            let position2 = rng.gen_range(0..customers2.len());
+
+            std::mem::swap(&mut customers1[position1], &mut customers2[position2]);
+
+            if !gene_sequence::fits(&individual.chromosomes[index1], &customers1)
+                || !gene_sequence::fits(&individual.chromosomes[index2], &customers2)
+            {
+                continue;
+            }
+
+            return gene_sequence::replace_customers_of_pair(
+                individual,
+                (index1, &customers1),
+                (index2, &customers2),
+                distance_service,
+            );
+        }
+
+        false
+    }
+}
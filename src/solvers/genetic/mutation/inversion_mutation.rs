@@ -0,0 +1,36 @@
+use rand::{seq::index::sample, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{gene_sequence, individual::Individual},
+};
+
+use super::mutation_operator::{choose_chromosome, MutationOperator};
+
+#[derive(Clone, Debug)]
+pub struct InversionMutation;
+
+impl<R: Rng + ?Sized> MutationOperator<R> for InversionMutation {
+    fn mutate(
+        &self,
+        individual: &mut Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> bool {
+        let chromosome_index = match choose_chromosome(individual, 2, rng) {
+            Some(chromosome_index) => chromosome_index,
+            None => return false,
+        };
+
+        let chromosome = &mut individual.chromosomes[chromosome_index];
+        let mut customers = gene_sequence::get_customers(chromosome);
+
+        let positions = sample(rng, customers.len(), 2);
+        let start = positions.index(0).min(positions.index(1));
+        let end = positions.index(0).max(positions.index(1));
+
+        customers[start..=end].reverse();
+
+        gene_sequence::replace_customers(chromosome, &customers, distance_service)
+    }
+}
@@ -0,0 +1,9 @@
+pub mod insertion_mutation;
+pub mod inter_route_relocate_mutation;
+pub mod inter_route_swap_mutation;
+pub mod inversion_mutation;
+pub(crate) mod mutation_operator;
+pub mod mutation_strategy;
+pub mod scramble_mutation;
+pub mod swap_mutation;
+mod tests;
@@ -0,0 +1,29 @@
+use rand::{seq::IteratorRandom, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{gene_sequence, individual::Individual},
+};
+
+pub trait MutationOperator<R: Rng + ?Sized> {
+    fn mutate(
+        &self,
+        individual: &mut Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> bool;
+}
+
+pub(super) fn choose_chromosome<R: Rng + ?Sized>(
+    individual: &Individual,
+    min_customers: usize,
+    rng: &mut R,
+) -> Option<usize> {
+    individual
+        .chromosomes
+        .iter()
+        .enumerate()
+        .filter(|(_, chromosome)| gene_sequence::get_customers(chromosome).len() >= min_customers)
+        .map(|(index, _)| index)
+        .choose(rng)
+}
@@ -0,0 +1,115 @@
+use std::rc::Rc;
+
+use rand::Rng;
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    local_search::inter_route::InterRouteSearcher,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::individual::Individual,
+};
+
+use super::{
+    insertion_mutation::InsertionMutation,
+    inter_route_relocate_mutation::InterRouteRelocateMutation,
+    inter_route_swap_mutation::InterRouteSwapMutation, inversion_mutation::InversionMutation,
+    mutation_operator::MutationOperator, scramble_mutation::ScrambleMutation,
+    swap_mutation::SwapMutation,
+};
+
+#[derive(Clone)]
+pub enum MutationStrategy {
+    Swap(SwapMutation),
+    Inversion(InversionMutation),
+    Scramble(ScrambleMutation),
+    Insertion(InsertionMutation),
+    InterRouteRelocate(InterRouteRelocateMutation),
+    InterRouteSwap(InterRouteSwapMutation),
+    InterRouteSearch(Rc<InterRouteSearcher>),
+}
+
+/// A mutation together with the probability of applying it to each individual of a generation.
+#[derive(Clone)]
+pub struct WeightedMutation {
+    pub strategy: MutationStrategy,
+    pub(crate) probability: f32,
+}
+
+impl WeightedMutation {
+    pub fn new(strategy: MutationStrategy, probability: f32) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&probability),
+            "the mutation probability should be between 0 and 1, got {probability}"
+        );
+
+        Self {
+            strategy,
+            probability,
+        }
+    }
+}
+
+impl<R: Rng + ?Sized> MutationOperator<R> for MutationStrategy {
+    fn mutate(
+        &self,
+        individual: &mut Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> bool {
+        match self {
+            MutationStrategy::Swap(operator) => operator.mutate(individual, rng, distance_service),
+            MutationStrategy::Inversion(operator) => {
+                operator.mutate(individual, rng, distance_service)
+            }
+            MutationStrategy::Scramble(operator) => {
+                operator.mutate(individual, rng, distance_service)
+            }
+            MutationStrategy::Insertion(operator) => {
+                operator.mutate(individual, rng, distance_service)
+            }
+            MutationStrategy::InterRouteRelocate(operator) => {
+                operator.mutate(individual, rng, distance_service)
+            }
+            MutationStrategy::InterRouteSwap(operator) => {
+                operator.mutate(individual, rng, distance_service)
+            }
+            MutationStrategy::InterRouteSearch(searcher) => {
+                searcher.mutate(&mut individual.chromosomes, rng)
+            }
+        }
+    }
+}
+
+/// Applies the mutations to a giant tour of customers by treating it as a single route without
+/// a capacity, so the inter-route mutations never fire on it.
+pub(crate) fn mutate_giant_tour<R: Rng + ?Sized>(
+    mutations: &[WeightedMutation],
+    depot: Stop,
+    tour: &mut Vec<Stop>,
+    rng: &mut R,
+    distance_service: &DistanceService,
+) -> bool {
+    let mut chromosome = Route::new(Vehicle::new(0, u32::MAX));
+    chromosome.replace_stops_unchecked(
+        [&[depot], tour.as_slice(), &[depot]].concat(),
+        distance_service,
+    );
+
+    let mut individual = Individual::new(vec![chromosome]);
+    let mut mutated = false;
+
+    for mutation in mutations {
+        if rng.gen_bool(mutation.probability.into()) {
+            mutated |= mutation
+                .strategy
+                .mutate(&mut individual, rng, distance_service);
+        }
+    }
+
+    if mutated {
+        let stops = &individual.chromosomes[0].stops;
+        *tour = stops[1..stops.len() - 1].to_vec();
+    }
+
+    mutated
+}
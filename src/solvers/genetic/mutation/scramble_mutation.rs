@@ -0,0 +1,45 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{gene_sequence, individual::Individual},
+};
+
+use super::mutation_operator::{choose_chromosome, MutationOperator};
+
+#[derive(Clone, Debug)]
+pub struct ScrambleMutation {
+    max_segment_length: usize,
+}
+
+impl<R: Rng + ?Sized> MutationOperator<R> for ScrambleMutation {
+    fn mutate(
+        &self,
+        individual: &mut Individual,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> bool {
+        let chromosome_index = match choose_chromosome(individual, 2, rng) {
+            Some(chromosome_index) => chromosome_index,
+            None => return false,
+        };
+
+        let chromosome = &mut individual.chromosomes[chromosome_index];
+        let mut customers = gene_sequence::get_customers(chromosome);
+
+        let length = rng.gen_range(2..=self.max_segment_length.min(customers.len()));
+        let start = rng.gen_range(0..=customers.len() - length);
+
+        customers[start..start + length].shuffle(rng);
+
+        gene_sequence::replace_customers(chromosome, &customers, distance_service)
+    }
+}
+
+impl ScrambleMutation {
+    pub fn new(max_segment_length: usize) -> Self {
+        Self {
+            max_segment_length: max_segment_length.max(2),
+        }
+    }
+}
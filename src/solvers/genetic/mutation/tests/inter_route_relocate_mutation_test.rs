@@ -0,0 +1,93 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::mutation::{
+        inter_route_relocate_mutation::InterRouteRelocateMutation,
+        mutation_operator::MutationOperator,
+    },
+    tests::fixtures::{services_fixture::distance_service, stops_fixture::stops},
+};
+
+use crate::solvers::genetic::tests::fixtures::{
+    build_unchecked_individual, ids, individual_builder, IndividualBuilder,
+};
+
+#[rstest]
+fn test_moves_a_customer_to_another_chromosome(
+    stops: Vec<Stop>,
+    individual_builder: IndividualBuilder,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mut individual = individual_builder(vec![
+        (10, vec![stops[0], stops[1], stops[0]]),
+        (10, vec![stops[0]]),
+    ]);
+
+    let mutated = InterRouteRelocateMutation.mutate(&mut individual, &mut rng, &distance_service);
+
+    assert!(mutated);
+    assert_eq!(ids(&individual), vec![vec![0], vec![0, 1, 0]]);
+}
+
+#[rstest]
+fn test_respects_the_vehicle_capacity(
+    individual_builder: IndividualBuilder,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let depot = Stop::new(0, 0);
+
+    let mut individual = individual_builder(vec![
+        (10, vec![depot, Stop::new(1, 5), depot]),
+        (10, vec![depot, Stop::new(2, 5), depot]),
+    ]);
+
+    for _ in 0..10 {
+        let mutated =
+            InterRouteRelocateMutation.mutate(&mut individual, &mut rng, &distance_service);
+
+        assert!(!mutated);
+    }
+
+    assert_eq!(ids(&individual), vec![vec![0, 1, 0], vec![0, 2, 0]]);
+}
+
+#[rstest]
+fn test_keeps_every_customer_once_when_the_source_stays_overloaded(
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let depot = Stop::new(0, 0);
+
+    let mut individual = build_unchecked_individual(
+        vec![
+            (
+                10,
+                vec![
+                    depot,
+                    Stop::new(1, 8),
+                    Stop::new(2, 8),
+                    Stop::new(3, 1),
+                    depot,
+                ],
+            ),
+            (10, vec![depot]),
+        ],
+        &distance_service,
+    );
+
+    for _ in 0..10 {
+        InterRouteRelocateMutation.mutate(&mut individual, &mut rng, &distance_service);
+
+        let mut customers: Vec<u32> = ids(&individual).concat();
+        customers.retain(|id| *id != 0);
+        customers.sort_unstable();
+
+        assert_eq!(customers, vec![1, 2, 3]);
+    }
+}
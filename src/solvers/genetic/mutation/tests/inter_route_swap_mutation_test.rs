@@ -0,0 +1,51 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::mutation::{
+        inter_route_swap_mutation::InterRouteSwapMutation, mutation_operator::MutationOperator,
+    },
+    tests::fixtures::{services_fixture::distance_service, stops_fixture::stops},
+};
+
+use crate::solvers::genetic::tests::fixtures::{ids, individual_builder, IndividualBuilder};
+
+#[rstest]
+fn test_swaps_customers_between_chromosomes(
+    stops: Vec<Stop>,
+    individual_builder: IndividualBuilder,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mut individual = individual_builder(vec![
+        (10, vec![stops[0], stops[1], stops[0]]),
+        (10, vec![stops[0], stops[2], stops[0]]),
+    ]);
+
+    let mutated = InterRouteSwapMutation.mutate(&mut individual, &mut rng, &distance_service);
+
+    assert!(mutated);
+    assert_eq!(ids(&individual), vec![vec![0, 2, 0], vec![0, 1, 0]]);
+}
+
+#[rstest]
+fn test_respects_the_vehicle_capacity(
+    individual_builder: IndividualBuilder,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let depot = Stop::new(0, 0);
+
+    let mut individual = individual_builder(vec![
+        (3, vec![depot, Stop::new(1, 1), depot]),
+        (10, vec![depot, Stop::new(2, 5), depot]),
+    ]);
+
+    let mutated = InterRouteSwapMutation.mutate(&mut individual, &mut rng, &distance_service);
+
+    assert!(!mutated);
+    assert_eq!(ids(&individual), vec![vec![0, 1, 0], vec![0, 2, 0]]);
+}
@@ -0,0 +1,52 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::mutation::{
+        inversion_mutation::InversionMutation, mutation_operator::MutationOperator,
+    },
+    tests::fixtures::{services_fixture::distance_service, stops_fixture::stops},
+};
+
+use crate::solvers::genetic::tests::fixtures::{ids, individual_builder, IndividualBuilder};
+
+#[rstest]
+fn test_mutates_the_only_chromosome_with_two_customers(
+    stops: Vec<Stop>,
+    individual_builder: IndividualBuilder,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mut individual = individual_builder(vec![
+        (10, vec![stops[0], stops[1], stops[2], stops[0]]),
+        (10, vec![stops[0], stops[3], stops[0]]),
+    ]);
+
+    let mutated = InversionMutation.mutate(&mut individual, &mut rng, &distance_service);
+    individual.update_fitness();
+
+    assert!(mutated);
+    assert_eq!(ids(&individual), vec![vec![0, 2, 1, 0], vec![0, 3, 0]]);
+    assert_eq!(individual.fitness, 14.0);
+}
+
+#[rstest]
+fn test_cannot_mutate_chromosomes_with_a_single_customer(
+    stops: Vec<Stop>,
+    individual_builder: IndividualBuilder,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mut individual = individual_builder(vec![
+        (10, vec![stops[0], stops[1], stops[0]]),
+        (10, vec![stops[0], stops[3], stops[0]]),
+    ]);
+
+    let mutated = InversionMutation.mutate(&mut individual, &mut rng, &distance_service);
+
+    assert!(!mutated);
+    assert_eq!(ids(&individual), vec![vec![0, 1, 0], vec![0, 3, 0]]);
+}
@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod insertion_mutation_test;
+#[cfg(test)]
+mod inter_route_relocate_mutation_test;
+#[cfg(test)]
+mod inter_route_swap_mutation_test;
+#[cfg(test)]
+mod inversion_mutation_test;
+#[cfg(test)]
+mod mutation_strategy_test;
+#[cfg(test)]
+mod scramble_mutation_test;
+#[cfg(test)]
+mod swap_mutation_test;
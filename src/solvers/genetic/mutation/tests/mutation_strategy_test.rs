@@ -0,0 +1,73 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::mutation::{
+        inter_route_swap_mutation::InterRouteSwapMutation,
+        inversion_mutation::InversionMutation,
+        mutation_strategy::{self, MutationStrategy, WeightedMutation},
+    },
+    tests::fixtures::{
+        services_fixture::distance_service,
+        stops_fixture::{ids, stops},
+    },
+};
+
+#[rstest]
+#[should_panic(expected = "the mutation probability should be between 0 and 1")]
+#[case::above_one(1.5)]
+#[should_panic(expected = "the mutation probability should be between 0 and 1")]
+#[case::negative(-0.1)]
+fn test_rejects_probabilities_outside_of_the_unit_interval(#[case] probability: f32) {
+    WeightedMutation::new(MutationStrategy::Inversion(InversionMutation), probability);
+}
+
+#[rstest]
+fn test_mutates_a_giant_tour(stops: Vec<Stop>, distance_service: DistanceService) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mut tour = vec![stops[1], stops[2]];
+
+    let mutations = vec![WeightedMutation::new(
+        MutationStrategy::Inversion(InversionMutation),
+        1.0,
+    )];
+
+    let mutated = mutation_strategy::mutate_giant_tour(
+        &mutations,
+        stops[0],
+        &mut tour,
+        &mut rng,
+        &distance_service,
+    );
+
+    assert!(mutated);
+    assert_eq!(ids(&tour), vec![2, 1]);
+}
+
+#[rstest]
+fn test_inter_route_mutations_leave_a_giant_tour_untouched(
+    stops: Vec<Stop>,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mut tour = vec![stops[1], stops[2], stops[3]];
+
+    let mutations = vec![WeightedMutation::new(
+        MutationStrategy::InterRouteSwap(InterRouteSwapMutation),
+        1.0,
+    )];
+
+    let mutated = mutation_strategy::mutate_giant_tour(
+        &mutations,
+        stops[0],
+        &mut tour,
+        &mut rng,
+        &distance_service,
+    );
+
+    assert!(!mutated);
+    assert_eq!(ids(&tour), vec![1, 2, 3]);
+}
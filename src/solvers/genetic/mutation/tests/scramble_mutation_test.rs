@@ -0,0 +1,61 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::mutation::{
+        mutation_operator::MutationOperator, scramble_mutation::ScrambleMutation,
+    },
+    tests::fixtures::{services_fixture::distance_service, stops_fixture::stops},
+};
+
+use crate::solvers::genetic::tests::fixtures::{ids, individual_builder, IndividualBuilder};
+
+#[rstest]
+fn test_scrambles_a_segment_of_the_chromosome(
+    stops: Vec<Stop>,
+    individual_builder: IndividualBuilder,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mutation = ScrambleMutation::new(3);
+
+    let original = individual_builder(vec![(
+        10,
+        vec![stops[0], stops[1], stops[2], stops[3], stops[0]],
+    )]);
+
+    for _ in 0..10 {
+        let mut individual = original.clone();
+
+        assert!(mutation.mutate(&mut individual, &mut rng, &distance_service));
+
+        let mut customers = ids(&individual)[0].clone();
+        assert_eq!(customers.first(), Some(&0));
+        assert_eq!(customers.last(), Some(&0));
+
+        customers.sort();
+        assert_eq!(customers, vec![0, 0, 1, 2, 3]);
+    }
+}
+
+#[rstest]
+fn test_segments_of_two_genes_are_swapped_or_kept(
+    stops: Vec<Stop>,
+    individual_builder: IndividualBuilder,
+    distance_service: DistanceService,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let mutation = ScrambleMutation::new(2);
+
+    let original = individual_builder(vec![(10, vec![stops[0], stops[1], stops[2], stops[0]])]);
+
+    for _ in 0..10 {
+        let mut individual = original.clone();
+        mutation.mutate(&mut individual, &mut rng, &distance_service);
+
+        assert!([vec![0, 1, 2, 0], vec![0, 2, 1, 0]].contains(&ids(&individual)[0]));
+    }
+}
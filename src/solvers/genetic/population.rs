@@ -18,19 +18,17 @@ impl From<&[VrpSolution]> for Population {
 }
 
 impl Population {
-    #[allow(dead_code)]
     pub(super) fn new(individuals: Vec<Individual>) -> Self {
         Self { individuals }
     }
 
-    pub(super) fn get_k_bests(&mut self, k: usize) -> &[Individual] {
-        self.individuals.sort_by(|individual1, individual2| {
-            individual1
-                .fitness
-                .partial_cmp(&individual2.fitness)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+    pub(super) fn sort_by_fitness(&mut self) {
+        Self::sort_individuals(&mut self.individuals);
+    }
 
-        &self.individuals[..k]
+    pub(super) fn sort_individuals(individuals: &mut [Individual]) {
+        individuals.sort_by(|individual1, individual2| {
+            individual1.fitness.total_cmp(&individual2.fitness)
+        });
     }
 }
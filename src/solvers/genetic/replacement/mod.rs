@@ -0,0 +1,2 @@
+pub mod replacement_strategy;
+mod tests;
@@ -0,0 +1,77 @@
+use crate::solvers::genetic::{individual::Individual, population::Population};
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum ReplacementStrategy {
+    /// Each offspring replaces the worst non-elite individual it improves on.
+    #[default]
+    SteadyStateWorst,
+    /// The next population is the best of parents and `offspring_size` offspring.
+    MuPlusLambda { offspring_size: usize },
+    /// The next population is the elite plus the best of `offspring_size` offspring.
+    MuCommaLambda { offspring_size: usize },
+    /// The elite survives and the rest of the population is bred again.
+    ElitistGenerational,
+}
+
+impl ReplacementStrategy {
+    pub(crate) fn count_offspring(&self, population_size: usize, elite_size: usize) -> usize {
+        match self {
+            ReplacementStrategy::SteadyStateWorst => 2,
+            ReplacementStrategy::MuPlusLambda { offspring_size }
+            | ReplacementStrategy::MuCommaLambda { offspring_size } => *offspring_size,
+            ReplacementStrategy::ElitistGenerational => {
+                population_size.saturating_sub(elite_size).max(1)
+            }
+        }
+    }
+
+    pub(crate) fn replace(
+        &self,
+        population: &mut Population,
+        mut offspring: Vec<Individual>,
+        elite_size: usize,
+    ) {
+        let population_size = population.individuals.len();
+        let elite_size = elite_size.min(population_size);
+
+        population.sort_by_fitness();
+        Population::sort_individuals(&mut offspring);
+
+        match self {
+            ReplacementStrategy::SteadyStateWorst => {
+                for individual in offspring {
+                    let worst = match population.individuals.last() {
+                        Some(worst) => worst,
+                        None => break,
+                    };
+
+                    if population_size <= elite_size || individual.fitness >= worst.fitness {
+                        continue;
+                    }
+
+                    population.individuals[population_size - 1] = individual;
+                    population.sort_by_fitness();
+                }
+            }
+            ReplacementStrategy::MuPlusLambda { .. } => {
+                population.individuals.extend(offspring);
+                population.sort_by_fitness();
+                population.individuals.truncate(population_size);
+            }
+            ReplacementStrategy::MuCommaLambda { .. }
+            | ReplacementStrategy::ElitistGenerational => {
+                let parents = population.individuals.split_off(elite_size);
+
+                offspring.truncate(population_size - elite_size);
+                population.individuals.extend(offspring);
+
+                let missing = population_size - population.individuals.len();
+                population
+                    .individuals
+                    .extend(parents.into_iter().take(missing));
+
+                population.sort_by_fitness();
+            }
+        }
+    }
+}
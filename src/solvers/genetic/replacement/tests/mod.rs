@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod replacement_strategy_test;
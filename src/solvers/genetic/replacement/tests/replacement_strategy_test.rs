@@ -0,0 +1,56 @@
+use rstest::rstest;
+
+use crate::solvers::genetic::{
+    individual::Individual, population::Population,
+    replacement::replacement_strategy::ReplacementStrategy,
+};
+
+fn individuals(fitnesses: &[f32]) -> Vec<Individual> {
+    fitnesses
+        .iter()
+        .map(|fitness| Individual {
+            fitness: *fitness,
+            ..Default::default()
+        })
+        .collect()
+}
+
+fn fitnesses(population: &Population) -> Vec<f32> {
+    population
+        .individuals
+        .iter()
+        .map(|individual| individual.fitness)
+        .collect()
+}
+
+#[rstest]
+#[case::steady_state(ReplacementStrategy::SteadyStateWorst, &[25.0, 50.0], 1, &[10.0, 20.0, 25.0, 30.0])]
+#[case::steady_state_with_a_full_elite(ReplacementStrategy::SteadyStateWorst, &[5.0], 4, &[10.0, 20.0, 30.0, 40.0])]
+#[case::mu_plus_lambda(ReplacementStrategy::MuPlusLambda { offspring_size: 3 }, &[5.0, 50.0, 15.0], 0, &[5.0, 10.0, 15.0, 20.0])]
+#[case::mu_comma_lambda(ReplacementStrategy::MuCommaLambda { offspring_size: 4 }, &[50.0, 45.0, 60.0, 35.0], 1, &[10.0, 35.0, 45.0, 50.0])]
+#[case::elitist_generational(ReplacementStrategy::ElitistGenerational, &[50.0, 5.0], 2, &[5.0, 10.0, 20.0, 50.0])]
+#[case::elitist_generational_with_missing_offspring(ReplacementStrategy::ElitistGenerational, &[50.0], 2, &[10.0, 20.0, 30.0, 50.0])]
+fn test_replaces_the_population(
+    #[case] strategy: ReplacementStrategy,
+    #[case] offspring: &[f32],
+    #[case] elite_size: usize,
+    #[case] expected: &[f32],
+) {
+    let mut population = Population::new(individuals(&[40.0, 10.0, 30.0, 20.0]));
+
+    strategy.replace(&mut population, individuals(offspring), elite_size);
+
+    assert_eq!(fitnesses(&population), expected);
+}
+
+#[rstest]
+#[case::steady_state(ReplacementStrategy::SteadyStateWorst, 2)]
+#[case::mu_plus_lambda(ReplacementStrategy::MuPlusLambda { offspring_size: 6 }, 6)]
+#[case::mu_comma_lambda(ReplacementStrategy::MuCommaLambda { offspring_size: 14 }, 14)]
+#[case::elitist_generational(ReplacementStrategy::ElitistGenerational, 7)]
+fn test_counts_the_offspring_of_a_generation(
+    #[case] strategy: ReplacementStrategy,
+    #[case] expected: usize,
+) {
+    assert_eq!(strategy.count_offspring(10, 3), expected);
+}
@@ -0,0 +1,30 @@
+const MIN_WEIGHT: f32 = 1e-3;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FitnessScaling {
+    Inverted,
+    Normalized,
+}
+
+impl FitnessScaling {
+    pub(crate) fn calculate_weights(&self, fitnesses: &[f32]) -> Vec<f32> {
+        match self {
+            FitnessScaling::Inverted => fitnesses
+                .iter()
+                .map(|fitness| 1.0 / fitness.max(f32::EPSILON))
+                .collect(),
+            FitnessScaling::Normalized => {
+                let best = fitnesses.iter().copied().fold(f32::MAX, f32::min);
+                let worst = fitnesses.iter().copied().fold(f32::MIN, f32::max);
+
+                fitnesses
+                    .iter()
+                    .map(|fitness| match worst - best > 0.0 {
+                        true => (worst - fitness) / (worst - best) + MIN_WEIGHT,
+                        false => 1.0,
+                    })
+                    .collect()
+            }
+        }
+    }
+}
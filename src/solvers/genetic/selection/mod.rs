@@ -0,0 +1,8 @@
+pub mod fitness_scaling;
+pub mod rank_selection;
+pub mod roulette_wheel_selection;
+pub(crate) mod selection_operator;
+pub mod selection_strategy;
+pub mod stochastic_universal_sampling;
+mod tests;
+pub mod tournament_selection;
@@ -0,0 +1,40 @@
+use rand::Rng;
+
+use super::selection_operator::{choose_weighted, SelectionOperator};
+
+#[derive(Clone, Debug)]
+pub struct RankSelection {
+    selection_pressure: f32,
+}
+
+impl<R: Rng + ?Sized> SelectionOperator<R> for RankSelection {
+    fn select(&self, fitnesses: &[f32], number_of_parents: usize, rng: &mut R) -> Vec<usize> {
+        let weights = self.calculate_weights(fitnesses);
+
+        choose_weighted(&weights, number_of_parents, rng)
+    }
+}
+
+impl RankSelection {
+    pub fn new(selection_pressure: f32) -> Self {
+        Self {
+            selection_pressure: selection_pressure.clamp(1.0, 2.0),
+        }
+    }
+
+    pub(crate) fn calculate_weights(&self, fitnesses: &[f32]) -> Vec<f32> {
+        let mut ranking: Vec<usize> = (0..fitnesses.len()).collect();
+
+        ranking.sort_by(|index1, index2| fitnesses[*index2].total_cmp(&fitnesses[*index1]));
+
+        let last_rank = (fitnesses.len() as f32 - 1.0).max(1.0);
+        let mut weights = vec![0.0; fitnesses.len()];
+
+        for (rank, index) in ranking.into_iter().enumerate() {
+            weights[index] = (2.0 - self.selection_pressure)
+                + 2.0 * (self.selection_pressure - 1.0) * rank as f32 / last_rank;
+        }
+
+        weights
+    }
+}
@@ -0,0 +1,25 @@
+use rand::Rng;
+
+use super::{
+    fitness_scaling::FitnessScaling,
+    selection_operator::{choose_weighted, SelectionOperator},
+};
+
+#[derive(Clone, Debug)]
+pub struct RouletteWheelSelection {
+    scaling: FitnessScaling,
+}
+
+impl<R: Rng + ?Sized> SelectionOperator<R> for RouletteWheelSelection {
+    fn select(&self, fitnesses: &[f32], number_of_parents: usize, rng: &mut R) -> Vec<usize> {
+        let weights = self.scaling.calculate_weights(fitnesses);
+
+        choose_weighted(&weights, number_of_parents, rng)
+    }
+}
+
+impl RouletteWheelSelection {
+    pub fn new(scaling: FitnessScaling) -> Self {
+        Self { scaling }
+    }
+}
@@ -0,0 +1,19 @@
+use rand::{seq::SliceRandom, Rng};
+
+pub trait SelectionOperator<R: Rng + ?Sized> {
+    fn select(&self, fitnesses: &[f32], number_of_parents: usize, rng: &mut R) -> Vec<usize>;
+}
+
+pub(super) fn choose_weighted<R: Rng + ?Sized>(
+    weights: &[f32],
+    number_of_parents: usize,
+    rng: &mut R,
+) -> Vec<usize> {
+    let candidates: Vec<usize> = (0..weights.len()).collect();
+
+    candidates
+        .choose_multiple_weighted(rng, number_of_parents, |index| weights[*index])
+        .unwrap_or_else(|err| panic!("the selection weights should be valid: {err}"))
+        .copied()
+        .collect()
+}
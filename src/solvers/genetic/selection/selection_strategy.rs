@@ -0,0 +1,39 @@
+use rand::Rng;
+
+use super::{
+    rank_selection::RankSelection, roulette_wheel_selection::RouletteWheelSelection,
+    selection_operator::SelectionOperator,
+    stochastic_universal_sampling::StochasticUniversalSampling,
+    tournament_selection::TournamentSelection,
+};
+
+#[derive(Clone, Debug)]
+pub enum SelectionStrategy {
+    Tournament(TournamentSelection),
+    Rank(RankSelection),
+    RouletteWheel(RouletteWheelSelection),
+    StochasticUniversalSampling(StochasticUniversalSampling),
+}
+
+impl Default for SelectionStrategy {
+    fn default() -> Self {
+        SelectionStrategy::Tournament(TournamentSelection::new(2))
+    }
+}
+
+impl<R: Rng + ?Sized> SelectionOperator<R> for SelectionStrategy {
+    fn select(&self, fitnesses: &[f32], number_of_parents: usize, rng: &mut R) -> Vec<usize> {
+        match self {
+            SelectionStrategy::Tournament(operator) => {
+                operator.select(fitnesses, number_of_parents, rng)
+            }
+            SelectionStrategy::Rank(operator) => operator.select(fitnesses, number_of_parents, rng),
+            SelectionStrategy::RouletteWheel(operator) => {
+                operator.select(fitnesses, number_of_parents, rng)
+            }
+            SelectionStrategy::StochasticUniversalSampling(operator) => {
+                operator.select(fitnesses, number_of_parents, rng)
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use rand::Rng;
+
+use super::{fitness_scaling::FitnessScaling, selection_operator::SelectionOperator};
+
+/// Places evenly spaced pointers over the weights, so an individual whose weight spans more
+/// than one step is sampled more than once.
+#[derive(Clone, Debug)]
+pub struct StochasticUniversalSampling {
+    scaling: FitnessScaling,
+}
+
+impl<R: Rng + ?Sized> SelectionOperator<R> for StochasticUniversalSampling {
+    fn select(&self, fitnesses: &[f32], number_of_parents: usize, rng: &mut R) -> Vec<usize> {
+        if fitnesses.is_empty() || number_of_parents == 0 {
+            return Vec::new();
+        }
+
+        let weights = self.scaling.calculate_weights(fitnesses);
+        let step = weights.iter().sum::<f32>() / number_of_parents as f32;
+        let start = rng.gen_range(0.0..step);
+
+        let mut parents = Vec::with_capacity(number_of_parents);
+        let mut index = 0;
+        let mut cumulative_weight = weights[0];
+
+        for pointer in (0..number_of_parents).map(|parent| start + parent as f32 * step) {
+            while cumulative_weight < pointer && index < weights.len() - 1 {
+                index += 1;
+                cumulative_weight += weights[index];
+            }
+
+            parents.push(index);
+        }
+
+        parents
+    }
+}
+
+impl StochasticUniversalSampling {
+    pub fn new(scaling: FitnessScaling) -> Self {
+        Self { scaling }
+    }
+}
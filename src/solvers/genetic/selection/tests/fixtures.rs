@@ -0,0 +1,6 @@
+use rstest::fixture;
+
+#[fixture]
+pub(crate) fn fitnesses() -> Vec<f32> {
+    vec![40.0, 10.0, 30.0, 20.0]
+}
@@ -0,0 +1,9 @@
+mod fixtures;
+#[cfg(test)]
+mod rank_selection_test;
+#[cfg(test)]
+mod roulette_wheel_selection_test;
+#[cfg(test)]
+mod stochastic_universal_sampling_test;
+#[cfg(test)]
+mod tournament_selection_test;
@@ -0,0 +1,40 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::solvers::genetic::selection::{
+    rank_selection::RankSelection, selection_operator::SelectionOperator,
+};
+
+use super::fixtures::fitnesses;
+
+#[rstest]
+fn test_weights_grow_linearly_with_the_rank(fitnesses: Vec<f32>) {
+    let selection = RankSelection::new(2.0);
+
+    let weights = selection.calculate_weights(&fitnesses);
+
+    assert_eq!(weights, vec![0.0, 2.0, 2.0 / 3.0, 4.0 / 3.0]);
+}
+
+#[rstest]
+fn test_without_pressure_every_rank_has_the_same_weight(fitnesses: Vec<f32>) {
+    let selection = RankSelection::new(1.0);
+
+    let weights = selection.calculate_weights(&fitnesses);
+
+    assert_eq!(weights, vec![1.0; 4]);
+}
+
+#[rstest]
+fn test_never_selects_the_worst_individual_under_maximum_pressure(fitnesses: Vec<f32>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let selection = RankSelection::new(2.0);
+
+    for _ in 0..100 {
+        let parents = selection.select(&fitnesses, 2, &mut rng);
+
+        assert_eq!(parents.len(), 2);
+        assert!(!parents.contains(&0));
+    }
+}
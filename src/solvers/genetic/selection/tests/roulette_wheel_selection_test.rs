@@ -0,0 +1,42 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::solvers::genetic::selection::{
+    fitness_scaling::FitnessScaling, roulette_wheel_selection::RouletteWheelSelection,
+    selection_operator::SelectionOperator,
+};
+
+use super::fixtures::fitnesses;
+
+#[rstest]
+#[case(FitnessScaling::Inverted)]
+#[case(FitnessScaling::Normalized)]
+fn test_better_individuals_are_selected_more_often(
+    fitnesses: Vec<f32>,
+    #[case] scaling: FitnessScaling,
+) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let selection = RouletteWheelSelection::new(scaling);
+    let mut selections = [0; 4];
+
+    for _ in 0..1000 {
+        let parents = selection.select(&fitnesses, 1, &mut rng);
+        selections[parents[0]] += 1;
+    }
+
+    assert!(selections[1] > selections[3]);
+    assert!(selections[3] > selections[2]);
+    assert!(selections[2] > selections[0]);
+}
+
+#[rstest]
+fn test_normalized_scaling_maps_costs_between_the_best_and_the_worst() {
+    let fitnesses = [30.0, 10.0, 20.0];
+
+    let weights = FitnessScaling::Normalized.calculate_weights(&fitnesses);
+
+    assert!(weights[0] > 0.0 && weights[0] < 0.01);
+    assert!((weights[1] - weights[0] - 1.0).abs() < 1e-6);
+    assert!((weights[2] - weights[0] - 0.5).abs() < 1e-6);
+}
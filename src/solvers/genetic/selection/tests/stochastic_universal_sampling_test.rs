@@ -0,0 +1,30 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::solvers::genetic::selection::{
+    fitness_scaling::FitnessScaling, selection_operator::SelectionOperator,
+    stochastic_universal_sampling::StochasticUniversalSampling,
+};
+
+#[rstest]
+fn test_samples_each_individual_once_when_the_weights_are_equal() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let fitnesses = [10.0, 10.0, 10.0, 10.0];
+    let selection = StochasticUniversalSampling::new(FitnessScaling::Inverted);
+
+    let parents = selection.select(&fitnesses, 4, &mut rng);
+
+    assert_eq!(parents, vec![0, 1, 2, 3]);
+}
+
+#[rstest]
+fn test_dominant_individuals_are_sampled_more_than_once() {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let fitnesses = [100.0, 1.0, 100.0, 100.0];
+    let selection = StochasticUniversalSampling::new(FitnessScaling::Inverted);
+
+    let parents = selection.select(&fitnesses, 2, &mut rng);
+
+    assert_eq!(parents, vec![1, 1]);
+}
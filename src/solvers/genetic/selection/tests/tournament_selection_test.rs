@@ -0,0 +1,32 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::solvers::genetic::selection::{
+    selection_operator::SelectionOperator, tournament_selection::TournamentSelection,
+};
+
+use super::fixtures::fitnesses;
+
+#[rstest]
+fn test_a_full_tournament_selects_the_best_individuals(fitnesses: Vec<f32>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let selection = TournamentSelection::new(fitnesses.len());
+
+    let parents = selection.select(&fitnesses, 2, &mut rng);
+
+    assert_eq!(parents, vec![1, 3]);
+}
+
+#[rstest]
+fn test_selects_distinct_parents(fitnesses: Vec<f32>) {
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+    let selection = TournamentSelection::new(1);
+
+    for _ in 0..100 {
+        let parents = selection.select(&fitnesses, 2, &mut rng);
+
+        assert_eq!(parents.len(), 2);
+        assert_ne!(parents[0], parents[1]);
+    }
+}
@@ -0,0 +1,33 @@
+use rand::{seq::SliceRandom, Rng};
+
+use super::selection_operator::SelectionOperator;
+
+#[derive(Clone, Debug)]
+pub struct TournamentSelection {
+    size: usize,
+}
+
+impl<R: Rng + ?Sized> SelectionOperator<R> for TournamentSelection {
+    fn select(&self, fitnesses: &[f32], number_of_parents: usize, rng: &mut R) -> Vec<usize> {
+        let mut candidates: Vec<usize> = (0..fitnesses.len()).collect();
+        let mut parents = Vec::with_capacity(number_of_parents);
+
+        while parents.len() < number_of_parents && !candidates.is_empty() {
+            let winner = *candidates
+                .choose_multiple(rng, self.size)
+                .min_by(|index1, index2| fitnesses[**index1].total_cmp(&fitnesses[**index2]))
+                .unwrap_or_else(|| panic!("the tournament should have at least one contestant"));
+
+            candidates.retain(|candidate| *candidate != winner);
+            parents.push(winner);
+        }
+
+        parents
+    }
+}
+
+impl TournamentSelection {
+    pub fn new(size: usize) -> Self {
+        Self { size: size.max(1) }
+    }
+}
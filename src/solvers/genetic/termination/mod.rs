@@ -0,0 +1,2 @@
+pub mod termination_criteria;
+mod tests;
@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StopReason {
+    MaxGenerations,
+    TargetFitness,
+    EvaluationBudget,
+    Stagnation,
+    TimeLimit,
+}
+
+/// Optional criteria checked together with `max_generations`; the GA stops as soon as any of
+/// them is met.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TerminationCriteria {
+    pub time_limit: Option<Duration>,
+    pub max_generations_without_improvement: Option<u32>,
+    pub target_fitness: Option<f32>,
+    pub max_evaluations: Option<u32>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct SearchProgress {
+    pub(crate) generation: u32,
+    pub(crate) generations_without_improvement: u32,
+    pub(crate) evaluations: u32,
+    pub(crate) best_fitness: f32,
+    pub(crate) elapsed: Duration,
+}
+
+impl TerminationCriteria {
+    pub(crate) fn check(
+        &self,
+        max_generations: u32,
+        progress: &SearchProgress,
+    ) -> Option<StopReason> {
+        if self
+            .target_fitness
+            .is_some_and(|target_fitness| progress.best_fitness <= target_fitness)
+        {
+            return Some(StopReason::TargetFitness);
+        }
+
+        if progress.generation >= max_generations {
+            return Some(StopReason::MaxGenerations);
+        }
+
+        if self
+            .max_evaluations
+            .is_some_and(|max_evaluations| progress.evaluations >= max_evaluations)
+        {
+            return Some(StopReason::EvaluationBudget);
+        }
+
+        if self
+            .max_generations_without_improvement
+            .is_some_and(|max_stagnation| {
+                progress.generations_without_improvement >= max_stagnation
+            })
+        {
+            return Some(StopReason::Stagnation);
+        }
+
+        if self
+            .time_limit
+            .is_some_and(|time_limit| progress.elapsed >= time_limit)
+        {
+            return Some(StopReason::TimeLimit);
+        }
+
+        None
+    }
+}
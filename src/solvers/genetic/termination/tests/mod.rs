@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod termination_criteria_test;
@@ -0,0 +1,74 @@
+use std::time::Duration;
+
+use rstest::{fixture, rstest};
+
+use crate::solvers::genetic::termination::termination_criteria::{
+    SearchProgress, StopReason, TerminationCriteria,
+};
+
+#[fixture]
+fn progress() -> SearchProgress {
+    SearchProgress {
+        generation: 5,
+        generations_without_improvement: 3,
+        evaluations: 100,
+        best_fitness: 20.0,
+        elapsed: Duration::from_millis(50),
+    }
+}
+
+#[rstest]
+#[case::no_criteria(TerminationCriteria::default(), 10, None)]
+#[case::max_generations(TerminationCriteria::default(), 5, Some(StopReason::MaxGenerations))]
+#[case::time_limit(
+    TerminationCriteria { time_limit: Some(Duration::from_millis(50)), ..Default::default() },
+    10,
+    Some(StopReason::TimeLimit)
+)]
+#[case::time_limit_not_reached(
+    TerminationCriteria { time_limit: Some(Duration::from_secs(1)), ..Default::default() },
+    10,
+    None
+)]
+#[case::stagnation(
+    TerminationCriteria { max_generations_without_improvement: Some(3), ..Default::default() },
+    10,
+    Some(StopReason::Stagnation)
+)]
+#[case::target_fitness(
+    TerminationCriteria { target_fitness: Some(25.0), ..Default::default() },
+    10,
+    Some(StopReason::TargetFitness)
+)]
+#[case::target_fitness_not_reached(
+    TerminationCriteria { target_fitness: Some(15.0), ..Default::default() },
+    10,
+    None
+)]
+#[case::evaluation_budget(
+    TerminationCriteria { max_evaluations: Some(100), ..Default::default() },
+    10,
+    Some(StopReason::EvaluationBudget)
+)]
+#[case::combination(
+    TerminationCriteria {
+        max_generations_without_improvement: Some(10),
+        max_evaluations: Some(80),
+        ..Default::default()
+    },
+    10,
+    Some(StopReason::EvaluationBudget)
+)]
+#[case::target_fitness_comes_first(
+    TerminationCriteria { target_fitness: Some(20.0), ..Default::default() },
+    5,
+    Some(StopReason::TargetFitness)
+)]
+fn test_termination_criteria(
+    progress: SearchProgress,
+    #[case] criteria: TerminationCriteria,
+    #[case] max_generations: u32,
+    #[case] expected: Option<StopReason>,
+) {
+    assert_eq!(criteria.check(max_generations, &progress), expected);
+}
@@ -2,7 +2,12 @@ use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 use rstest::fixture;
 
-use crate::solvers::genetic::individual::Individual;
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::individual::Individual,
+    tests::fixtures::services_fixture::distance_service,
+};
 
 use crate::tests::fixtures::services_fixture::{route_service_factory, RouteServiceFactory};
 
@@ -20,3 +25,56 @@ pub(crate) fn individual_factory(route_service_factory: RouteServiceFactory) ->
 
     Box::new(wrapper)
 }
+
+pub(crate) type IndividualBuilder = Box<dyn Fn(Vec<(u32, Vec<Stop>)>) -> Individual>;
+
+#[fixture]
+pub(crate) fn individual_builder(distance_service: DistanceService) -> IndividualBuilder {
+    let wrapper = move |chromosomes: Vec<(u32, Vec<Stop>)>| -> Individual {
+        let chromosomes = chromosomes
+            .into_iter()
+            .enumerate()
+            .map(|(vehicle_id, (capacity, stops))| {
+                let mut chromosome = Route::new(Vehicle::new(vehicle_id as u32, capacity));
+
+                chromosome
+                    .replace_stops(stops, &distance_service)
+                    .unwrap_or_else(|_| panic!("the vehicle {vehicle_id} should support the load"));
+
+                chromosome
+            })
+            .collect();
+
+        Individual::new(chromosomes)
+    };
+
+    Box::new(wrapper)
+}
+
+#[cfg(test)]
+pub(crate) fn build_unchecked_individual(
+    chromosomes: Vec<(u32, Vec<Stop>)>,
+    distance_service: &DistanceService,
+) -> Individual {
+    let chromosomes = chromosomes
+        .into_iter()
+        .enumerate()
+        .map(|(vehicle_id, (capacity, stops))| {
+            let mut chromosome = Route::new(Vehicle::new(vehicle_id as u32, capacity));
+            chromosome.replace_stops_unchecked(stops, distance_service);
+
+            chromosome
+        })
+        .collect();
+
+    Individual::new(chromosomes)
+}
+
+#[cfg(test)]
+pub(crate) fn ids(individual: &Individual) -> Vec<Vec<u32>> {
+    individual
+        .chromosomes
+        .iter()
+        .map(|chromosome| crate::tests::fixtures::stops_fixture::ids(&chromosome.stops))
+        .collect()
+}
@@ -0,0 +1,80 @@
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::{
+        crossover::tests::order_crossover::fixtures::parents_fixture::{parents, Parents},
+        gene_sequence,
+    },
+    tests::fixtures::{services_fixture::distance_service, stops_fixture::ids},
+};
+
+#[rstest]
+fn test_flatten_concatenates_the_customers(parents: Parents) {
+    assert_eq!(ids(&gene_sequence::flatten(&parents.0)), vec![1, 2, 3]);
+    assert_eq!(ids(&gene_sequence::flatten(&parents.1)), vec![2, 1, 3]);
+}
+
+#[rstest]
+fn test_rebuild_keeps_the_template_route_sizes(
+    parents: Parents,
+    distance_service: DistanceService,
+) {
+    let genes = vec![Stop::new(3, 0), Stop::new(2, 0), Stop::new(1, 0)];
+
+    let offspring = gene_sequence::rebuild(&parents.0, &genes, &distance_service).unwrap();
+
+    assert_eq!(ids(&offspring.chromosomes[0].stops), vec![0, 3, 2, 0]);
+    assert_eq!(ids(&offspring.chromosomes[1].stops), vec![0, 1, 0]);
+    assert_eq!(offspring.fitness, 10.0);
+}
+
+#[rstest]
+fn test_rebuild_moves_genes_that_do_not_fit_to_the_next_chromosome(
+    parents: Parents,
+    distance_service: DistanceService,
+) {
+    let genes = vec![Stop::new(1, 60), Stop::new(2, 60), Stop::new(3, 0)];
+
+    let offspring = gene_sequence::rebuild(&parents.0, &genes, &distance_service).unwrap();
+
+    assert_eq!(ids(&offspring.chromosomes[0].stops), vec![0, 1, 0]);
+    assert_eq!(ids(&offspring.chromosomes[1].stops), vec![0, 2, 3, 0]);
+}
+
+#[rstest]
+fn test_rebuild_fails_when_the_fleet_cannot_support_the_load(
+    parents: Parents,
+    distance_service: DistanceService,
+) {
+    let genes = vec![Stop::new(1, 60), Stop::new(2, 60), Stop::new(3, 60)];
+
+    let offspring = gene_sequence::rebuild(&parents.0, &genes, &distance_service);
+
+    assert!(offspring.is_none());
+}
+
+#[rstest]
+fn test_insert_at_cheapest_positions_uses_empty_chromosomes(
+    parents: Parents,
+    distance_service: DistanceService,
+) {
+    let mut chromosomes = parents.0.chromosomes;
+    let removed = [Stop::new(3, 0)];
+
+    gene_sequence::remove_genes(
+        &mut chromosomes,
+        &removed.iter().copied().collect(),
+        &distance_service,
+    )
+    .unwrap();
+
+    assert_eq!(ids(&chromosomes[1].stops), vec![0]);
+
+    gene_sequence::insert_at_cheapest_positions(&mut chromosomes, &removed, &distance_service)
+        .unwrap();
+
+    assert_eq!(ids(&chromosomes[0].stops), vec![0, 1, 3, 2, 0]);
+    assert_eq!(ids(&chromosomes[1].stops), vec![0]);
+}
@@ -1,60 +1,479 @@
+use std::time::Duration;
+
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 use rstest::rstest;
 
+use crate::solvers::genetic::adaptation::adaptation_parameters::{
+    AdaptationParameters, AdaptationStrategy,
+};
+use crate::solvers::genetic::diversity::diversity_parameters::{
+    DiversityParameters, DiversityRecovery,
+};
 use crate::solvers::genetic::genetic_solver::GeneticSolverParameters;
-use crate::solvers::random::random_solver::RandomSolver;
-use crate::solvers::solver::Solver;
-use crate::solvers::two_stage_genetic::two_stage_genetic_solver::{
-    TwoStageGeneticSolver, TwoStageGeneticSolverParameters,
+use crate::solvers::genetic::infeasibility::infeasibility_parameters::InfeasibilityParameters;
+use crate::solvers::genetic::initialization::{
+    initialization_parameters::{InitializationParameters, InitializationSource, WeightedSource},
+    population_initializer::PopulationInitializer,
 };
+use crate::solvers::genetic::mutation::{
+    insertion_mutation::InsertionMutation,
+    inter_route_relocate_mutation::InterRouteRelocateMutation,
+    inter_route_swap_mutation::InterRouteSwapMutation,
+    inversion_mutation::InversionMutation,
+    mutation_strategy::{MutationStrategy, WeightedMutation},
+    scramble_mutation::ScrambleMutation,
+    swap_mutation::SwapMutation,
+};
+use crate::solvers::genetic::replacement::replacement_strategy::ReplacementStrategy;
+use crate::solvers::genetic::selection::{
+    fitness_scaling::FitnessScaling, rank_selection::RankSelection,
+    roulette_wheel_selection::RouletteWheelSelection, selection_strategy::SelectionStrategy,
+    stochastic_universal_sampling::StochasticUniversalSampling,
+    tournament_selection::TournamentSelection,
+};
+use crate::solvers::genetic::termination::termination_criteria::{StopReason, TerminationCriteria};
+use crate::solvers::solver::Solver;
 use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
 use crate::{
-    domain::stop::Stop, services::distance::distance_service::DistanceMatrix,
-    solvers::genetic::crossover::order_crossover::OrderCrossover,
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::{
+        distance::distance_service::{DistanceMatrix, DistanceService},
+        route::route_service::RouteMap,
+    },
+    solvers::{
+        genetic::crossover::{
+            best_cost_route_crossover::BestCostRouteCrossover,
+            crossover_operator::CrossoverOperator, order_crossover::OrderCrossover,
+            selective_route_exchange_crossover::SelectiveRouteExchangeCrossover,
+        },
+        vrp_solution::VrpSolution,
+    },
 };
 
 use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::genetic_algorithm_fixture::{
+    genetic_solver_factory, GeneticSolverFactory,
+};
 use crate::tests::fixtures::stops_fixture::stops;
 
 #[rstest]
 fn test_genetic_algorithm_can_generate_a_good_route(
-    distances: DistanceMatrix,
-    stops: Vec<Stop>,
-    vehicle_factory: VehicleFactory,
+    mut genetic_solver_factory: GeneticSolverFactory,
 ) {
-    let vehicles = vehicle_factory(2);
+    let mut genetic_solver = genetic_solver_factory.build(GeneticSolverParameters {
+        elite_size: 5,
+        max_generations: 10,
+        local_search_rate: 0.2,
+        mutations: vec![WeightedMutation::new(
+            MutationStrategy::Swap(SwapMutation),
+            0.05,
+        )],
+        ..Default::default()
+    });
 
-    let rng = ChaCha8Rng::seed_from_u64(0);
-    let mut random_solver =
-        RandomSolver::new(stops.clone(), vehicles, &distances, Box::new(rng.clone()));
+    let solution = genetic_solver.solve();
 
-    let parameters = TwoStageGeneticSolverParameters {
-        population_size: 10,
-        genetic_solver_parameters: GeneticSolverParameters {
-            elite_size: 5,
-            max_generations: 10,
-            local_search_rate: 0.2,
-            mutation_rate: 0.05,
+    let solution_v1 = &solution.routes.get(&0).unwrap().stops;
+    let solution_v2 = &solution.routes.get(&1).unwrap().stops;
+
+    assert_ne!(solution_v1.len(), 0);
+    assert_ne!(solution_v2.len(), 0);
+}
+
+#[rstest]
+#[case::tournament(SelectionStrategy::Tournament(TournamentSelection::new(3)))]
+#[case::rank(SelectionStrategy::Rank(RankSelection::new(1.5)))]
+#[case::roulette_wheel(SelectionStrategy::RouletteWheel(RouletteWheelSelection::new(
+    FitnessScaling::Normalized
+)))]
+#[case::stochastic_universal_sampling(SelectionStrategy::StochasticUniversalSampling(
+    StochasticUniversalSampling::new(FitnessScaling::Inverted)
+))]
+fn test_genetic_algorithm_can_use_any_selection_strategy(
+    mut genetic_solver_factory: GeneticSolverFactory,
+    #[case] selection: SelectionStrategy,
+) {
+    let mut genetic_solver = genetic_solver_factory.build(GeneticSolverParameters {
+        elite_size: 5,
+        max_generations: 10,
+        local_search_rate: 0.2,
+        mutations: vec![WeightedMutation::new(
+            MutationStrategy::Swap(SwapMutation),
+            0.05,
+        )],
+        selection,
+        ..Default::default()
+    });
+
+    let solution = genetic_solver.solve();
+
+    assert_ne!(solution.total_distance, f32::MAX);
+}
+
+#[rstest]
+fn test_genetic_algorithm_can_combine_weighted_mutations(
+    mut genetic_solver_factory: GeneticSolverFactory,
+) {
+    let mut genetic_solver = genetic_solver_factory.build(GeneticSolverParameters {
+        elite_size: 5,
+        max_generations: 10,
+        local_search_rate: 0.2,
+        mutations: vec![
+            WeightedMutation::new(MutationStrategy::Swap(SwapMutation), 0.1),
+            WeightedMutation::new(MutationStrategy::Inversion(InversionMutation), 0.1),
+            WeightedMutation::new(MutationStrategy::Scramble(ScrambleMutation::new(3)), 0.1),
+            WeightedMutation::new(MutationStrategy::Insertion(InsertionMutation), 0.1),
+            WeightedMutation::new(
+                MutationStrategy::InterRouteRelocate(InterRouteRelocateMutation),
+                0.2,
+            ),
+            WeightedMutation::new(
+                MutationStrategy::InterRouteSwap(InterRouteSwapMutation),
+                0.2,
+            ),
+        ],
+        ..Default::default()
+    });
+
+    let solution = genetic_solver.solve();
+
+    for route in solution.routes.values() {
+        let load: u32 = route.stops.iter().map(|stop| stop.usage).sum();
+        assert!(load < route.vehicle.capacity);
+    }
+
+    assert_ne!(solution.total_distance, f32::MAX);
+}
+
+#[rstest]
+#[case::steady_state(ReplacementStrategy::SteadyStateWorst)]
+#[case::mu_plus_lambda(ReplacementStrategy::MuPlusLambda { offspring_size: 6 })]
+#[case::mu_comma_lambda(ReplacementStrategy::MuCommaLambda { offspring_size: 12 })]
+#[case::elitist_generational(ReplacementStrategy::ElitistGenerational)]
+fn test_genetic_algorithm_can_use_any_replacement_strategy(
+    mut genetic_solver_factory: GeneticSolverFactory,
+    #[case] replacement: ReplacementStrategy,
+) {
+    let mut genetic_solver = genetic_solver_factory.build(GeneticSolverParameters {
+        elite_size: 2,
+        max_generations: 10,
+        local_search_rate: 0.2,
+        replacement,
+        ..Default::default()
+    });
+
+    let solution = genetic_solver.solve();
+
+    assert_ne!(solution.total_distance, f32::MAX);
+    assert_eq!(genetic_solver.get_generations(), 10);
+}
+
+#[rstest]
+fn test_genetic_algorithm_counts_failed_evaluations(genetic_solver_factory: GeneticSolverFactory) {
+    let mut genetic_solver_factory =
+        genetic_solver_factory.with_crossover(Box::new(OrderCrossover::new(0)));
+
+    let mut genetic_solver = genetic_solver_factory.build(GeneticSolverParameters {
+        max_generations: 7,
+        replacement: ReplacementStrategy::MuPlusLambda { offspring_size: 4 },
+        ..Default::default()
+    });
+
+    let solution = genetic_solver.solve();
+
+    assert_ne!(solution.total_distance, f32::MAX);
+    assert_eq!(genetic_solver.get_generations(), 7);
+    assert_eq!(genetic_solver.get_failed_evaluations(), 14);
+}
+
+#[rstest]
+#[case::immigrants(DiversityRecovery::Immigrants { ratio: 0.5 })]
+#[case::restart(DiversityRecovery::Restart)]
+fn test_genetic_algorithm_tracks_diversity(
+    mut genetic_solver_factory: GeneticSolverFactory,
+    #[case] recovery: DiversityRecovery,
+) {
+    let mut genetic_solver = genetic_solver_factory.build(GeneticSolverParameters {
+        elite_size: 1,
+        max_generations: 8,
+        diversity: DiversityParameters {
+            biased_fitness: true,
+            min_diversity: Some(1.0),
+            recovery,
+            ..Default::default()
         },
+        ..Default::default()
+    });
+
+    let solution = genetic_solver.solve();
+
+    assert_ne!(solution.total_distance, f32::MAX);
+    assert_eq!(genetic_solver.get_diversity_history().len(), 8);
+    assert!(genetic_solver
+        .get_diversity_history()
+        .iter()
+        .all(|diversity| (0.0..=2.0).contains(diversity)));
+}
+
+#[rstest]
+#[case::fixed(AdaptationStrategy::Fixed)]
+#[case::success_based(AdaptationStrategy::SuccessBased { target_success_rate: 0.2, factor: 1.5 })]
+#[case::diversity_based(AdaptationStrategy::DiversityBased { target_diversity: 0.5, factor: 1.5 })]
+#[case::self_adaptive(AdaptationStrategy::SelfAdaptive { learning_rate: 0.5 })]
+fn test_genetic_algorithm_logs_the_parameters_of_each_generation(
+    mut genetic_solver_factory: GeneticSolverFactory,
+    #[case] strategy: AdaptationStrategy,
+) {
+    let adaptation = AdaptationParameters {
+        strategy: strategy.clone(),
+        ..Default::default()
     };
 
-    let crossover_op = OrderCrossover::new(5);
+    let mut genetic_solver = genetic_solver_factory.build(GeneticSolverParameters {
+        elite_size: 2,
+        max_generations: 6,
+        mutations: vec![
+            WeightedMutation::new(MutationStrategy::Swap(SwapMutation), 0.3),
+            WeightedMutation::new(MutationStrategy::Inversion(InversionMutation), 0.3),
+        ],
+        local_search_rate: 0.2,
+        adaptation: adaptation.clone(),
+        ..Default::default()
+    });
+
+    let solution = genetic_solver.solve();
+    let history = genetic_solver.get_parameter_history();
+
+    assert_ne!(solution.total_distance, f32::MAX);
+    assert_eq!(history.len(), 6);
+    assert_eq!(history[0].elite_size, 2);
+
+    for parameters in history {
+        assert_eq!(parameters.mutation_rates.len(), 2);
+        assert!(parameters
+            .mutation_rates
+            .iter()
+            .chain([parameters.local_search_rate].iter())
+            .all(|rate| *rate <= adaptation.max_rate));
+    }
+
+    if strategy == AdaptationStrategy::Fixed {
+        assert!(history
+            .iter()
+            .all(|parameters| parameters.mutation_rates == vec![0.3, 0.3]
+                && parameters.local_search_rate == 0.2
+                && parameters.elite_size == 2));
+    }
+}
+
+#[rstest]
+#[case::max_generations(TerminationCriteria::default(), StopReason::MaxGenerations)]
+#[case::time_limit(
+    TerminationCriteria { time_limit: Some(Duration::ZERO), ..Default::default() },
+    StopReason::TimeLimit
+)]
+#[case::stagnation(
+    TerminationCriteria { max_generations_without_improvement: Some(2), ..Default::default() },
+    StopReason::Stagnation
+)]
+#[case::target_fitness(
+    TerminationCriteria { target_fitness: Some(100.0), ..Default::default() },
+    StopReason::TargetFitness
+)]
+#[case::evaluation_budget(
+    TerminationCriteria { max_evaluations: Some(30), ..Default::default() },
+    StopReason::EvaluationBudget
+)]
+fn test_genetic_algorithm_reports_why_it_stopped(
+    mut genetic_solver_factory: GeneticSolverFactory,
+    #[case] termination: TerminationCriteria,
+    #[case] expected: StopReason,
+) {
+    let mut genetic_solver = genetic_solver_factory.build(GeneticSolverParameters {
+        max_generations: 1000,
+        termination: termination.clone(),
+        ..Default::default()
+    });
+
+    let solution = genetic_solver.solve();
+
+    assert_ne!(solution.total_distance, f32::MAX);
+    assert_eq!(solution.stop_reason, Some(expected));
+    assert_eq!(genetic_solver.get_stop_reason(), Some(expected));
+
+    match expected {
+        StopReason::MaxGenerations => assert_eq!(genetic_solver.get_generations(), 1000),
+        StopReason::EvaluationBudget => assert!(genetic_solver.get_evaluations() >= 30),
+        StopReason::TimeLimit | StopReason::TargetFitness => {
+            assert_eq!(genetic_solver.get_generations(), 0)
+        }
+        StopReason::Stagnation => assert!(genetic_solver.get_generations() < 1000),
+    }
+}
 
-    let mut genetic_solver = TwoStageGeneticSolver::new(
-        stops,
+#[rstest]
+fn test_genetic_algorithm_can_start_from_a_mixed_population(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+    genetic_solver_factory: GeneticSolverFactory,
+) {
+    let initializer = PopulationInitializer::new(
+        stops.clone(),
+        vehicle_factory(2),
         &distances,
-        &mut random_solver,
-        parameters,
-        &crossover_op,
-        Box::new(rng),
+        InitializationParameters {
+            sources: vec![
+                WeightedSource::new(InitializationSource::Random, 0.5),
+                WeightedSource::new(InitializationSource::Greedy, 0.25),
+                WeightedSource::new(InitializationSource::Savings, 0.25),
+            ],
+            local_search: true,
+            ..Default::default()
+        },
+        Box::new(ChaCha8Rng::seed_from_u64(0)),
     );
 
+    let mut genetic_solver_factory = genetic_solver_factory.with_population_size(6);
+
+    let mut genetic_solver = genetic_solver_factory
+        .build(GeneticSolverParameters {
+            elite_size: 1,
+            max_generations: 5,
+            ..Default::default()
+        })
+        .with_initializer(initializer);
+
     let solution = genetic_solver.solve();
 
-    let solution_v1 = &solution.routes.get(&0).unwrap().stops;
-    let solution_v2 = &solution.routes.get(&1).unwrap().stops;
+    assert_eq!(solution.total_distance, 8.0);
+    assert_eq!(genetic_solver.get_generations(), 5);
+}
 
-    assert_ne!(solution_v1.len(), 0);
-    assert_ne!(solution_v2.len(), 0);
+#[rstest]
+fn test_genetic_algorithm_keeps_infeasible_offspring_apart(distances: DistanceMatrix) {
+    let stops = vec![
+        Stop::new(0, 0),
+        Stop::new(1, 4),
+        Stop::new(2, 4),
+        Stop::new(3, 4),
+        Stop::new(4, 0),
+    ];
+
+    let mut genetic_solver_factory =
+        GeneticSolverFactory::new(stops, distances).with_population_size(6);
+
+    let mut genetic_solver = genetic_solver_factory.build(GeneticSolverParameters {
+        elite_size: 1,
+        max_generations: 10,
+        infeasibility: Some(InfeasibilityParameters {
+            subpopulation_size: 4,
+            repair_rate: 1.0,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    let solution = genetic_solver.solve();
+
+    for route in solution.routes.values() {
+        let load: u32 = route.stops.iter().map(|stop| stop.usage).sum();
+
+        assert!(load < route.vehicle.capacity);
+    }
+
+    assert_eq!(genetic_solver.get_generations(), 10);
+    assert!(genetic_solver.get_infeasible_individuals() <= 4);
+    assert!(genetic_solver.get_repaired_individuals() > 0);
+}
+
+struct PresetSolver {
+    solutions: Vec<VrpSolution>,
+    next: usize,
+}
+
+impl Solver<VrpSolution> for PresetSolver {
+    fn solve(&mut self) -> VrpSolution {
+        let solution = self.solutions[self.next % self.solutions.len()].clone();
+        self.next += 1;
+
+        solution
+    }
+}
+
+fn build_solution(routes: Vec<Vec<Stop>>, distance_service: &DistanceService) -> VrpSolution {
+    let routes: RouteMap = routes
+        .into_iter()
+        .enumerate()
+        .map(|(vehicle_id, stops)| {
+            let mut route = Route::new(Vehicle::new(vehicle_id as u32, 10));
+            route.replace_stops_unchecked(stops, distance_service);
+
+            (vehicle_id as u32, route)
+        })
+        .collect();
+
+    let total_distance = routes.values().map(|route| route.total_distance()).sum();
+
+    VrpSolution::new(&routes, total_distance)
+}
+
+#[rstest]
+#[case::best_cost_route(Box::new(BestCostRouteCrossover::new(5)))]
+#[case::selective_route_exchange(Box::new(SelectiveRouteExchangeCrossover::new(5)))]
+fn test_route_based_crossovers_give_up_on_overloaded_parents(
+    distances: DistanceMatrix,
+    #[case] crossover_op: Box<dyn CrossoverOperator<ChaCha8Rng>>,
+) {
+    let stops = vec![
+        Stop::new(0, 0),
+        Stop::new(1, 6),
+        Stop::new(2, 6),
+        Stop::new(3, 6),
+        Stop::new(4, 0),
+    ];
+    let distance_service = DistanceService::new(stops.clone(), &distances);
+    let [depot, stop1, stop2, stop3] = [stops[0], stops[1], stops[2], stops[3]];
+
+    let first_stage_solver = PresetSolver {
+        solutions: vec![
+            build_solution(
+                vec![
+                    vec![depot, stop1, depot],
+                    vec![depot, stop2, depot],
+                    vec![depot, stop3, depot],
+                ],
+                &distance_service,
+            ),
+            build_solution(
+                vec![
+                    vec![depot, stop1, stop2, depot],
+                    vec![depot, stop3, depot],
+                    vec![depot],
+                ],
+                &distance_service,
+            ),
+        ],
+        next: 0,
+    };
+
+    let mut genetic_solver_factory = GeneticSolverFactory::new(stops, distances)
+        .with_population_size(4)
+        .with_first_stage_solver(Box::new(first_stage_solver))
+        .with_crossover(crossover_op);
+
+    let mut genetic_solver = genetic_solver_factory.build(GeneticSolverParameters {
+        elite_size: 1,
+        max_generations: 10,
+        infeasibility: Some(InfeasibilityParameters {
+            repair_rate: 0.0,
+            ..Default::default()
+        }),
+        ..Default::default()
+    });
+
+    genetic_solver.solve();
+
+    assert_eq!(genetic_solver.get_generations(), 10);
 }
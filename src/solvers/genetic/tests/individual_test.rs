@@ -5,7 +5,10 @@ use crate::domain::stop::Stop;
 use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
 use crate::tests::fixtures::stops_fixture::stops;
 
+use crate::services::distance::distance_service::DistanceService;
 use crate::solvers::genetic::individual::Individual;
+use crate::solvers::genetic::tests::fixtures::build_unchecked_individual;
+use crate::tests::fixtures::services_fixture::distance_service;
 
 #[rstest]
 fn test_fitness_is_correct(stops: Vec<Stop>, route_factory: RouteFactory) {
@@ -38,3 +41,24 @@ fn test_gene_swap(stops: Vec<Stop>, route_factory: RouteFactory) {
         stops[1].id
     );
 }
+
+#[rstest]
+#[case::feasible(vec![(10, vec![Stop::new(0, 0), Stop::new(1, 4), Stop::new(2, 5), Stop::new(0, 0)])], 0)]
+#[case::at_capacity(vec![(10, vec![Stop::new(0, 0), Stop::new(1, 5), Stop::new(2, 5), Stop::new(0, 0)])], 1)]
+#[case::overloaded_routes(
+    vec![
+        (10, vec![Stop::new(0, 0), Stop::new(1, 8), Stop::new(2, 4), Stop::new(0, 0)]),
+        (5, vec![Stop::new(0, 0), Stop::new(3, 6), Stop::new(0, 0)]),
+    ],
+    5
+)]
+fn test_overload(
+    distance_service: DistanceService,
+    #[case] chromosomes: Vec<(u32, Vec<Stop>)>,
+    #[case] expected: u32,
+) {
+    let individual = build_unchecked_individual(chromosomes, &distance_service);
+
+    assert_eq!(individual.get_overload(), expected);
+    assert_eq!(individual.is_feasible(), expected == 0);
+}
@@ -1,5 +1,7 @@
 pub(crate) mod fixtures;
 #[cfg(test)]
+mod gene_sequence_test;
+#[cfg(test)]
 mod genetic_algorithm_test;
 #[cfg(test)]
 mod individual_test;
@@ -1,12 +1,15 @@
+use std::{cell::RefCell, rc::Rc};
+
 use crate::{
     local_search::local_searcher::LocalSearcher,
-    solvers::{solution::Solution, solver::Solver},
+    solvers::{solution::Solution, solution_collector::SolutionCollector, solver::Solver},
 };
 
 pub struct GraspSolver<S: Solution + Default> {
     max_improvement_times: u8,
     first_stage_solver: Box<dyn Solver<S>>,
     local_searcher: Box<dyn LocalSearcher<S> + 'static>,
+    collector: Option<Rc<RefCell<dyn SolutionCollector<S>>>>,
 }
 
 impl<S> GraspSolver<S>
@@ -22,8 +25,13 @@ where
             local_searcher,
             max_improvement_times,
             first_stage_solver,
+            collector: None,
         }
     }
+
+    pub fn set_collector(&mut self, collector: Rc<RefCell<dyn SolutionCollector<S>>>) {
+        self.collector = Some(collector);
+    }
 }
 
 impl<S> Solver<S> for GraspSolver<S>
@@ -38,6 +46,10 @@ where
             let mut solution = self.first_stage_solver.solve();
             self.local_searcher.run(&mut solution);
 
+            if let Some(collector) = &self.collector {
+                collector.borrow_mut().collect(&solution);
+            }
+
             if solution.is_better_than(&best_solution) {
                 best_solution = solution;
                 times_without_improvement = 0;
@@ -1,4 +1,10 @@
-use crate::services::distance::distance_service::DistanceMatrix;
+use std::rc::Rc;
+
+use crate::local_search::{
+    random_swap_shaker::RandomSwapShaker, two_opt::TwoOptSearcher,
+    variable_neighborhood_search::VariableNeighborhoodSearch,
+};
+use crate::services::distance::distance_service::{DistanceMatrix, DistanceService};
 use crate::solvers::grasp::vrp_grasp_solver::GraspSolverParameters;
 use crate::solvers::solver::Solver;
 use rand::SeedableRng;
@@ -35,3 +41,39 @@ fn test_grasp_solution_is_correct(
     assert_ne!(solution_v2.len(), 0);
     assert_eq!(solution.total_distance, 10.0);
 }
+
+#[rstest]
+fn test_grasp_accepts_a_variable_neighborhood_search(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let rng = ChaCha8Rng::seed_from_u64(0);
+    let vehicles = vehicle_factory(2);
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let parameters = GraspSolverParameters {
+        rcl_size: 3,
+        max_improvement_times: 3,
+    };
+
+    let local_searcher = VariableNeighborhoodSearch::new(
+        3,
+        2,
+        Box::new(RandomSwapShaker::new(distance_service.clone(), rng.clone())),
+        Box::new(TwoOptSearcher::new(distance_service.clone())),
+    );
+
+    let mut solver = VrpGraspSolver::with_local_searcher(
+        stops,
+        vehicles,
+        distance_service,
+        parameters,
+        rng,
+        Box::new(local_searcher),
+    );
+
+    let solution = solver.solve();
+
+    assert_eq!(solution.total_distance, 10.0);
+}
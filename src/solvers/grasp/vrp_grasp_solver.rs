@@ -4,14 +4,14 @@ use rand::Rng;
 
 use crate::{
     domain::{stop::Stop, vehicle::Vehicle},
-    local_search::two_opt::TwoOptSearcher,
+    local_search::{local_searcher::LocalSearcher, two_opt::TwoOptSearcher},
     services::{
         distance::distance_service::{DistanceMatrix, DistanceService},
         route::route_service::RouteService,
     },
     solvers::{
-        solver::Solver, vrp_sequential::vrp_sequential_solver::VrpSequentialSolver,
-        vrp_solution::VrpSolution,
+        set_partitioning::route_pool::RoutePool, solver::Solver,
+        vrp_sequential::vrp_sequential_solver::VrpSequentialSolver, vrp_solution::VrpSolution,
     },
 };
 
@@ -42,13 +42,32 @@ impl VrpGraspSolver {
         rng: R,
     ) -> Self {
         let distance_service = Rc::new(DistanceService::new(stops.clone(), distances));
+        let local_searcher = Box::new(TwoOptSearcher::new(distance_service.clone()));
 
+        Self::with_local_searcher(
+            stops,
+            vehicles,
+            distance_service,
+            parameters,
+            rng,
+            local_searcher,
+        )
+    }
+
+    pub fn with_local_searcher<R: Rng + 'static>(
+        stops: Vec<Stop>,
+        vehicles: Vec<Vehicle>,
+        distance_service: Rc<DistanceService>,
+        parameters: GraspSolverParameters,
+        rng: R,
+        local_searcher: Box<dyn LocalSearcher<VrpSolution>>,
+    ) -> Self {
         let candidate_chooser = Box::new(GraspCandidateChooser {
             rcl_size: parameters.rcl_size,
             rng: RefCell::new(rng),
         });
 
-        let route_service = RouteService::new(stops, vehicles, distance_service.clone());
+        let route_service = RouteService::new(stops, vehicles, distance_service);
 
         let first_stage_solver = VrpSequentialSolver {
             route_service,
@@ -59,8 +78,13 @@ impl VrpGraspSolver {
             grasp_solver: GraspSolver::new(
                 parameters.max_improvement_times,
                 Box::new(first_stage_solver),
-                Box::new(TwoOptSearcher::new(distance_service)),
+                local_searcher,
             ),
         }
     }
+
+    pub fn with_route_pool(mut self, route_pool: Rc<RefCell<RoutePool>>) -> Self {
+        self.grasp_solver.set_collector(route_pool);
+        self
+    }
 }
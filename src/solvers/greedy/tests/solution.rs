@@ -23,10 +23,7 @@ impl Solution for TestSolution {
 
 impl TestSolution {
     pub(super) fn insert(&mut self, sequence_id: u32, candidate_id: u32, cost: u32) {
-        self.data
-            .entry(sequence_id)
-            .or_insert_with(Vec::new)
-            .push(candidate_id);
+        self.data.entry(sequence_id).or_default().push(candidate_id);
         self.cost += cost;
     }
 }
@@ -0,0 +1,73 @@
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::distance::distance_service::DistanceService,
+    solvers::genetic::diversity::broken_pairs_distance::Neighbors,
+};
+
+#[derive(Clone)]
+pub(super) struct GiantTour {
+    pub(super) tour: Vec<Stop>,
+    pub(super) routes: Vec<Vec<Stop>>,
+    pub(super) distance: f32,
+    pub(super) excess_load: u32,
+    pub(super) penalized_cost: f32,
+    pub(super) neighbors: Neighbors,
+}
+
+impl GiantTour {
+    pub(super) fn new(
+        routes: Vec<Vec<Stop>>,
+        depot: &Stop,
+        vehicles: &[Vehicle],
+        capacity_penalty: f32,
+        distance_service: &DistanceService,
+    ) -> Self {
+        let distance = routes
+            .iter()
+            .map(|route| Self::calculate_route_distance(route, depot, distance_service))
+            .sum();
+
+        let excess_load = routes
+            .iter()
+            .zip(vehicles)
+            .map(|(route, vehicle)| Self::calculate_excess_load(route, depot, vehicle))
+            .sum();
+
+        Self {
+            distance,
+            excess_load,
+            tour: routes.iter().flatten().copied().collect(),
+            neighbors: Neighbors::new(&routes, depot),
+            penalized_cost: distance + capacity_penalty * excess_load as f32,
+            routes,
+        }
+    }
+
+    pub(super) fn calculate_route_distance(
+        route: &[Stop],
+        depot: &Stop,
+        distance_service: &DistanceService,
+    ) -> f32 {
+        let (first_stop, last_stop) = match (route.first(), route.last()) {
+            (Some(first_stop), Some(last_stop)) => (first_stop, last_stop),
+            _ => return 0.0,
+        };
+
+        distance_service.get_distance(depot, first_stop)
+            + route
+                .windows(2)
+                .map(|window| distance_service.get_distance(&window[0], &window[1]))
+                .sum::<f32>()
+            + distance_service.get_distance(last_stop, depot)
+    }
+
+    pub(super) fn calculate_excess_load(route: &[Stop], depot: &Stop, vehicle: &Vehicle) -> u32 {
+        let load: u32 = 2 * depot.usage + route.iter().map(|stop| stop.usage).sum::<u32>();
+
+        vehicle.calculate_excess_load(load)
+    }
+
+    pub(super) fn is_feasible(&self) -> bool {
+        self.excess_load == 0
+    }
+}
@@ -0,0 +1,309 @@
+use std::rc::Rc;
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    local_search::local_searcher::LocalSearcher,
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
+    solvers::{
+        genetic::{
+            diversity::{
+                broken_pairs_distance::{self, Neighbors},
+                population_diversity,
+            },
+            genetic_solver::{GeneticSolverParameters, MAX_PARENT_DRAWS},
+            mutation::mutation_strategy,
+        },
+        solver::Solver,
+        vrp_solution::VrpSolution,
+    },
+};
+
+use super::{giant_tour::GiantTour, split::Split};
+
+pub struct HybridGeneticSolverParameters {
+    pub population_size: usize,
+    pub generation_size: usize,
+    pub closest_neighbors: usize,
+    pub capacity_penalty: f32,
+    pub genetic_solver_parameters: GeneticSolverParameters,
+}
+
+pub struct HybridGeneticSolver<R: Rng + ?Sized> {
+    split: Split,
+    depot: Stop,
+    customers: Vec<Stop>,
+    unassigned_stops: Vec<Stop>,
+    best: Option<GiantTour>,
+    population: Vec<GiantTour>,
+    parameters: HybridGeneticSolverParameters,
+    local_searcher: Box<dyn LocalSearcher<VrpSolution>>,
+    distance_service: Rc<DistanceService>,
+    rng: Box<R>,
+}
+
+impl<R: Rng + ?Sized> Solver<VrpSolution> for HybridGeneticSolver<R> {
+    fn solve(&mut self) -> VrpSolution {
+        self.best = None;
+        self.initialize_population();
+
+        for _ in 0..self.parameters.genetic_solver_parameters.max_generations {
+            let broken_pairs_distances = self.calculate_broken_pairs_distances();
+            let biased_fitnesses = self.calculate_biased_fitnesses(&broken_pairs_distances);
+
+            let (parent1, parent2) = self.select_parents(&biased_fitnesses);
+
+            let mut tour = Self::order_crossover(
+                &self.population[parent1].tour,
+                &self.population[parent2].tour,
+                &mut self.rng,
+            );
+
+            self.mutate(&mut tour);
+
+            let offspring = self.split.run(&tour);
+            self.add_individual(offspring);
+
+            if self.population.len()
+                >= self.parameters.population_size + self.parameters.generation_size
+            {
+                self.select_survivors();
+            }
+        }
+
+        self.population.clear();
+
+        match self.best.as_ref().and_then(|best| self.split.decode(best)) {
+            Some(solution) => solution.with_unassigned_stops(self.unassigned_stops.clone()),
+            None => VrpSolution::default().with_unassigned_stops(
+                [self.customers.as_slice(), self.unassigned_stops.as_slice()].concat(),
+            ),
+        }
+    }
+}
+
+impl<R: Rng + ?Sized> HybridGeneticSolver<R> {
+    pub fn new(
+        stops: Vec<Stop>,
+        vehicles: Vec<Vehicle>,
+        distances: &DistanceMatrix,
+        parameters: HybridGeneticSolverParameters,
+        local_searcher: Box<dyn LocalSearcher<VrpSolution>>,
+        rng: Box<R>,
+    ) -> Self {
+        let depot = stops[0];
+        let distance_service = Rc::new(DistanceService::new(stops.clone(), distances));
+
+        let max_capacity = vehicles
+            .iter()
+            .map(|vehicle| vehicle.capacity)
+            .max()
+            .unwrap_or_default();
+
+        let (customers, unassigned_stops) = stops
+            .into_iter()
+            .skip(1)
+            .partition(|stop| 2 * depot.usage + stop.usage < max_capacity);
+
+        Self {
+            rng,
+            depot,
+            customers,
+            unassigned_stops,
+            local_searcher,
+            best: None,
+            population: Vec::new(),
+            split: Split::new(
+                depot,
+                vehicles,
+                parameters.capacity_penalty,
+                distance_service.clone(),
+            ),
+            distance_service,
+            parameters,
+        }
+    }
+
+    fn initialize_population(&mut self) {
+        self.population.clear();
+
+        for _ in 0..self.parameters.population_size {
+            let mut tour = self.customers.clone();
+            tour.shuffle(&mut self.rng);
+
+            let individual = self.split.run(&tour);
+            self.add_individual(individual);
+        }
+    }
+
+    fn add_individual(&mut self, individual: GiantTour) {
+        let local_search_rate = self.parameters.genetic_solver_parameters.local_search_rate;
+
+        let individual = match self.rng.gen_bool(local_search_rate.into()) {
+            true => self.educate(individual),
+            false => individual,
+        };
+
+        let is_new_best = match &self.best {
+            Some(best) => individual.distance < best.distance,
+            None => true,
+        };
+
+        if individual.is_feasible() && is_new_best {
+            self.best = Some(individual.clone());
+        }
+
+        self.population.push(individual);
+    }
+
+    fn educate(&self, individual: GiantTour) -> GiantTour {
+        match self.split.decode(&individual) {
+            Some(mut solution) => {
+                self.local_searcher.run(&mut solution);
+                self.split.encode(&solution)
+            }
+            None => individual,
+        }
+    }
+
+    fn mutate(&mut self, tour: &mut Vec<Stop>) {
+        if tour.len() < 2 {
+            return;
+        }
+
+        mutation_strategy::mutate_giant_tour(
+            &self.parameters.genetic_solver_parameters.mutations,
+            self.depot,
+            tour,
+            self.rng.as_mut(),
+            &self.distance_service,
+        );
+    }
+
+    pub(super) fn order_crossover(parent1: &[Stop], parent2: &[Stop], rng: &mut R) -> Vec<Stop> {
+        let number_of_stops = parent1.len();
+
+        if number_of_stops < 2 {
+            return parent1.to_vec();
+        }
+
+        let first_cut = rng.gen_range(0..number_of_stops);
+        let second_cut = rng.gen_range(first_cut + 1..=number_of_stops);
+
+        Self::order_crossover_between(parent1, parent2, first_cut, second_cut)
+    }
+
+    pub(super) fn order_crossover_between(
+        parent1: &[Stop],
+        parent2: &[Stop],
+        first_cut: usize,
+        second_cut: usize,
+    ) -> Vec<Stop> {
+        let number_of_stops = parent1.len();
+        let inherited = &parent1[first_cut..second_cut];
+
+        let mut remaining = (0..number_of_stops)
+            .map(|offset| parent2[(second_cut + offset) % number_of_stops])
+            .filter(|stop| !inherited.contains(stop));
+
+        let mut offspring = vec![parent1[0]; number_of_stops];
+        offspring[first_cut..second_cut].copy_from_slice(inherited);
+
+        for offset in 0..(number_of_stops - inherited.len()) {
+            offspring[(second_cut + offset) % number_of_stops] = remaining
+                .next()
+                .expect("the second parent should hold every stop of the first one");
+        }
+
+        offspring
+    }
+
+    fn calculate_broken_pairs_distances(&self) -> Vec<Vec<f32>> {
+        let neighbors: Vec<&Neighbors> = self
+            .population
+            .iter()
+            .map(|individual| &individual.neighbors)
+            .collect();
+
+        broken_pairs_distance::calculate_distances(&neighbors)
+    }
+
+    pub(super) fn calculate_biased_fitnesses(
+        &self,
+        broken_pairs_distances: &[Vec<f32>],
+    ) -> Vec<f32> {
+        let costs: Vec<f32> = self
+            .population
+            .iter()
+            .map(|individual| individual.penalized_cost)
+            .collect();
+
+        population_diversity::calculate_biased_fitnesses(
+            &costs,
+            broken_pairs_distances,
+            self.parameters.genetic_solver_parameters.elite_size,
+            self.parameters.closest_neighbors,
+        )
+    }
+
+    pub(super) fn select_parents(&mut self, biased_fitnesses: &[f32]) -> (usize, usize) {
+        let parent1 = self.binary_tournament(biased_fitnesses);
+        let mut parent2 = self.binary_tournament(biased_fitnesses);
+
+        // Same as in the genetic solver: crossing a parent with itself only copies it.
+        for _ in 0..MAX_PARENT_DRAWS {
+            if biased_fitnesses.len() < 2 || parent1 != parent2 {
+                break;
+            }
+
+            parent2 = self.binary_tournament(biased_fitnesses);
+        }
+
+        (parent1, parent2)
+    }
+
+    fn binary_tournament(&mut self, biased_fitnesses: &[f32]) -> usize {
+        let first_index = self.rng.gen_range(0..biased_fitnesses.len());
+        let second_index = self.rng.gen_range(0..biased_fitnesses.len());
+
+        match biased_fitnesses[first_index] <= biased_fitnesses[second_index] {
+            true => first_index,
+            false => second_index,
+        }
+    }
+
+    fn select_survivors(&mut self) {
+        let mut broken_pairs_distances = self.calculate_broken_pairs_distances();
+
+        while self.population.len() > self.parameters.population_size {
+            let biased_fitnesses = self.calculate_biased_fitnesses(&broken_pairs_distances);
+
+            let is_clone: Vec<bool> = broken_pairs_distances
+                .iter()
+                .enumerate()
+                .map(|(index, distances)| {
+                    distances
+                        .iter()
+                        .enumerate()
+                        .any(|(other_index, distance)| other_index != index && *distance == 0.0)
+                })
+                .collect();
+
+            let worst_index = (0..self.population.len())
+                .max_by(|index1, index2| {
+                    is_clone[*index1]
+                        .cmp(&is_clone[*index2])
+                        .then(biased_fitnesses[*index1].total_cmp(&biased_fitnesses[*index2]))
+                })
+                .expect("the population should not be empty");
+
+            self.population.swap_remove(worst_index);
+            broken_pairs_distances.swap_remove(worst_index);
+
+            for distances in broken_pairs_distances.iter_mut() {
+                distances.swap_remove(worst_index);
+            }
+        }
+    }
+}
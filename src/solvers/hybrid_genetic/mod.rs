@@ -0,0 +1,4 @@
+mod giant_tour;
+pub mod hybrid_genetic_solver;
+mod split;
+mod tests;
@@ -0,0 +1,157 @@
+use std::rc::Rc;
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::{distance::distance_service::DistanceService, route::route_service::RouteMap},
+    solvers::vrp_solution::VrpSolution,
+};
+
+use super::giant_tour::GiantTour;
+
+pub(super) struct Split {
+    depot: Stop,
+    vehicles: Vec<Vehicle>,
+    capacity_penalty: f32,
+    distance_service: Rc<DistanceService>,
+}
+
+impl Split {
+    pub(super) fn new(
+        depot: Stop,
+        vehicles: Vec<Vehicle>,
+        capacity_penalty: f32,
+        distance_service: Rc<DistanceService>,
+    ) -> Self {
+        Self {
+            depot,
+            capacity_penalty,
+            distance_service,
+            vehicles,
+        }
+    }
+
+    pub(super) fn evaluate(&self, routes: Vec<Vec<Stop>>) -> GiantTour {
+        GiantTour::new(
+            routes,
+            &self.depot,
+            &self.vehicles,
+            self.capacity_penalty,
+            &self.distance_service,
+        )
+    }
+
+    pub(super) fn run(&self, tour: &[Stop]) -> GiantTour {
+        let number_of_stops = tour.len();
+        let number_of_vehicles = self.vehicles.len();
+
+        let mut costs = vec![vec![f32::MAX; number_of_stops + 1]; number_of_vehicles + 1];
+        let mut predecessors = vec![vec![0; number_of_stops + 1]; number_of_vehicles + 1];
+
+        costs[0][0] = 0.0;
+
+        for vehicle_index in 1..=number_of_vehicles {
+            let vehicle = &self.vehicles[vehicle_index - 1];
+
+            for start in 0..=number_of_stops {
+                let previous_cost = costs[vehicle_index - 1][start];
+
+                if previous_cost == f32::MAX {
+                    continue;
+                }
+
+                if previous_cost < costs[vehicle_index][start] {
+                    costs[vehicle_index][start] = previous_cost;
+                    predecessors[vehicle_index][start] = start;
+                }
+
+                let mut load = 2 * self.depot.usage;
+                let mut inner_distance = 0.0;
+
+                for end in (start + 1)..=number_of_stops {
+                    let last_stop = &tour[end - 1];
+
+                    load += last_stop.usage;
+
+                    if end > start + 1 {
+                        inner_distance += self
+                            .distance_service
+                            .get_distance(&tour[end - 2], last_stop);
+                    }
+
+                    let route_cost = self
+                        .distance_service
+                        .get_distance(&self.depot, &tour[start])
+                        + inner_distance
+                        + self.distance_service.get_distance(last_stop, &self.depot)
+                        + self.capacity_penalty * vehicle.calculate_excess_load(load) as f32;
+
+                    if previous_cost + route_cost < costs[vehicle_index][end] {
+                        costs[vehicle_index][end] = previous_cost + route_cost;
+                        predecessors[vehicle_index][end] = start;
+                    }
+                }
+            }
+        }
+
+        let mut routes = vec![Vec::new(); number_of_vehicles];
+        let mut end = number_of_stops;
+
+        for vehicle_index in (1..=number_of_vehicles).rev() {
+            let start = predecessors[vehicle_index][end];
+            routes[vehicle_index - 1] = tour[start..end].to_vec();
+            end = start;
+        }
+
+        self.evaluate(routes)
+    }
+
+    pub(super) fn decode(&self, giant_tour: &GiantTour) -> Option<VrpSolution> {
+        if !giant_tour.is_feasible() {
+            return None;
+        }
+
+        let routes: RouteMap = self
+            .vehicles
+            .iter()
+            .zip(giant_tour.routes.iter())
+            .map(|(vehicle, customers)| {
+                let mut route = Route::new(Vehicle::new(vehicle.id, vehicle.capacity));
+
+                let mut stops = Vec::with_capacity(customers.len() + 2);
+                stops.push(self.depot);
+                stops.extend_from_slice(customers);
+                stops.push(self.depot);
+
+                route
+                    .replace_stops(stops, &self.distance_service)
+                    .unwrap_or_else(|_| {
+                        panic!("the vehicle {} should support the load", vehicle.id)
+                    });
+
+                (vehicle.id, route)
+            })
+            .collect();
+
+        let total_distance = routes.values().map(|route| route.total_distance()).sum();
+
+        Some(VrpSolution::new(&routes, total_distance))
+    }
+
+    pub(super) fn encode(&self, solution: &VrpSolution) -> GiantTour {
+        let routes = self
+            .vehicles
+            .iter()
+            .map(|vehicle| match solution.routes.get(&vehicle.id) {
+                Some(route) => route
+                    .stops
+                    .iter()
+                    .filter(|stop| stop.id != self.depot.id)
+                    .copied()
+                    .collect(),
+                None => Vec::new(),
+            })
+            .collect();
+
+        self.evaluate(routes)
+    }
+}
@@ -0,0 +1,167 @@
+use std::rc::Rc;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    local_search::two_opt::TwoOptSearcher,
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
+    solvers::{
+        genetic::{
+            genetic_solver::GeneticSolverParameters,
+            mutation::{
+                mutation_strategy::{MutationStrategy, WeightedMutation},
+                swap_mutation::SwapMutation,
+            },
+        },
+        hybrid_genetic::hybrid_genetic_solver::{
+            HybridGeneticSolver, HybridGeneticSolverParameters,
+        },
+        solver::Solver,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::{ids, stops};
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn test_order_crossover_keeps_a_permutation(stops: Vec<Stop>) {
+    let parent1 = vec![stops[1], stops[2], stops[3], stops[4]];
+    let parent2 = vec![stops[4], stops[3], stops[2], stops[1]];
+
+    let offspring =
+        HybridGeneticSolver::<ChaCha8Rng>::order_crossover_between(&parent1, &parent2, 1, 3);
+
+    assert_eq!(ids(&offspring), vec![4, 2, 3, 1]);
+}
+
+#[rstest]
+fn test_hybrid_genetic_solver_finds_a_good_solution(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let parameters = HybridGeneticSolverParameters {
+        population_size: 5,
+        generation_size: 5,
+        closest_neighbors: 2,
+        capacity_penalty: 10.0,
+        genetic_solver_parameters: GeneticSolverParameters {
+            elite_size: 2,
+            mutations: vec![WeightedMutation::new(
+                MutationStrategy::Swap(SwapMutation),
+                0.1,
+            )],
+            max_generations: 20,
+            local_search_rate: 0.5,
+            ..Default::default()
+        },
+    };
+
+    let mut solver = HybridGeneticSolver::new(
+        stops,
+        vehicle_factory(2),
+        &distances,
+        parameters,
+        Box::new(TwoOptSearcher::new(distance_service)),
+        Box::new(ChaCha8Rng::seed_from_u64(0)),
+    );
+
+    let solution = solver.solve();
+
+    let mut visited_stops: Vec<u32> = solution
+        .routes
+        .values()
+        .flat_map(|route| ids(&route.stops[1..route.stops.len() - 1]))
+        .collect();
+    visited_stops.sort_unstable();
+
+    assert_eq!(visited_stops, vec![1, 2, 3]);
+    assert_eq!(ids(&solution.unassigned_stops), vec![4]);
+    assert_eq!(solution.total_distance, 8.0);
+    assert!(!solution.is_feasible());
+}
+
+#[rstest]
+fn test_hybrid_genetic_solver_reports_when_no_feasible_solution_is_found(
+    distances: DistanceMatrix,
+) {
+    let stops = vec![
+        Stop::new(0, 0),
+        Stop::new(1, 6),
+        Stop::new(2, 6),
+        Stop::new(3, 6),
+        Stop::new(4, 6),
+    ];
+
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let parameters = HybridGeneticSolverParameters {
+        population_size: 5,
+        generation_size: 5,
+        closest_neighbors: 2,
+        capacity_penalty: 10.0,
+        genetic_solver_parameters: GeneticSolverParameters {
+            max_generations: 5,
+            ..Default::default()
+        },
+    };
+
+    let mut solver = HybridGeneticSolver::new(
+        stops,
+        vec![Vehicle::new(0, 10), Vehicle::new(1, 10)],
+        &distances,
+        parameters,
+        Box::new(TwoOptSearcher::new(distance_service)),
+        Box::new(ChaCha8Rng::seed_from_u64(0)),
+    );
+
+    let solution = solver.solve();
+
+    let mut unassigned_stops = ids(&solution.unassigned_stops);
+    unassigned_stops.sort_unstable();
+
+    assert!(solution.routes.is_empty());
+    assert_eq!(unassigned_stops, vec![1, 2, 3, 4]);
+    assert!(!solution.is_feasible());
+}
+
+#[rstest]
+fn test_parent_selection_re_draws_a_repeated_parent(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let parameters = HybridGeneticSolverParameters {
+        population_size: 2,
+        generation_size: 2,
+        closest_neighbors: 2,
+        capacity_penalty: 10.0,
+        genetic_solver_parameters: Default::default(),
+    };
+
+    let mut solver = HybridGeneticSolver::new(
+        stops,
+        vehicle_factory(2),
+        &distances,
+        parameters,
+        Box::new(TwoOptSearcher::new(distance_service)),
+        Box::new(ChaCha8Rng::seed_from_u64(0)),
+    );
+
+    // Binary tournaments pick the first individual three times out of four, so without re-draws
+    // only about three pairs out of eight would hold two different parents.
+    let distinct_pairs = (0..100)
+        .map(|_| solver.select_parents(&[0.0, 1.0]))
+        .filter(|(parent1, parent2)| parent1 != parent2)
+        .count();
+
+    assert!(distinct_pairs > 60);
+}
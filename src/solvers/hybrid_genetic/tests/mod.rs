@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod hybrid_genetic_solver_test;
+#[cfg(test)]
+mod split_test;
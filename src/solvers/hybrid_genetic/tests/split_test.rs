@@ -0,0 +1,88 @@
+use std::rc::Rc;
+
+use rstest::rstest;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
+    solvers::hybrid_genetic::split::Split,
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::{ids, stops};
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn test_split_finds_the_cheapest_partition(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let split = Split::new(stops[0], vehicle_factory(2), 100.0, distance_service);
+
+    let giant_tour = split.run(&stops[1..4]);
+
+    assert_eq!(ids(&giant_tour.routes[0]), vec![1]);
+    assert_eq!(ids(&giant_tour.routes[1]), vec![2, 3]);
+    assert_eq!(giant_tour.distance, 10.0);
+    assert!(giant_tour.is_feasible());
+}
+
+#[rstest]
+fn test_split_respects_vehicle_capacities(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let loaded_stops = [stops[0], Stop::new(1, 2), Stop::new(2, 6), Stop::new(3, 6)];
+
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let vehicles = vec![Vehicle::new(0, 10), Vehicle::new(1, 10)];
+    let split = Split::new(stops[0], vehicles, 100.0, distance_service);
+
+    let giant_tour = split.run(&loaded_stops[1..]);
+
+    assert_eq!(ids(&giant_tour.routes[0]), vec![1, 2]);
+    assert_eq!(ids(&giant_tour.routes[1]), vec![3]);
+    assert_eq!(giant_tour.distance, 14.0);
+    assert!(giant_tour.is_feasible());
+}
+
+#[rstest]
+fn test_split_penalizes_unavoidable_overloads(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let loaded_stops = [stops[0], Stop::new(1, 8), Stop::new(2, 8)];
+
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let vehicles = vec![Vehicle::new(0, 10)];
+    let split = Split::new(stops[0], vehicles, 100.0, distance_service);
+
+    let giant_tour = split.run(&loaded_stops[1..]);
+
+    assert!(!giant_tour.is_feasible());
+    assert_eq!(giant_tour.excess_load, 7);
+    assert_eq!(giant_tour.penalized_cost, giant_tour.distance + 700.0);
+    assert!(split.decode(&giant_tour).is_none());
+}
+
+#[rstest]
+fn test_split_decodes_and_encodes_solutions(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let split = Split::new(stops[0], vehicle_factory(2), 100.0, distance_service);
+
+    let giant_tour = split.run(&stops[1..4]);
+    let solution = split.decode(&giant_tour).unwrap();
+
+    assert_eq!(ids(&solution.routes[&1].stops), vec![0, 2, 3, 0]);
+    assert_eq!(solution.total_distance, giant_tour.distance);
+
+    let encoded = split.encode(&solution);
+
+    assert_eq!(ids(&encoded.tour), ids(&giant_tour.tour));
+    assert_eq!(
+        encoded
+            .neighbors
+            .broken_pairs_distance(&giant_tour.neighbors),
+        0.0
+    );
+}
@@ -0,0 +1,270 @@
+use std::{
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Barrier, Mutex,
+    },
+    thread,
+};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceMatrix,
+    solvers::{
+        genetic::{
+            crossover::crossover_operator::CrossoverOperator,
+            genetic_solver::{GeneticSolver, GeneticSolverParameters},
+            population::Population,
+            termination::termination_criteria::StopReason,
+        },
+        solver::Solver,
+        vrp_solution::VrpSolution,
+    },
+};
+
+use super::migration::MigrationParameters;
+
+pub type IslandFactory<'a> = Box<
+    dyn Fn(usize) -> (Box<dyn Solver<VrpSolution> + 'a>, GeneticSolverParameters)
+        + Send
+        + Sync
+        + 'a,
+>;
+
+pub struct IslandGeneticSolverParameters {
+    pub number_of_islands: usize,
+    pub population_size: u32,
+    pub migration: MigrationParameters,
+    pub seed: Option<u64>,
+}
+
+impl Default for IslandGeneticSolverParameters {
+    fn default() -> Self {
+        Self {
+            number_of_islands: 4,
+            population_size: 10,
+            migration: Default::default(),
+            seed: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IslandRecord {
+    pub best_cost: f32,
+    pub generations: u32,
+    pub received_migrants: usize,
+    pub stop_reason: Option<StopReason>,
+}
+
+#[derive(Default)]
+struct Mailbox {
+    emigrants: Population,
+    finished: bool,
+}
+
+pub struct IslandGeneticSolver<'a> {
+    stops: Vec<Stop>,
+    distances: &'a DistanceMatrix,
+    factory: IslandFactory<'a>,
+    crossover_op: &'a (dyn CrossoverOperator<ChaCha8Rng> + Sync),
+    parameters: IslandGeneticSolverParameters,
+    records: Vec<IslandRecord>,
+}
+
+impl<'a> Solver<VrpSolution> for IslandGeneticSolver<'a> {
+    fn solve(&mut self) -> VrpSolution {
+        let number_of_islands = self.parameters.number_of_islands;
+        let seed = self.parameters.seed.unwrap_or_else(rand::random);
+
+        let mailboxes: Mutex<Vec<Mailbox>> =
+            Mutex::new((0..number_of_islands).map(|_| Default::default()).collect());
+        let barrier = Barrier::new(number_of_islands);
+        let aborted = AtomicBool::new(false);
+
+        let results: Vec<(VrpSolution, IslandRecord)> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..number_of_islands)
+                .map(|island| {
+                    let solver = &*self;
+                    let mailboxes = &mailboxes;
+                    let barrier = &barrier;
+                    let aborted = &aborted;
+
+                    scope
+                        .spawn(move || solver.run_island(island, seed, mailboxes, barrier, aborted))
+                })
+                .collect();
+
+            let results: Vec<_> = handles.into_iter().map(|handle| handle.join()).collect();
+
+            results
+                .into_iter()
+                .map(|result| result.unwrap_or_else(|payload| panic::resume_unwind(payload)))
+                .collect()
+        });
+
+        let mut best_solution = VrpSolution::default();
+        self.records = Vec::with_capacity(results.len());
+
+        for (solution, record) in results {
+            if solution.is_better_than(&best_solution) {
+                best_solution = solution;
+            }
+
+            self.records.push(record);
+        }
+
+        best_solution
+    }
+}
+
+impl<'a> IslandGeneticSolver<'a> {
+    pub fn new(
+        stops: Vec<Stop>,
+        distances: &'a DistanceMatrix,
+        factory: IslandFactory<'a>,
+        crossover_op: &'a (dyn CrossoverOperator<ChaCha8Rng> + Sync),
+        parameters: IslandGeneticSolverParameters,
+    ) -> Self {
+        Self {
+            stops,
+            distances,
+            factory,
+            crossover_op,
+            parameters: IslandGeneticSolverParameters {
+                number_of_islands: parameters.number_of_islands.max(1),
+                ..parameters
+            },
+            records: Vec::new(),
+        }
+    }
+
+    pub fn get_records(&self) -> &[IslandRecord] {
+        &self.records
+    }
+
+    /// Runs `evolve_island`, and if it panics while the other islands may still be waiting on
+    /// the barrier, flags the run as aborted and completes the current round with them so that
+    /// they stop instead of blocking forever. The panic is then raised again.
+    fn run_island(
+        &self,
+        island: usize,
+        seed: u64,
+        mailboxes: &Mutex<Vec<Mailbox>>,
+        barrier: &Barrier,
+        aborted: &AtomicBool,
+    ) -> (VrpSolution, IslandRecord) {
+        let synchronized = Cell::new(true);
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            self.evolve_island(island, seed, mailboxes, barrier, aborted, &synchronized)
+        }));
+
+        match result {
+            Ok(result) => result,
+            Err(payload) => {
+                if synchronized.get() {
+                    aborted.store(true, Ordering::SeqCst);
+                    barrier.wait();
+                    barrier.wait();
+                }
+
+                panic::resume_unwind(payload)
+            }
+        }
+    }
+
+    fn evolve_island(
+        &self,
+        island: usize,
+        seed: u64,
+        mailboxes: &Mutex<Vec<Mailbox>>,
+        barrier: &Barrier,
+        aborted: &AtomicBool,
+        synchronized: &Cell<bool>,
+    ) -> (VrpSolution, IslandRecord) {
+        let (mut first_stage_solver, genetic_solver_parameters) = (self.factory)(island);
+
+        let solutions: Vec<VrpSolution> = (0..self.parameters.population_size)
+            .map(|_| first_stage_solver.solve())
+            .collect();
+
+        let mut genetic_solver = GeneticSolver::new(
+            self.stops.clone(),
+            self.distances,
+            Population::from(solutions.as_slice()),
+            genetic_solver_parameters,
+            self.crossover_op,
+            Box::new(ChaCha8Rng::seed_from_u64(seed.wrapping_add(island as u64))),
+        );
+
+        let migration = &self.parameters.migration;
+        let sources = migration
+            .topology
+            .get_sources(island, self.parameters.number_of_islands);
+        let mut received_migrants = 0;
+
+        genetic_solver.initialize();
+
+        loop {
+            for _ in 0..migration.interval.max(1) {
+                if genetic_solver.stop_condition_met() {
+                    break;
+                }
+
+                genetic_solver.evolve_generation();
+            }
+
+            let mailbox = Mailbox {
+                emigrants: genetic_solver.select_emigrants(migration.number_of_migrants),
+                finished: genetic_solver.stop_condition_met(),
+            };
+
+            mailboxes
+                .lock()
+                .expect("the mailboxes should not be poisoned")[island] = mailbox;
+
+            barrier.wait();
+
+            let (immigrants, all_finished) = {
+                let mailboxes = mailboxes
+                    .lock()
+                    .expect("the mailboxes should not be poisoned");
+
+                let immigrants: Vec<Population> = sources
+                    .iter()
+                    .map(|source| mailboxes[*source].emigrants.clone())
+                    .collect();
+
+                (immigrants, mailboxes.iter().all(|mailbox| mailbox.finished))
+            };
+
+            barrier.wait();
+
+            if all_finished || aborted.load(Ordering::SeqCst) {
+                break;
+            }
+
+            received_migrants += genetic_solver.accept_immigrants(immigrants);
+        }
+
+        synchronized.set(false);
+
+        let generations = genetic_solver.get_current_generation();
+        let stop_reason = genetic_solver.get_stop_reason();
+        let solution = genetic_solver.finish();
+
+        let record = IslandRecord {
+            generations,
+            received_migrants,
+            stop_reason,
+            best_cost: solution.total_distance,
+        };
+
+        (solution, record)
+    }
+}
@@ -0,0 +1,38 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MigrationTopology {
+    #[default]
+    Ring,
+    FullyConnected,
+}
+
+impl MigrationTopology {
+    pub(crate) fn get_sources(&self, island: usize, number_of_islands: usize) -> Vec<usize> {
+        if number_of_islands < 2 {
+            return Vec::new();
+        }
+
+        match self {
+            MigrationTopology::Ring => vec![(island + number_of_islands - 1) % number_of_islands],
+            MigrationTopology::FullyConnected => (0..number_of_islands)
+                .filter(|source| *source != island)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MigrationParameters {
+    pub topology: MigrationTopology,
+    pub interval: u32,
+    pub number_of_migrants: usize,
+}
+
+impl Default for MigrationParameters {
+    fn default() -> Self {
+        Self {
+            topology: Default::default(),
+            interval: 5,
+            number_of_migrants: 1,
+        }
+    }
+}
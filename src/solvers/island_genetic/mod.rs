@@ -0,0 +1,3 @@
+pub mod island_genetic_solver;
+pub mod migration;
+mod tests;
@@ -0,0 +1,151 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceMatrix,
+    solvers::{
+        genetic::{
+            crossover::order_crossover::OrderCrossover, genetic_solver::GeneticSolverParameters,
+        },
+        island_genetic::{
+            island_genetic_solver::{
+                IslandFactory, IslandGeneticSolver, IslandGeneticSolverParameters,
+            },
+            migration::{MigrationParameters, MigrationTopology},
+        },
+        random::random_solver::RandomSolver,
+        solver::Solver,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+fn build_factory<'a>(
+    stops: &'a [Stop],
+    distances: &'a DistanceMatrix,
+    vehicle_factory: VehicleFactory,
+) -> IslandFactory<'a> {
+    Box::new(move |island| {
+        let rng = ChaCha8Rng::seed_from_u64(island as u64);
+        let solver =
+            RandomSolver::new(stops.to_vec(), vehicle_factory(2), distances, Box::new(rng));
+
+        let parameters = GeneticSolverParameters {
+            elite_size: 2,
+            max_generations: 12,
+            ..Default::default()
+        };
+
+        (Box::new(solver), parameters)
+    })
+}
+
+#[rstest]
+#[case::ring(MigrationTopology::Ring)]
+#[case::fully_connected(MigrationTopology::FullyConnected)]
+fn test_islands_exchange_migrants(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+    #[case] topology: MigrationTopology,
+) {
+    let crossover_op = OrderCrossover::new(5);
+
+    let mut solver = IslandGeneticSolver::new(
+        stops.clone(),
+        &distances,
+        build_factory(&stops, &distances, vehicle_factory),
+        &crossover_op,
+        IslandGeneticSolverParameters {
+            number_of_islands: 3,
+            migration: MigrationParameters {
+                topology,
+                interval: 4,
+                number_of_migrants: 2,
+            },
+            seed: Some(0),
+            ..Default::default()
+        },
+    );
+
+    let solution = solver.solve();
+    let records = solver.get_records();
+
+    assert_ne!(solution.total_distance, f32::MAX);
+    assert_eq!(records.len(), 3);
+    assert!(records.iter().all(|record| record.generations == 12));
+    assert!(records
+        .iter()
+        .all(|record| record.best_cost >= solution.total_distance));
+}
+
+#[rstest]
+fn test_seeded_islands_are_reproducible(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let crossover_op = OrderCrossover::new(5);
+
+    let solve = || {
+        let mut solver = IslandGeneticSolver::new(
+            stops.clone(),
+            &distances,
+            build_factory(&stops, &distances, vehicle_factory),
+            &crossover_op,
+            IslandGeneticSolverParameters {
+                number_of_islands: 4,
+                migration: MigrationParameters {
+                    topology: MigrationTopology::FullyConnected,
+                    ..Default::default()
+                },
+                seed: Some(42),
+                ..Default::default()
+            },
+        );
+
+        let solution = solver.solve();
+
+        let records: Vec<(f32, usize)> = solver
+            .get_records()
+            .iter()
+            .map(|record| (record.best_cost, record.received_migrants))
+            .collect();
+
+        (solution.total_distance, records)
+    };
+
+    assert_eq!(solve(), solve());
+}
+
+#[rstest]
+#[should_panic(expected = "the island failed")]
+fn test_island_panic_does_not_block_the_others(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let crossover_op = OrderCrossover::new(5);
+    let factory = build_factory(&stops, &distances, vehicle_factory);
+
+    let mut solver = IslandGeneticSolver::new(
+        stops.clone(),
+        &distances,
+        Box::new(move |island| match island {
+            1 => panic!("the island failed"),
+            _ => factory(island),
+        }),
+        &crossover_op,
+        IslandGeneticSolverParameters {
+            number_of_islands: 3,
+            seed: Some(0),
+            ..Default::default()
+        },
+    );
+
+    solver.solve();
+}
@@ -0,0 +1,22 @@
+use rstest::rstest;
+
+use crate::solvers::island_genetic::migration::MigrationTopology;
+
+#[rstest]
+#[case::ring(MigrationTopology::Ring, 0, vec![3])]
+#[case::ring_middle(MigrationTopology::Ring, 2, vec![1])]
+#[case::fully_connected(MigrationTopology::FullyConnected, 1, vec![0, 2, 3])]
+fn test_migration_topology_sources(
+    #[case] topology: MigrationTopology,
+    #[case] island: usize,
+    #[case] expected: Vec<usize>,
+) {
+    assert_eq!(topology.get_sources(island, 4), expected);
+}
+
+#[rstest]
+#[case::ring(MigrationTopology::Ring)]
+#[case::fully_connected(MigrationTopology::FullyConnected)]
+fn test_a_single_island_has_no_sources(#[case] topology: MigrationTopology) {
+    assert!(topology.get_sources(0, 1).is_empty());
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod island_genetic_solver_test;
+#[cfg(test)]
+mod migration_test;
@@ -0,0 +1,31 @@
+use crate::solvers::{solution::Solution, vrp_solution::VrpSolution};
+
+pub trait AcceptanceCriterion<S: Solution> {
+    fn accepts(&self, candidate: &S, current: &S) -> bool;
+}
+
+pub struct BetterOnlyAcceptance;
+
+impl<S: Solution> AcceptanceCriterion<S> for BetterOnlyAcceptance {
+    fn accepts(&self, candidate: &S, current: &S) -> bool {
+        candidate.is_better_than(current)
+    }
+}
+
+pub struct RandomWalkAcceptance;
+
+impl<S: Solution> AcceptanceCriterion<S> for RandomWalkAcceptance {
+    fn accepts(&self, _: &S, _: &S) -> bool {
+        true
+    }
+}
+
+pub struct ThresholdAcceptance {
+    pub threshold: f32,
+}
+
+impl AcceptanceCriterion<VrpSolution> for ThresholdAcceptance {
+    fn accepts(&self, candidate: &VrpSolution, current: &VrpSolution) -> bool {
+        candidate.get_cost() < current.get_cost() + self.threshold
+    }
+}
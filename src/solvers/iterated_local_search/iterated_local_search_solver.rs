@@ -0,0 +1,67 @@
+use crate::{
+    local_search::{local_searcher::LocalSearcher, shaker::Shaker},
+    solvers::{solution::Solution, solver::Solver},
+};
+
+use super::acceptance_criterion::AcceptanceCriterion;
+
+pub struct IteratedLocalSearchParameters {
+    pub max_iterations: u32,
+    pub perturbation_intensity: usize,
+}
+
+pub struct IteratedLocalSearchSolver<S: Solution + Clone> {
+    parameters: IteratedLocalSearchParameters,
+    first_stage_solver: Box<dyn Solver<S>>,
+    perturbation: Box<dyn Shaker<S>>,
+    local_searcher: Box<dyn LocalSearcher<S>>,
+    acceptance_criterion: Box<dyn AcceptanceCriterion<S>>,
+}
+
+impl<S: Solution + Clone> IteratedLocalSearchSolver<S> {
+    pub fn new(
+        parameters: IteratedLocalSearchParameters,
+        first_stage_solver: Box<dyn Solver<S>>,
+        perturbation: Box<dyn Shaker<S>>,
+        local_searcher: Box<dyn LocalSearcher<S>>,
+        acceptance_criterion: Box<dyn AcceptanceCriterion<S>>,
+    ) -> Self {
+        Self {
+            parameters,
+            perturbation,
+            local_searcher,
+            first_stage_solver,
+            acceptance_criterion,
+        }
+    }
+}
+
+impl<S: Solution + Clone> Solver<S> for IteratedLocalSearchSolver<S> {
+    fn solve(&mut self) -> S {
+        let mut current_solution = self.first_stage_solver.solve();
+        self.local_searcher.run(&mut current_solution);
+
+        let mut best_solution = current_solution.clone();
+
+        for _ in 0..self.parameters.max_iterations {
+            let mut candidate = current_solution.clone();
+
+            self.perturbation
+                .shake(&mut candidate, self.parameters.perturbation_intensity);
+            self.local_searcher.run(&mut candidate);
+
+            if candidate.is_better_than(&best_solution) {
+                best_solution = candidate.clone();
+            }
+
+            if self
+                .acceptance_criterion
+                .accepts(&candidate, &current_solution)
+            {
+                current_solution = candidate;
+            }
+        }
+
+        best_solution
+    }
+}
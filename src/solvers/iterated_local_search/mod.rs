@@ -0,0 +1,3 @@
+pub mod acceptance_criterion;
+pub mod iterated_local_search_solver;
+mod tests;
@@ -0,0 +1,118 @@
+use std::rc::Rc;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop,
+    local_search::{
+        double_bridge_shaker::DoubleBridgeShaker, local_searcher::LocalSearcher,
+        segment_swap_shaker::SegmentSwapShaker, two_opt::TwoOptSearcher,
+    },
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
+    solvers::{
+        greedy::vrp_greedy_solver::VrpGreedySolver,
+        iterated_local_search::{
+            acceptance_criterion::{
+                AcceptanceCriterion, BetterOnlyAcceptance, RandomWalkAcceptance,
+                ThresholdAcceptance,
+            },
+            iterated_local_search_solver::{
+                IteratedLocalSearchParameters, IteratedLocalSearchSolver,
+            },
+        },
+        solver::Solver,
+        vrp_solution::VrpSolution,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn test_acceptance_criteria() {
+    let current = VrpSolution::new(&Default::default(), 10.0);
+    let better = VrpSolution::new(&Default::default(), 9.0);
+    let slightly_worse = VrpSolution::new(&Default::default(), 10.5);
+
+    assert!(BetterOnlyAcceptance.accepts(&better, &current));
+    assert!(!BetterOnlyAcceptance.accepts(&slightly_worse, &current));
+
+    assert!(RandomWalkAcceptance.accepts(&slightly_worse, &current));
+
+    assert!(ThresholdAcceptance { threshold: 1.0 }.accepts(&slightly_worse, &current));
+    assert!(!ThresholdAcceptance { threshold: 0.5 }.accepts(&slightly_worse, &current));
+}
+
+#[rstest]
+#[case(Box::new(BetterOnlyAcceptance))]
+#[case(Box::new(RandomWalkAcceptance))]
+#[case(Box::new(ThresholdAcceptance { threshold: 1.0 }))]
+fn test_iterated_local_search_is_not_worse_than_its_local_search(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+    #[case] acceptance_criterion: Box<dyn AcceptanceCriterion<VrpSolution>>,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let mut initial_solution =
+        VrpGreedySolver::new(vehicle_factory(2), &distances, stops.clone()).solve();
+    LocalSearcher::run(
+        &TwoOptSearcher::new(distance_service.clone()),
+        &mut initial_solution,
+    );
+
+    let parameters = IteratedLocalSearchParameters {
+        max_iterations: 10,
+        perturbation_intensity: 2,
+    };
+
+    let mut solver = IteratedLocalSearchSolver::new(
+        parameters,
+        Box::new(VrpGreedySolver::new(vehicle_factory(2), &distances, stops)),
+        Box::new(DoubleBridgeShaker::new(
+            distance_service.clone(),
+            ChaCha8Rng::seed_from_u64(0),
+        )),
+        Box::new(TwoOptSearcher::new(distance_service)),
+        acceptance_criterion,
+    );
+
+    let solution = solver.solve();
+
+    assert!(solution.total_distance <= initial_solution.total_distance);
+}
+
+#[rstest]
+fn test_iterated_local_search_can_perturb_across_routes(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let parameters = IteratedLocalSearchParameters {
+        max_iterations: 10,
+        perturbation_intensity: 1,
+    };
+
+    let mut solver = IteratedLocalSearchSolver::new(
+        parameters,
+        Box::new(VrpGreedySolver::new(vehicle_factory(2), &distances, stops)),
+        Box::new(SegmentSwapShaker::new(
+            distance_service.clone(),
+            2,
+            ChaCha8Rng::seed_from_u64(0),
+        )),
+        Box::new(TwoOptSearcher::new(distance_service)),
+        Box::new(BetterOnlyAcceptance),
+    );
+
+    let solution = solver.solve();
+
+    assert_ne!(solution.routes.len(), 0);
+    assert_ne!(solution.total_distance, f32::MAX);
+}
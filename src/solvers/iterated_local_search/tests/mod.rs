@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod iterated_local_search_test;
@@ -1,10 +1,22 @@
+pub mod ant_colony;
+pub mod bounded;
+pub mod branch_and_bound;
 pub mod genetic;
 pub mod grasp;
 pub mod greedy;
+pub mod hybrid_genetic;
+pub mod island_genetic;
+pub mod iterated_local_search;
+pub mod multi_start;
+pub mod portfolio;
 pub mod random;
+pub mod ruin_and_recreate;
 pub mod sequential;
+pub mod set_partitioning;
 pub mod solution;
+pub mod solution_collector;
 pub mod solver;
+pub mod tabu_search;
 pub mod two_stage_genetic;
 pub mod vrp_sequential;
 pub mod vrp_solution;
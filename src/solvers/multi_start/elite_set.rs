@@ -0,0 +1,56 @@
+use crate::solvers::solution::Solution;
+
+pub struct EliteSet<S: Solution + PartialEq> {
+    capacity: usize,
+    solutions: Vec<S>,
+}
+
+impl<S: Solution + PartialEq> EliteSet<S> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            solutions: Vec::new(),
+        }
+    }
+
+    pub fn insert(&mut self, solution: S) -> bool {
+        if self.solutions.contains(&solution) {
+            return false;
+        }
+
+        let position = self
+            .solutions
+            .iter()
+            .position(|elite| solution.is_better_than(elite))
+            .unwrap_or(self.solutions.len());
+
+        if position >= self.capacity {
+            return false;
+        }
+
+        self.solutions.insert(position, solution);
+        self.solutions.truncate(self.capacity);
+
+        true
+    }
+
+    pub fn get_best(&self) -> Option<&S> {
+        self.solutions.first()
+    }
+
+    pub fn get_solutions(&self) -> &[S] {
+        &self.solutions
+    }
+
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.solutions.clear();
+    }
+}
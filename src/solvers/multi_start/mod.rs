@@ -0,0 +1,3 @@
+pub mod elite_set;
+pub mod multi_start_solver;
+mod tests;
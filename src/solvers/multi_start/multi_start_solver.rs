@@ -0,0 +1,247 @@
+use std::{
+    sync::Mutex,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    local_search::local_searcher::LocalSearcher,
+    solvers::{solution::Solution, solver::Solver},
+};
+
+use super::elite_set::EliteSet;
+
+pub type StartFactory<'a, S> = Box<
+    dyn Fn(usize) -> (Box<dyn Solver<S> + 'a>, Box<dyn LocalSearcher<S> + 'a>) + Send + Sync + 'a,
+>;
+
+pub struct MultiStartParameters<C> {
+    pub max_iterations: Option<u32>,
+    pub time_limit: Option<Duration>,
+    pub max_iterations_without_improvement: Option<u32>,
+    pub target_cost: Option<C>,
+    pub elite_size: usize,
+}
+
+impl<C> Default for MultiStartParameters<C> {
+    fn default() -> Self {
+        Self {
+            max_iterations: Some(10),
+            time_limit: None,
+            max_iterations_without_improvement: None,
+            target_cost: None,
+            elite_size: 1,
+        }
+    }
+}
+
+enum Starts<'a, S: Solution> {
+    Sequential {
+        solver: Box<dyn Solver<S> + 'a>,
+        local_searcher: Box<dyn LocalSearcher<S> + 'a>,
+    },
+    Parallel {
+        factory: StartFactory<'a, S>,
+        number_of_threads: usize,
+    },
+}
+
+struct SearchState<S: Solution + PartialEq> {
+    elite: EliteSet<S>,
+    iterations: u32,
+    iterations_without_improvement: u32,
+}
+
+pub struct MultiStartSolver<'a, S: Solution + PartialEq> {
+    starts: Starts<'a, S>,
+    parameters: MultiStartParameters<S::Cost>,
+    elite: EliteSet<S>,
+    iterations: u32,
+}
+
+impl<'a, S> MultiStartSolver<'a, S>
+where
+    S: Solution + Clone + Default + PartialEq + Send,
+    S::Cost: Sync,
+{
+    pub fn new(
+        solver: Box<dyn Solver<S> + 'a>,
+        local_searcher: Box<dyn LocalSearcher<S> + 'a>,
+        parameters: MultiStartParameters<S::Cost>,
+    ) -> Self {
+        Self {
+            starts: Starts::Sequential {
+                solver,
+                local_searcher,
+            },
+            elite: EliteSet::new(parameters.elite_size),
+            iterations: 0,
+            parameters,
+        }
+    }
+
+    pub fn with_parallel_starts(
+        factory: StartFactory<'a, S>,
+        number_of_threads: usize,
+        parameters: MultiStartParameters<S::Cost>,
+    ) -> Self {
+        Self {
+            starts: Starts::Parallel {
+                factory,
+                number_of_threads: number_of_threads.max(1),
+            },
+            elite: EliteSet::new(parameters.elite_size),
+            iterations: 0,
+            parameters,
+        }
+    }
+
+    pub fn get_elite(&self) -> &[S] {
+        self.elite.get_solutions()
+    }
+
+    pub fn get_iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    fn should_stop(
+        parameters: &MultiStartParameters<S::Cost>,
+        state: &SearchState<S>,
+        start: Instant,
+    ) -> bool {
+        if state.iterations == 0 {
+            return false;
+        }
+
+        let has_stop_criteria = parameters.max_iterations.is_some()
+            || parameters.time_limit.is_some()
+            || parameters.max_iterations_without_improvement.is_some()
+            || parameters.target_cost.is_some();
+
+        if !has_stop_criteria {
+            return true;
+        }
+
+        let reached_iterations = parameters
+            .max_iterations
+            .is_some_and(|max_iterations| state.iterations >= max_iterations);
+
+        let reached_time_limit = parameters
+            .time_limit
+            .is_some_and(|time_limit| start.elapsed() >= time_limit);
+
+        let reached_stagnation = parameters
+            .max_iterations_without_improvement
+            .is_some_and(|max_stagnation| state.iterations_without_improvement >= max_stagnation);
+
+        let reached_target = match (&parameters.target_cost, state.elite.get_best()) {
+            (Some(target_cost), Some(best)) => best.get_cost() <= *target_cost,
+            _ => false,
+        };
+
+        reached_iterations || reached_time_limit || reached_stagnation || reached_target
+    }
+
+    fn record(state: &mut SearchState<S>, solution: S) {
+        let is_improvement = match state.elite.get_best() {
+            Some(best) => solution.is_better_than(best),
+            None => true,
+        };
+
+        state.elite.insert(solution);
+        state.iterations += 1;
+
+        match is_improvement {
+            true => state.iterations_without_improvement = 0,
+            false => state.iterations_without_improvement += 1,
+        }
+    }
+
+    fn run_start(solver: &mut dyn Solver<S>, local_searcher: &dyn LocalSearcher<S>) -> S {
+        let mut solution = solver.solve();
+        local_searcher.run(&mut solution);
+
+        solution
+    }
+}
+
+impl<'a, S> Solver<S> for MultiStartSolver<'a, S>
+where
+    S: Solution + Clone + Default + PartialEq + Send,
+    S::Cost: Sync,
+{
+    fn solve(&mut self) -> S {
+        let start = Instant::now();
+
+        let mut state = SearchState {
+            elite: EliteSet::new(self.parameters.elite_size),
+            iterations: 0,
+            iterations_without_improvement: 0,
+        };
+
+        let parameters = &self.parameters;
+
+        match &mut self.starts {
+            Starts::Sequential {
+                solver,
+                local_searcher,
+            } => {
+                while !Self::should_stop(parameters, &state, start) {
+                    let solution = Self::run_start(solver.as_mut(), local_searcher.as_ref());
+                    Self::record(&mut state, solution);
+                }
+            }
+            Starts::Parallel {
+                factory,
+                number_of_threads,
+            } => {
+                let shared_state = Mutex::new(state);
+                let factory = &*factory;
+
+                thread::scope(|scope| {
+                    for thread_index in 0..*number_of_threads {
+                        let shared_state = &shared_state;
+
+                        scope.spawn(move || {
+                            let (mut solver, local_searcher) = factory(thread_index);
+
+                            loop {
+                                let should_stop = Self::should_stop(
+                                    parameters,
+                                    &shared_state
+                                        .lock()
+                                        .expect("the search state should not be poisoned"),
+                                    start,
+                                );
+
+                                if should_stop {
+                                    break;
+                                }
+
+                                let solution =
+                                    Self::run_start(solver.as_mut(), local_searcher.as_ref());
+
+                                let mut state = shared_state
+                                    .lock()
+                                    .expect("the search state should not be poisoned");
+
+                                if !Self::should_stop(parameters, &state, start) {
+                                    Self::record(&mut state, solution);
+                                }
+                            }
+                        });
+                    }
+                });
+
+                state = shared_state
+                    .into_inner()
+                    .expect("the search state should not be poisoned");
+            }
+        }
+
+        self.iterations = state.iterations;
+        self.elite = state.elite;
+
+        self.elite.get_best().cloned().unwrap_or_default()
+    }
+}
@@ -0,0 +1,60 @@
+use rstest::rstest;
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::{
+        distance::distance_service::{DistanceMatrix, DistanceService},
+        route::route_service::RouteMap,
+    },
+    solvers::{multi_start::elite_set::EliteSet, vrp_solution::VrpSolution},
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+
+fn build_solution(sequence: &[Stop], distance_service: &DistanceService) -> VrpSolution {
+    let mut route = Route::new(Vehicle::new(0, 10));
+    route
+        .replace_stops(sequence.to_vec(), distance_service)
+        .unwrap();
+
+    let total_distance = route.total_distance();
+    let routes: RouteMap = RouteMap::from([(0, route)]);
+
+    VrpSolution::new(&routes, total_distance)
+}
+
+#[rstest]
+fn test_elite_set_keeps_the_best_distinct_solutions(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let distance_service = DistanceService::new(stops.clone(), &distances);
+
+    let best = build_solution(
+        &[stops[0], stops[1], stops[3], stops[2], stops[0]],
+        &distance_service,
+    );
+    let middle = build_solution(
+        &[stops[0], stops[1], stops[2], stops[3], stops[0]],
+        &distance_service,
+    );
+    let worst = build_solution(
+        &[stops[0], stops[3], stops[1], stops[2], stops[0]],
+        &distance_service,
+    );
+
+    let mut elite_set = EliteSet::new(2);
+
+    assert!(elite_set.insert(middle.clone()));
+    assert!(!elite_set.insert(middle.clone()));
+    assert!(elite_set.insert(best.clone()));
+    assert!(!elite_set.insert(worst));
+
+    let costs: Vec<f32> = elite_set
+        .get_solutions()
+        .iter()
+        .map(|solution| solution.total_distance)
+        .collect();
+
+    assert_eq!(elite_set.len(), 2);
+    assert_eq!(costs, vec![best.total_distance, middle.total_distance]);
+    assert!(elite_set.get_best().unwrap() == &best);
+}
@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod elite_set_test;
+#[cfg(test)]
+mod multi_start_solver_test;
@@ -0,0 +1,166 @@
+use std::{rc::Rc, time::Duration};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    local_search::two_opt::TwoOptSearcher,
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
+    solvers::{
+        greedy::vrp_greedy_solver::VrpGreedySolver,
+        multi_start::multi_start_solver::{MultiStartParameters, MultiStartSolver},
+        random::random_solver::RandomSolver,
+        solver::Solver,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn test_multi_start_stops_after_max_iterations(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let mut solver = MultiStartSolver::new(
+        Box::new(VrpGreedySolver::new(vehicle_factory(2), &distances, stops)),
+        Box::new(TwoOptSearcher::new(distance_service)),
+        MultiStartParameters {
+            max_iterations: Some(3),
+            elite_size: 3,
+            ..Default::default()
+        },
+    );
+
+    let solution = solver.solve();
+
+    assert_eq!(solution.total_distance, 10.0);
+    assert_eq!(solver.get_iterations(), 3);
+    assert_eq!(solver.get_elite().len(), 1);
+}
+
+#[rstest]
+fn test_multi_start_stops_on_stagnation(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let mut solver = MultiStartSolver::new(
+        Box::new(VrpGreedySolver::new(vehicle_factory(2), &distances, stops)),
+        Box::new(TwoOptSearcher::new(distance_service)),
+        MultiStartParameters {
+            max_iterations: None,
+            max_iterations_without_improvement: Some(2),
+            ..Default::default()
+        },
+    );
+
+    solver.solve();
+
+    assert_eq!(solver.get_iterations(), 3);
+}
+
+#[rstest]
+fn test_multi_start_stops_at_the_target_cost(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let mut solver = MultiStartSolver::new(
+        Box::new(VrpGreedySolver::new(vehicle_factory(2), &distances, stops)),
+        Box::new(TwoOptSearcher::new(distance_service)),
+        MultiStartParameters {
+            max_iterations: None,
+            time_limit: Some(Duration::from_secs(10)),
+            target_cost: Some(10.0),
+            ..Default::default()
+        },
+    );
+
+    solver.solve();
+
+    assert_eq!(solver.get_iterations(), 1);
+}
+
+#[rstest]
+fn test_multi_start_keeps_an_elite_of_distinct_solutions(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let mut solver = MultiStartSolver::new(
+        Box::new(RandomSolver::new(
+            stops,
+            vehicle_factory(2),
+            &distances,
+            Box::new(ChaCha8Rng::seed_from_u64(0)),
+        )),
+        Box::new(TwoOptSearcher::new(distance_service)),
+        MultiStartParameters {
+            max_iterations: Some(30),
+            elite_size: 3,
+            ..Default::default()
+        },
+    );
+
+    let solution = solver.solve();
+    let elite = solver.get_elite();
+
+    assert!(elite.len() > 1);
+    assert!(elite.len() <= 3);
+    assert_eq!(solution.total_distance, elite[0].total_distance);
+
+    for (index, elite_solution) in elite.iter().enumerate() {
+        assert!(elite[..index].iter().all(|other| other != elite_solution
+            && other.total_distance <= elite_solution.total_distance));
+    }
+}
+
+#[rstest]
+fn test_multi_start_can_run_parallel_starts(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let factory_distances = distances.clone();
+    let factory_stops = stops.clone();
+
+    let mut solver = MultiStartSolver::with_parallel_starts(
+        Box::new(move |thread_index| {
+            let distance_service = Rc::new(DistanceService::new(
+                factory_stops.clone(),
+                &factory_distances,
+            ));
+
+            (
+                Box::new(RandomSolver::new(
+                    factory_stops.clone(),
+                    vec![Vehicle::new(0, 10), Vehicle::new(1, 10)],
+                    &factory_distances,
+                    Box::new(ChaCha8Rng::seed_from_u64(thread_index as u64)),
+                )),
+                Box::new(TwoOptSearcher::new(distance_service)),
+            )
+        }),
+        4,
+        MultiStartParameters {
+            max_iterations: Some(20),
+            elite_size: 2,
+            ..Default::default()
+        },
+    );
+
+    let solution = solver.solve();
+
+    assert_eq!(solver.get_iterations(), 20);
+    assert_ne!(solution.total_distance, f32::MAX);
+    assert!(!solver.get_elite().is_empty());
+}
@@ -0,0 +1,2 @@
+pub mod portfolio_solver;
+mod tests;
@@ -0,0 +1,222 @@
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::solvers::{solver::Solver, vrp_solution::VrpSolution};
+
+pub type SolverFactory<'a> = Box<dyn Fn() -> Box<dyn Solver<VrpSolution> + 'a> + Send + Sync + 'a>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PortfolioExecution {
+    Sequential,
+    /// Runs every strategy on its own thread, so all of them have to be solver factories.
+    Parallel,
+}
+
+pub struct PortfolioSolverParameters {
+    pub time_budget: Duration,
+    pub execution: PortfolioExecution,
+    pub max_runs_per_strategy: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct StrategyRecord {
+    pub name: String,
+    pub runs: u32,
+    pub best_cost: f32,
+    pub elapsed: Duration,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PortfolioReport {
+    pub winner: Option<String>,
+    pub records: Vec<StrategyRecord>,
+}
+
+enum Strategy<'a> {
+    Instance(Box<dyn Solver<VrpSolution> + 'a>),
+    Factory(SolverFactory<'a>),
+}
+
+struct PortfolioStrategy<'a> {
+    name: String,
+    strategy: Strategy<'a>,
+}
+
+pub struct PortfolioSolver<'a> {
+    strategies: Vec<PortfolioStrategy<'a>>,
+    parameters: PortfolioSolverParameters,
+    report: PortfolioReport,
+}
+
+impl<'a> Solver<VrpSolution> for PortfolioSolver<'a> {
+    fn solve(&mut self) -> VrpSolution {
+        let deadline = Instant::now() + self.parameters.time_budget;
+
+        let results = match self.parameters.execution {
+            PortfolioExecution::Sequential => self.solve_sequentially(deadline),
+            PortfolioExecution::Parallel => self.solve_in_parallel(deadline),
+        };
+
+        let mut best_solution = VrpSolution::default();
+        let mut winner = None;
+        let mut records = Vec::with_capacity(results.len());
+
+        for (solution, record) in results {
+            if solution.is_better_than(&best_solution) {
+                best_solution = solution;
+                winner = Some(record.name.clone());
+            }
+
+            records.push(record);
+        }
+
+        self.report = PortfolioReport { winner, records };
+
+        best_solution.with_portfolio_report(self.report.clone())
+    }
+}
+
+impl<'a> PortfolioSolver<'a> {
+    pub fn new(parameters: PortfolioSolverParameters) -> Self {
+        Self {
+            parameters,
+            strategies: Vec::new(),
+            report: Default::default(),
+        }
+    }
+
+    /// Adds a solver that is run on the calling thread. Parallel portfolios only take strategies
+    /// added with `with_solver_factory`, since each of them builds its solver on its own thread.
+    pub fn with_solver(mut self, name: &str, solver: Box<dyn Solver<VrpSolution> + 'a>) -> Self {
+        if self.parameters.execution == PortfolioExecution::Parallel {
+            panic!("the solver {name} should be added with a factory to run in parallel");
+        }
+
+        self.strategies.push(PortfolioStrategy {
+            name: name.to_string(),
+            strategy: Strategy::Instance(solver),
+        });
+        self
+    }
+
+    pub fn with_solver_factory(mut self, name: &str, factory: SolverFactory<'a>) -> Self {
+        self.strategies.push(PortfolioStrategy {
+            name: name.to_string(),
+            strategy: Strategy::Factory(factory),
+        });
+        self
+    }
+
+    pub fn get_report(&self) -> &PortfolioReport {
+        &self.report
+    }
+
+    pub fn get_winner(&self) -> Option<&str> {
+        self.report.winner.as_deref()
+    }
+
+    fn run_strategy(
+        name: &str,
+        solver: &mut dyn Solver<VrpSolution>,
+        deadline: Instant,
+        max_runs: u32,
+    ) -> (VrpSolution, StrategyRecord) {
+        let start = Instant::now();
+        let mut best_solution = VrpSolution::default();
+        let mut runs = 0;
+
+        loop {
+            let solution = solver.solve();
+            runs += 1;
+
+            if solution.is_better_than(&best_solution) {
+                best_solution = solution;
+            }
+
+            if runs >= max_runs || Instant::now() >= deadline {
+                break;
+            }
+        }
+
+        let record = StrategyRecord {
+            runs,
+            name: name.to_string(),
+            best_cost: best_solution.total_distance,
+            elapsed: start.elapsed(),
+        };
+
+        (best_solution, record)
+    }
+
+    fn split_deadline(deadline: Instant, remaining_strategies: usize) -> Instant {
+        let remaining_time = deadline.saturating_duration_since(Instant::now());
+
+        Instant::now() + remaining_time / remaining_strategies.max(1) as u32
+    }
+
+    fn solve_sequentially(&mut self, deadline: Instant) -> Vec<(VrpSolution, StrategyRecord)> {
+        let max_runs = self.parameters.max_runs_per_strategy.max(1);
+        let number_of_strategies = self.strategies.len();
+
+        self.strategies
+            .iter_mut()
+            .enumerate()
+            .map(|(index, portfolio_strategy)| {
+                let strategy_deadline =
+                    Self::split_deadline(deadline, number_of_strategies - index);
+
+                match &mut portfolio_strategy.strategy {
+                    Strategy::Instance(solver) => Self::run_strategy(
+                        &portfolio_strategy.name,
+                        solver.as_mut(),
+                        strategy_deadline,
+                        max_runs,
+                    ),
+                    Strategy::Factory(factory) => Self::run_strategy(
+                        &portfolio_strategy.name,
+                        factory().as_mut(),
+                        strategy_deadline,
+                        max_runs,
+                    ),
+                }
+            })
+            .collect()
+    }
+
+    fn solve_in_parallel(&mut self, deadline: Instant) -> Vec<(VrpSolution, StrategyRecord)> {
+        let max_runs = self.parameters.max_runs_per_strategy.max(1);
+
+        thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .strategies
+                .iter()
+                .map(|portfolio_strategy| {
+                    let name = portfolio_strategy.name.as_str();
+
+                    let factory = match &portfolio_strategy.strategy {
+                        Strategy::Factory(factory) => factory,
+                        Strategy::Instance(_) => {
+                            unreachable!("the strategy {name} should be a factory")
+                        }
+                    };
+
+                    scope.spawn(move || {
+                        Self::run_strategy(name, factory().as_mut(), deadline, max_runs)
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .zip(self.strategies.iter())
+                .map(|(handle, portfolio_strategy)| {
+                    handle.join().unwrap_or_else(|_| {
+                        panic!("the strategy {} should not panic", portfolio_strategy.name)
+                    })
+                })
+                .collect()
+        })
+    }
+}
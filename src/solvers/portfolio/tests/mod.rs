@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod portfolio_solver_test;
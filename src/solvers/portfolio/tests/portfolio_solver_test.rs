@@ -0,0 +1,160 @@
+use std::time::{Duration, Instant};
+
+use rstest::rstest;
+
+use crate::{
+    domain::{stop::Stop, vehicle::Vehicle},
+    services::distance::distance_service::DistanceMatrix,
+    solvers::{
+        branch_and_bound::branch_and_bound_solver::{
+            BranchAndBoundParameters, BranchAndBoundSolver,
+        },
+        greedy::vrp_greedy_solver::VrpGreedySolver,
+        portfolio::portfolio_solver::{
+            PortfolioExecution, PortfolioSolver, PortfolioSolverParameters,
+        },
+        solver::Solver,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+
+fn vehicles() -> Vec<Vehicle> {
+    vec![Vehicle::new(0, 10), Vehicle::new(1, 10)]
+}
+
+fn branch_and_bound_parameters() -> BranchAndBoundParameters {
+    BranchAndBoundParameters {
+        node_limit: 10_000,
+        time_limit: Duration::from_secs(10),
+    }
+}
+
+#[rstest]
+fn test_sequential_portfolio_reports_the_winning_strategy(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+) {
+    let mut solver = PortfolioSolver::new(PortfolioSolverParameters {
+        time_budget: Duration::from_secs(5),
+        execution: PortfolioExecution::Sequential,
+        max_runs_per_strategy: 1,
+    })
+    .with_solver(
+        "greedy",
+        Box::new(VrpGreedySolver::new(vehicles(), &distances, stops.clone())),
+    )
+    .with_solver(
+        "branch_and_bound",
+        Box::new(BranchAndBoundSolver::new(
+            stops,
+            vehicles(),
+            &distances,
+            branch_and_bound_parameters(),
+        )),
+    );
+
+    let solution = solver.solve();
+    let report = solution
+        .portfolio_report
+        .as_ref()
+        .expect("the portfolio should report its strategies");
+
+    assert_eq!(solution.total_distance, 8.0);
+    assert_eq!(report.winner.as_deref(), Some("branch_and_bound"));
+    assert_eq!(solver.get_winner(), Some("branch_and_bound"));
+    assert_eq!(report.records.len(), 2);
+    assert_eq!(report.records[0].name, "greedy");
+    assert_eq!(report.records[0].best_cost, 10.0);
+    assert_eq!(report.records[0].runs, 1);
+    assert_eq!(report.records[1].best_cost, 8.0);
+}
+
+#[rstest]
+fn test_parallel_portfolio_runs_factories_on_threads(distances: DistanceMatrix, stops: Vec<Stop>) {
+    let greedy_stops = stops.clone();
+    let greedy_distances = distances.clone();
+    let branch_and_bound_stops = stops.clone();
+    let branch_and_bound_distances = distances.clone();
+
+    let mut solver = PortfolioSolver::new(PortfolioSolverParameters {
+        time_budget: Duration::from_secs(5),
+        execution: PortfolioExecution::Parallel,
+        max_runs_per_strategy: 1,
+    })
+    .with_solver_factory(
+        "greedy",
+        Box::new(move || {
+            Box::new(VrpGreedySolver::new(
+                vehicles(),
+                &greedy_distances,
+                greedy_stops.clone(),
+            ))
+        }),
+    )
+    .with_solver_factory(
+        "branch_and_bound",
+        Box::new(move || {
+            Box::new(BranchAndBoundSolver::new(
+                branch_and_bound_stops.clone(),
+                vehicles(),
+                &branch_and_bound_distances,
+                branch_and_bound_parameters(),
+            ))
+        }),
+    );
+
+    let solution = solver.solve();
+    let names: Vec<&str> = solution
+        .portfolio_report
+        .as_ref()
+        .expect("the portfolio should report its strategies")
+        .records
+        .iter()
+        .map(|record| record.name.as_str())
+        .collect();
+
+    assert_eq!(solution.total_distance, 8.0);
+    assert_eq!(solver.get_winner(), Some("branch_and_bound"));
+    assert_eq!(names, vec!["greedy", "branch_and_bound"]);
+}
+
+#[rstest]
+#[should_panic(expected = "the solver greedy should be added with a factory to run in parallel")]
+fn test_parallel_portfolio_rejects_solver_instances(distances: DistanceMatrix, stops: Vec<Stop>) {
+    PortfolioSolver::new(PortfolioSolverParameters {
+        time_budget: Duration::from_secs(5),
+        execution: PortfolioExecution::Parallel,
+        max_runs_per_strategy: 1,
+    })
+    .with_solver(
+        "greedy",
+        Box::new(VrpGreedySolver::new(vehicles(), &distances, stops)),
+    );
+}
+
+#[rstest]
+fn test_portfolio_restarts_strategies_within_the_time_budget(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+) {
+    let time_budget = Duration::from_millis(50);
+
+    let mut solver = PortfolioSolver::new(PortfolioSolverParameters {
+        time_budget,
+        execution: PortfolioExecution::Sequential,
+        max_runs_per_strategy: u32::MAX,
+    })
+    .with_solver(
+        "greedy",
+        Box::new(VrpGreedySolver::new(vehicles(), &distances, stops)),
+    );
+
+    let start = Instant::now();
+    let solution = solver.solve();
+
+    assert_eq!(solution.total_distance, 10.0);
+    assert!(solver.get_report().records[0].runs > 1);
+    assert!(start.elapsed() < time_budget + Duration::from_secs(1));
+}
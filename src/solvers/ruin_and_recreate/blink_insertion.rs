@@ -0,0 +1,114 @@
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::distance::distance_service::DistanceService,
+};
+
+#[derive(Clone, Copy)]
+enum InsertionOrder {
+    Random,
+    Demand,
+    FarFromDepot,
+    CloseToDepot,
+}
+
+const INSERTION_ORDER_WEIGHTS: [(InsertionOrder, u32); 4] = [
+    (InsertionOrder::Random, 4),
+    (InsertionOrder::Demand, 4),
+    (InsertionOrder::FarFromDepot, 2),
+    (InsertionOrder::CloseToDepot, 1),
+];
+
+pub(super) struct BlinkInsertion {
+    blink_rate: f32,
+}
+
+impl BlinkInsertion {
+    pub(super) fn new(blink_rate: f32) -> Self {
+        Self { blink_rate }
+    }
+
+    fn sort_customers<R: Rng + ?Sized>(
+        customers: &mut [Stop],
+        depot: &Stop,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) {
+        let (order, _) = INSERTION_ORDER_WEIGHTS
+            .choose_weighted(rng, |(_, weight)| *weight)
+            .expect("the insertion order weights should be valid");
+
+        match order {
+            InsertionOrder::Random => customers.shuffle(rng),
+            InsertionOrder::Demand => {
+                customers.sort_by_key(|customer| std::cmp::Reverse(customer.usage))
+            }
+            InsertionOrder::FarFromDepot => customers.sort_by(|customer1, customer2| {
+                distance_service
+                    .get_distance(depot, customer2)
+                    .total_cmp(&distance_service.get_distance(depot, customer1))
+            }),
+            InsertionOrder::CloseToDepot => customers.sort_by(|customer1, customer2| {
+                distance_service
+                    .get_distance(depot, customer1)
+                    .total_cmp(&distance_service.get_distance(depot, customer2))
+            }),
+        }
+    }
+
+    pub(super) fn recreate<R: Rng + ?Sized>(
+        &self,
+        routes: &mut [Route],
+        mut customers: Vec<Stop>,
+        depot: &Stop,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> bool {
+        Self::sort_customers(&mut customers, depot, rng, distance_service);
+
+        for customer in customers {
+            let mut best_insertion: Option<(usize, usize, f32)> = None;
+
+            for (route_index, route) in routes.iter().enumerate() {
+                if !route.can_add_stop(&customer) {
+                    continue;
+                }
+
+                for position in 1..route.stops.len() {
+                    if rng.gen_bool(self.blink_rate.into()) {
+                        continue;
+                    }
+
+                    let previous = &route.stops[position - 1];
+                    let next = &route.stops[position];
+
+                    let cost = distance_service.get_distance(previous, &customer)
+                        + distance_service.get_distance(&customer, next)
+                        - distance_service.get_distance(previous, next);
+
+                    match best_insertion {
+                        Some((_, _, best_cost)) if best_cost <= cost => (),
+                        _ => best_insertion = Some((route_index, position, cost)),
+                    }
+                }
+            }
+
+            let (route_index, position, cost) = match best_insertion {
+                Some(best_insertion) => best_insertion,
+                None => return false,
+            };
+
+            routes[route_index]
+                .add_stop_at(customer, position, cost)
+                .unwrap_or_else(|_| {
+                    panic!(
+                        "the vehicle {} should support the load of stop {}",
+                        routes[route_index].vehicle.id, customer.id
+                    )
+                });
+        }
+
+        true
+    }
+}
@@ -0,0 +1,4 @@
+mod blink_insertion;
+pub mod ruin_and_recreate_solver;
+mod string_removal;
+mod tests;
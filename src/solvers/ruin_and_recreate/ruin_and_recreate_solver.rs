@@ -0,0 +1,199 @@
+use std::rc::Rc;
+
+use rand::Rng;
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::{
+        distance::distance_service::{DistanceMatrix, DistanceService},
+        route::route_service::RouteMap,
+    },
+    solvers::{solution::Solution, solver::Solver, vrp_solution::VrpSolution},
+};
+
+use super::{blink_insertion::BlinkInsertion, string_removal::StringRemoval};
+
+pub struct RuinAndRecreateParameters {
+    pub max_iterations: u32,
+    pub average_removed_customers: f32,
+    pub max_string_length: usize,
+    pub split_rate: f32,
+    pub split_depth: f32,
+    pub blink_rate: f32,
+    pub initial_temperature: f32,
+    pub final_temperature: f32,
+}
+
+impl Default for RuinAndRecreateParameters {
+    fn default() -> Self {
+        Self {
+            max_iterations: 10_000,
+            average_removed_customers: 10.0,
+            max_string_length: 10,
+            split_rate: 0.5,
+            split_depth: 0.01,
+            blink_rate: 0.01,
+            initial_temperature: 100.0,
+            final_temperature: 1.0,
+        }
+    }
+}
+
+pub struct RuinAndRecreateSolver<R: Rng> {
+    depot: Stop,
+    initial_solution: VrpSolution,
+    string_removal: StringRemoval,
+    blink_insertion: BlinkInsertion,
+    parameters: RuinAndRecreateParameters,
+    distance_service: Rc<DistanceService>,
+    rng: R,
+}
+
+impl<R: Rng> RuinAndRecreateSolver<R> {
+    pub fn new(
+        stops: Vec<Stop>,
+        distances: &DistanceMatrix,
+        initial_solution: VrpSolution,
+        parameters: RuinAndRecreateParameters,
+        rng: R,
+    ) -> Self {
+        let depot = stops[0];
+        let distance_service = Rc::new(DistanceService::new(stops, distances));
+
+        let customers: Vec<Stop> = initial_solution
+            .routes
+            .values()
+            .flat_map(|route| route.stops.iter())
+            .filter(|stop| stop.id != depot.id)
+            .copied()
+            .collect();
+
+        Self {
+            rng,
+            depot,
+            initial_solution,
+            string_removal: StringRemoval::new(
+                &customers,
+                &distance_service,
+                parameters.average_removed_customers,
+                parameters.max_string_length,
+                parameters.split_rate,
+                parameters.split_depth,
+            ),
+            blink_insertion: BlinkInsertion::new(parameters.blink_rate),
+            distance_service,
+            parameters,
+        }
+    }
+
+    fn get_temperature(&self, iteration: u32) -> f32 {
+        let progress = iteration as f32 / self.parameters.max_iterations.max(1) as f32;
+
+        self.parameters.initial_temperature
+            * (self.parameters.final_temperature / self.parameters.initial_temperature)
+                .powf(progress)
+    }
+
+    fn normalize_routes(&self, solution: &VrpSolution) -> Vec<Route> {
+        solution
+            .routes
+            .values()
+            .map(|route| match route.stops.len() < 2 {
+                true => {
+                    let mut empty_route = route.clone();
+
+                    empty_route
+                        .replace_stops(vec![self.depot, self.depot], &self.distance_service)
+                        .unwrap_or_else(|_| {
+                            panic!(
+                                "the vehicle {} should support the load of the depot",
+                                route.vehicle.id
+                            )
+                        });
+
+                    empty_route
+                }
+                false => route.clone(),
+            })
+            .collect()
+    }
+
+    fn build_solution(&self, routes: &[Route]) -> VrpSolution {
+        let route_map: RouteMap = routes
+            .iter()
+            .map(|route| {
+                let mut route = route.clone();
+
+                route
+                    .replace_stops(route.stops.clone(), &self.distance_service)
+                    .unwrap_or_else(|_| {
+                        panic!(
+                            "the vehicle {} should support its own load",
+                            route.vehicle.id
+                        )
+                    });
+
+                (route.vehicle.id, route)
+            })
+            .collect();
+
+        let total_distance = route_map.values().map(|route| route.total_distance()).sum();
+
+        VrpSolution::new(&route_map, total_distance)
+    }
+}
+
+impl<R: Rng> Solver<VrpSolution> for RuinAndRecreateSolver<R> {
+    fn solve(&mut self) -> VrpSolution {
+        let mut current_routes = self.normalize_routes(&self.initial_solution);
+        let mut current_cost: f32 = current_routes
+            .iter()
+            .map(|route| route.total_distance())
+            .sum();
+
+        let mut best_solution = self.build_solution(&current_routes);
+
+        for iteration in 0..self.parameters.max_iterations {
+            let mut candidate_routes = current_routes.clone();
+
+            let removed = self.string_removal.ruin(
+                &mut candidate_routes,
+                &mut self.rng,
+                &self.distance_service,
+            );
+
+            let is_complete = self.blink_insertion.recreate(
+                &mut candidate_routes,
+                removed,
+                &self.depot,
+                &mut self.rng,
+                &self.distance_service,
+            );
+
+            if !is_complete {
+                continue;
+            }
+
+            let candidate_cost: f32 = candidate_routes
+                .iter()
+                .map(|route| route.total_distance())
+                .sum();
+
+            let temperature = self.get_temperature(iteration);
+            let threshold = current_cost - temperature * self.rng.gen::<f32>().ln();
+
+            if candidate_cost >= threshold {
+                continue;
+            }
+
+            current_routes = candidate_routes;
+            current_cost = candidate_cost;
+
+            if current_cost < best_solution.get_cost() {
+                best_solution = self.build_solution(&current_routes);
+            }
+        }
+
+        best_solution
+    }
+}
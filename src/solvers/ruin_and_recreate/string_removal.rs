@@ -0,0 +1,210 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{
+    domain::{route::Route, stop::Stop},
+    services::distance::distance_service::DistanceService,
+};
+
+type NeighborLists = HashMap<u32, Vec<Stop>>;
+
+pub(super) struct StringRemoval {
+    average_removed_customers: f32,
+    max_string_length: usize,
+    split_rate: f32,
+    split_depth: f32,
+    neighbor_lists: NeighborLists,
+}
+
+impl StringRemoval {
+    pub(super) fn new(
+        customers: &[Stop],
+        distance_service: &DistanceService,
+        average_removed_customers: f32,
+        max_string_length: usize,
+        split_rate: f32,
+        split_depth: f32,
+    ) -> Self {
+        let neighbor_lists = customers
+            .iter()
+            .map(|customer| {
+                let mut neighbors = customers.to_vec();
+
+                neighbors.sort_by(|neighbor1, neighbor2| {
+                    distance_service
+                        .get_distance(customer, neighbor1)
+                        .total_cmp(&distance_service.get_distance(customer, neighbor2))
+                });
+
+                (customer.id, neighbors)
+            })
+            .collect();
+
+        Self {
+            split_rate,
+            split_depth,
+            neighbor_lists,
+            max_string_length,
+            average_removed_customers,
+        }
+    }
+
+    pub(super) fn ruin<R: Rng + ?Sized>(
+        &self,
+        routes: &mut [Route],
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> Vec<Stop> {
+        let route_lengths: Vec<usize> = routes
+            .iter()
+            .map(|route| route.stops.len().saturating_sub(2))
+            .filter(|length| *length > 0)
+            .collect();
+
+        if route_lengths.is_empty() {
+            return Vec::new();
+        }
+
+        let average_route_length = route_lengths.iter().sum::<usize>() / route_lengths.len();
+        let max_string_length = self.max_string_length.min(average_route_length).max(1);
+        let max_strings = (4.0 * self.average_removed_customers / (1.0 + max_string_length as f32)
+            - 1.0)
+            .max(1.0);
+        let number_of_strings = rng.gen_range(1.0..=max_strings).floor() as usize;
+
+        let route_by_customer: HashMap<u32, usize> = routes
+            .iter()
+            .enumerate()
+            .flat_map(|(route_index, route)| {
+                route
+                    .stops
+                    .iter()
+                    .skip(1)
+                    .take(route.stops.len().saturating_sub(2))
+                    .map(move |stop| (stop.id, route_index))
+            })
+            .collect();
+
+        let seed = match route_by_customer
+            .keys()
+            .copied()
+            .collect::<Vec<u32>>()
+            .choose(rng)
+        {
+            Some(seed) => *seed,
+            None => return Vec::new(),
+        };
+
+        let mut removed: Vec<Stop> = Vec::new();
+        let mut ruined_routes: HashSet<usize> = HashSet::new();
+
+        for neighbor in self.neighbor_lists[&seed].iter() {
+            if ruined_routes.len() >= number_of_strings {
+                break;
+            }
+
+            if removed.contains(neighbor) {
+                continue;
+            }
+
+            let route_index = match route_by_customer.get(&neighbor.id) {
+                Some(route_index) if !ruined_routes.contains(route_index) => *route_index,
+                _ => continue,
+            };
+
+            let route = &mut routes[route_index];
+            let route_length = route.stops.len() - 2;
+            let string_length = rng.gen_range(1..=max_string_length.min(route_length));
+
+            let position = route
+                .stops
+                .iter()
+                .position(|stop| stop.id == neighbor.id)
+                .unwrap_or_else(|| panic!("the route should contain the stop {}", neighbor.id));
+
+            removed.extend(match rng.gen_bool(self.split_rate.into()) {
+                true if string_length < route_length => {
+                    self.remove_split_string(route, position, string_length, rng, distance_service)
+                }
+                _ => Self::remove_string(route, position, string_length, rng, distance_service),
+            });
+
+            ruined_routes.insert(route_index);
+        }
+
+        removed
+    }
+
+    fn choose_string_start<R: Rng + ?Sized>(
+        route: &Route,
+        position: usize,
+        length: usize,
+        rng: &mut R,
+    ) -> usize {
+        let first_start = position.saturating_sub(length - 1).max(1);
+        let last_start = position.min(route.stops.len() - 1 - length);
+
+        rng.gen_range(first_start..=last_start)
+    }
+
+    fn remove_positions(
+        route: &mut Route,
+        positions: impl DoubleEndedIterator<Item = usize>,
+        distance_service: &DistanceService,
+    ) -> Vec<Stop> {
+        positions
+            .rev()
+            .map(|position| {
+                let previous = &route.stops[position - 1];
+                let current = &route.stops[position];
+                let next = &route.stops[position + 1];
+
+                let distance_reduction = distance_service.get_distance(previous, current)
+                    + distance_service.get_distance(current, next)
+                    - distance_service.get_distance(previous, next);
+
+                route.remove_stop(position, distance_reduction)
+            })
+            .collect()
+    }
+
+    fn remove_string<R: Rng + ?Sized>(
+        route: &mut Route,
+        position: usize,
+        length: usize,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> Vec<Stop> {
+        let start = Self::choose_string_start(route, position, length, rng);
+
+        Self::remove_positions(route, start..start + length, distance_service)
+    }
+
+    fn remove_split_string<R: Rng + ?Sized>(
+        &self,
+        route: &mut Route,
+        position: usize,
+        length: usize,
+        rng: &mut R,
+        distance_service: &DistanceService,
+    ) -> Vec<Stop> {
+        let max_preserved = route.stops.len() - 2 - length;
+        let mut preserved = 1;
+
+        while preserved < max_preserved && rng.gen_bool(self.split_depth.into()) {
+            preserved += 1;
+        }
+
+        let start = Self::choose_string_start(route, position, length + preserved, rng);
+        let preserved_start = start + rng.gen_range(0..=length);
+
+        let positions: Vec<usize> = (start..start + length + preserved)
+            .filter(|position| {
+                *position < preserved_start || *position >= preserved_start + preserved
+            })
+            .collect();
+
+        Self::remove_positions(route, positions.into_iter(), distance_service)
+    }
+}
@@ -0,0 +1,2 @@
+#[cfg(test)]
+mod ruin_and_recreate_solver_test;
@@ -0,0 +1,120 @@
+use std::rc::Rc;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
+    solvers::{
+        greedy::vrp_greedy_solver::VrpGreedySolver,
+        ruin_and_recreate::{
+            blink_insertion::BlinkInsertion,
+            ruin_and_recreate_solver::{RuinAndRecreateParameters, RuinAndRecreateSolver},
+            string_removal::StringRemoval,
+        },
+        solver::Solver,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+fn build_route(vehicle_id: u32, stops: Vec<Stop>, distance_service: &DistanceService) -> Route {
+    let mut route = Route::new(Vehicle::new(vehicle_id, 10));
+
+    route
+        .replace_stops(stops, distance_service)
+        .unwrap_or_else(|_| panic!("the vehicle {vehicle_id} should support the load"));
+
+    route
+}
+
+#[rstest]
+fn test_string_removal_and_blink_insertion_keep_every_customer(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let string_removal = StringRemoval::new(&stops[1..4], &distance_service, 2.0, 2, 0.5, 0.5);
+    let blink_insertion = BlinkInsertion::new(0.0);
+    let mut rng = ChaCha8Rng::seed_from_u64(0);
+
+    let mut routes = vec![
+        build_route(0, vec![stops[0], stops[1], stops[0]], &distance_service),
+        build_route(
+            1,
+            vec![stops[0], stops[2], stops[3], stops[0]],
+            &distance_service,
+        ),
+    ];
+
+    for _ in 0..20 {
+        let removed = string_removal.ruin(&mut routes, &mut rng, &distance_service);
+
+        assert!(!removed.is_empty());
+        assert!(removed.len() <= 3);
+
+        assert!(blink_insertion.recreate(
+            &mut routes,
+            removed,
+            &stops[0],
+            &mut rng,
+            &distance_service,
+        ));
+
+        let mut customer_ids: Vec<u32> = routes
+            .iter()
+            .flat_map(|route| route.stops.iter())
+            .filter(|stop| stop.id != 0)
+            .map(|stop| stop.id)
+            .collect();
+        customer_ids.sort();
+
+        assert_eq!(customer_ids, vec![1, 2, 3]);
+
+        for route in routes.iter() {
+            let expected_distance: f32 = route
+                .stops
+                .windows(2)
+                .map(|window| distance_service.get_distance(&window[0], &window[1]))
+                .sum();
+
+            assert!((route.total_distance() - expected_distance).abs() < 1e-4);
+        }
+    }
+}
+
+#[rstest]
+fn test_ruin_and_recreate_improves_the_initial_solution(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let initial_solution =
+        VrpGreedySolver::new(vehicle_factory(2), &distances, stops.clone()).solve();
+
+    assert_eq!(initial_solution.total_distance, 10.0);
+
+    let parameters = RuinAndRecreateParameters {
+        max_iterations: 200,
+        average_removed_customers: 2.0,
+        max_string_length: 2,
+        ..Default::default()
+    };
+
+    let mut solver = RuinAndRecreateSolver::new(
+        stops,
+        &distances,
+        initial_solution,
+        parameters,
+        ChaCha8Rng::seed_from_u64(0),
+    );
+
+    let solution = solver.solve();
+
+    assert_eq!(solution.total_distance, 8.0);
+    assert_eq!(solution.routes.len(), 2);
+}
@@ -26,6 +26,14 @@ where
         &self,
         candidates: Box<dyn Iterator<Item = (P::CandidateId, P::Cost)> + '_>,
     ) -> Option<P::CandidateId>;
+
+    fn get_best_candidate_from(
+        &self,
+        _last_candidate: Option<P::CandidateId>,
+        candidates: Box<dyn Iterator<Item = (P::CandidateId, P::Cost)> + '_>,
+    ) -> Option<P::CandidateId> {
+        self.get_best_candidate(candidates)
+    }
 }
 
 pub trait SequentialSolver<P>
@@ -44,12 +52,20 @@ where
 
     fn get_candidate_chooser(&self) -> &dyn CandidateChooser<P>;
 
+    fn get_last_candidate(&self, _sequence_id: P::SequenceId) -> Option<P::CandidateId> {
+        None
+    }
+
     fn run_iteration(&mut self) {
         let sequence_ids: Vec<P::SequenceId> = self.get_all_sequences().collect();
 
         for sequence_id in sequence_ids {
+            let last_candidate = self.get_last_candidate(sequence_id);
             let candidates = self.get_all_candidates(sequence_id);
-            let candidate_id = self.get_candidate_chooser().get_best_candidate(candidates);
+
+            let candidate_id = self
+                .get_candidate_chooser()
+                .get_best_candidate_from(last_candidate, candidates);
 
             if let Some(candidate_id) = candidate_id {
                 self.choose_candidate(sequence_id, candidate_id);
@@ -0,0 +1,3 @@
+pub mod route_pool;
+pub mod set_partitioning_solver;
+mod tests;
@@ -0,0 +1,86 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use crate::{
+    domain::route::Route,
+    services::distance::distance_service::DistanceService,
+    solvers::{solution_collector::SolutionCollector, vrp_solution::VrpSolution},
+};
+
+type RouteKey = Vec<u32>;
+
+pub struct RoutePool {
+    routes: BTreeMap<RouteKey, Route>,
+    distance_service: Rc<DistanceService>,
+}
+
+impl SolutionCollector<VrpSolution> for RoutePool {
+    fn collect(&mut self, solution: &VrpSolution) {
+        for route in solution.routes.values() {
+            self.add_route(route);
+        }
+    }
+}
+
+impl RoutePool {
+    pub fn new(distance_service: Rc<DistanceService>) -> Self {
+        Self {
+            distance_service,
+            routes: BTreeMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.routes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.routes.clear();
+    }
+
+    pub fn add_routes(&mut self, routes: &[Route]) {
+        for route in routes {
+            self.add_route(route);
+        }
+    }
+
+    pub fn add_route(&mut self, route: &Route) {
+        let (first_stop, last_stop) = match (route.stops.first(), route.stops.last()) {
+            (Some(first_stop), Some(last_stop)) => (first_stop, last_stop),
+            _ => return,
+        };
+
+        if route.stops.len() < 3 || first_stop.id != last_stop.id {
+            return;
+        }
+
+        let mut pooled_route = route.clone();
+
+        if pooled_route
+            .replace_stops(route.stops.clone(), &self.distance_service)
+            .is_err()
+        {
+            return;
+        }
+
+        let mut key: RouteKey = route.stops[1..route.stops.len() - 1]
+            .iter()
+            .map(|stop| stop.id)
+            .collect();
+        key.sort_unstable();
+
+        match self.routes.get(&key) {
+            Some(current) if current.total_distance() <= pooled_route.total_distance() => (),
+            _ => {
+                self.routes.insert(key, pooled_route);
+            }
+        }
+    }
+
+    pub(super) fn get_routes(&self) -> impl Iterator<Item = &Route> {
+        self.routes.values()
+    }
+}
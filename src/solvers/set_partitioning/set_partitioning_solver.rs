@@ -0,0 +1,265 @@
+use std::{cell::RefCell, cmp::Reverse, collections::HashMap, rc::Rc};
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::{distance::distance_service::DistanceService, route::route_service::RouteMap},
+    solvers::{solution_collector::SolutionCollector, solver::Solver, vrp_solution::VrpSolution},
+};
+
+use super::route_pool::RoutePool;
+
+pub struct SetPartitioningParameters {
+    pub rounds: u32,
+    pub node_limit: u64,
+}
+
+struct Column {
+    cost: f32,
+    customers: Vec<usize>,
+    route: Route,
+}
+
+struct Partitioning {
+    columns: Vec<Column>,
+    columns_by_customer: Vec<Vec<usize>>,
+    customer_bounds: Vec<f32>,
+    covered: Vec<bool>,
+    chosen: Vec<usize>,
+    best_cost: f32,
+    best_columns: Option<Vec<usize>>,
+    max_columns: usize,
+    explored_nodes: u64,
+    node_limit: u64,
+}
+
+impl Partitioning {
+    fn search(&mut self, cost: f32) {
+        if self.explored_nodes >= self.node_limit {
+            return;
+        }
+
+        self.explored_nodes += 1;
+
+        let customer = match self.covered.iter().position(|covered| !covered) {
+            Some(customer) => customer,
+            None => {
+                if cost < self.best_cost {
+                    self.best_cost = cost;
+                    self.best_columns = Some(self.chosen.clone());
+                }
+
+                return;
+            }
+        };
+
+        if self.chosen.len() >= self.max_columns {
+            return;
+        }
+
+        let remaining_bound: f32 = self
+            .covered
+            .iter()
+            .zip(self.customer_bounds.iter())
+            .filter(|(covered, _)| !**covered)
+            .map(|(_, bound)| bound)
+            .sum();
+
+        if cost + remaining_bound >= self.best_cost {
+            return;
+        }
+
+        for position in 0..self.columns_by_customer[customer].len() {
+            let column_index = self.columns_by_customer[customer][position];
+            let column = &self.columns[column_index];
+
+            if column
+                .customers
+                .iter()
+                .any(|customer| self.covered[*customer])
+            {
+                continue;
+            }
+
+            let column_cost = column.cost;
+            self.set_coverage(column_index, true);
+            self.chosen.push(column_index);
+
+            self.search(cost + column_cost);
+
+            self.chosen.pop();
+            self.set_coverage(column_index, false);
+        }
+    }
+
+    fn set_coverage(&mut self, column_index: usize, covered: bool) {
+        for customer in self.columns[column_index].customers.iter() {
+            self.covered[*customer] = covered;
+        }
+    }
+}
+
+pub struct SetPartitioningSolver {
+    vehicles: Vec<Vehicle>,
+    route_pool: Rc<RefCell<RoutePool>>,
+    distance_service: Rc<DistanceService>,
+    first_stage_solver: Box<dyn Solver<VrpSolution>>,
+    parameters: SetPartitioningParameters,
+}
+
+impl Solver<VrpSolution> for SetPartitioningSolver {
+    fn solve(&mut self) -> VrpSolution {
+        let mut best_solution = VrpSolution::default();
+
+        for _ in 0..self.parameters.rounds {
+            let solution = self.first_stage_solver.solve();
+            self.route_pool.borrow_mut().collect(&solution);
+
+            if solution.is_better_than(&best_solution) {
+                best_solution = solution;
+            }
+
+            if let Some(recombined) = self.recombine(&best_solution) {
+                if recombined.is_better_than(&best_solution) {
+                    best_solution = recombined;
+                }
+            }
+        }
+
+        best_solution
+    }
+}
+
+impl SetPartitioningSolver {
+    pub fn new(
+        vehicles: Vec<Vehicle>,
+        route_pool: Rc<RefCell<RoutePool>>,
+        distance_service: Rc<DistanceService>,
+        first_stage_solver: Box<dyn Solver<VrpSolution>>,
+        parameters: SetPartitioningParameters,
+    ) -> Self {
+        Self {
+            vehicles,
+            route_pool,
+            distance_service,
+            first_stage_solver,
+            parameters,
+        }
+    }
+
+    pub fn recombine(&self, reference: &VrpSolution) -> Option<VrpSolution> {
+        let customer_indices: HashMap<u32, usize> = reference
+            .routes
+            .values()
+            .filter(|route| route.stops.len() > 2)
+            .flat_map(|route| route.stops[1..route.stops.len() - 1].iter())
+            .enumerate()
+            .map(|(index, stop)| (stop.id, index))
+            .collect();
+
+        let columns = self.build_columns(&customer_indices);
+
+        let mut columns_by_customer = vec![Vec::new(); customer_indices.len()];
+        let mut customer_bounds = vec![f32::MAX; customer_indices.len()];
+
+        for (column_index, column) in columns.iter().enumerate() {
+            let share = column.cost / column.customers.len() as f32;
+
+            for customer in column.customers.iter() {
+                columns_by_customer[*customer].push(column_index);
+                customer_bounds[*customer] = customer_bounds[*customer].min(share);
+            }
+        }
+
+        for column_indices in columns_by_customer.iter_mut() {
+            column_indices.sort_by(|column1, column2| {
+                let share1 = columns[*column1].cost / columns[*column1].customers.len() as f32;
+                let share2 = columns[*column2].cost / columns[*column2].customers.len() as f32;
+
+                share1.total_cmp(&share2)
+            });
+        }
+
+        let mut partitioning = Partitioning {
+            columns,
+            columns_by_customer,
+            customer_bounds,
+            covered: vec![false; customer_indices.len()],
+            chosen: Vec::new(),
+            best_cost: reference.total_distance,
+            best_columns: None,
+            max_columns: self.vehicles.len(),
+            explored_nodes: 0,
+            node_limit: self.parameters.node_limit,
+        };
+
+        partitioning.search(0.0);
+
+        let best_columns = partitioning.best_columns?;
+        let routes = best_columns
+            .into_iter()
+            .map(|column_index| partitioning.columns[column_index].route.clone())
+            .collect();
+
+        self.assign_routes(routes, reference)
+    }
+
+    fn build_columns(&self, customer_indices: &HashMap<u32, usize>) -> Vec<Column> {
+        self.route_pool
+            .borrow()
+            .get_routes()
+            .filter_map(|route| {
+                let customers = route.stops[1..route.stops.len() - 1]
+                    .iter()
+                    .map(|stop| customer_indices.get(&stop.id).copied())
+                    .collect::<Option<Vec<usize>>>()?;
+
+                Some(Column {
+                    customers,
+                    cost: route.total_distance(),
+                    route: route.clone(),
+                })
+            })
+            .collect()
+    }
+
+    fn assign_routes(
+        &self,
+        mut routes: Vec<Route>,
+        reference: &VrpSolution,
+    ) -> Option<VrpSolution> {
+        let depot = reference
+            .routes
+            .values()
+            .find_map(|route| route.stops.first().copied())?;
+
+        let mut vehicles = self.vehicles.clone();
+        vehicles.sort_by_key(|vehicle| Reverse(vehicle.capacity));
+
+        routes.sort_by_key(|route| Reverse(Self::calculate_load(&route.stops)));
+
+        let mut assigned_routes: RouteMap = RouteMap::new();
+
+        for (index, vehicle) in vehicles.iter().enumerate() {
+            let stops = match routes.get(index) {
+                Some(route) => route.stops.clone(),
+                None => vec![depot, depot],
+            };
+
+            let mut route = Route::new(Vehicle::new(vehicle.id, vehicle.capacity));
+            route.replace_stops(stops, &self.distance_service).ok()?;
+
+            assigned_routes.insert(vehicle.id, route);
+        }
+
+        let total_distance = assigned_routes
+            .values()
+            .map(|route| route.total_distance())
+            .sum();
+
+        Some(VrpSolution::new(&assigned_routes, total_distance))
+    }
+
+    fn calculate_load(stops: &[Stop]) -> u32 {
+        stops.iter().map(|stop| stop.usage).sum()
+    }
+}
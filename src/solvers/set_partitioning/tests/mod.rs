@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod route_pool_test;
+#[cfg(test)]
+mod set_partitioning_solver_test;
@@ -0,0 +1,40 @@
+use std::rc::Rc;
+
+use rstest::rstest;
+
+use crate::{
+    domain::stop::Stop, services::distance::distance_service::DistanceService,
+    solvers::set_partitioning::route_pool::RoutePool,
+};
+
+use crate::tests::fixtures::routes_fixture::{route_factory, RouteFactory};
+use crate::tests::fixtures::services_fixture::distance_service;
+use crate::tests::fixtures::stops_fixture::stops;
+
+#[rstest]
+fn test_route_pool_keeps_the_cheapest_route_per_customer_set(
+    distance_service: DistanceService,
+    route_factory: RouteFactory,
+    stops: Vec<Stop>,
+) {
+    let mut route_pool = RoutePool::new(Rc::new(distance_service));
+
+    route_pool.add_route(&route_factory(vec![
+        stops[0], stops[1], stops[2], stops[3], stops[0],
+    ]));
+    route_pool.add_route(&route_factory(vec![
+        stops[0], stops[2], stops[3], stops[1], stops[0],
+    ]));
+    route_pool.add_route(&route_factory(vec![stops[0], stops[1], stops[0]]));
+    route_pool.add_route(&route_factory(vec![stops[0], stops[0]]));
+
+    assert_eq!(route_pool.len(), 2);
+
+    let mut costs: Vec<f32> = route_pool
+        .get_routes()
+        .map(|route| route.total_distance())
+        .collect();
+    costs.sort_by(|cost1, cost2| cost1.total_cmp(cost2));
+
+    assert_eq!(costs, vec![4.0, 8.0]);
+}
@@ -0,0 +1,135 @@
+use std::{cell::RefCell, rc::Rc};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::rstest;
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::{
+        distance::distance_service::{DistanceMatrix, DistanceService},
+        route::route_service::RouteMap,
+    },
+    solvers::{
+        grasp::vrp_grasp_solver::{GraspSolverParameters, VrpGraspSolver},
+        greedy::vrp_greedy_solver::VrpGreedySolver,
+        set_partitioning::{
+            route_pool::RoutePool,
+            set_partitioning_solver::{SetPartitioningParameters, SetPartitioningSolver},
+        },
+        solver::Solver,
+        vrp_solution::VrpSolution,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::stops;
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+fn build_solution(routes: Vec<Route>) -> VrpSolution {
+    let total_distance = routes.iter().map(|route| route.total_distance()).sum();
+    let routes: RouteMap = routes
+        .into_iter()
+        .map(|route| (route.vehicle.id, route))
+        .collect();
+
+    VrpSolution::new(&routes, total_distance)
+}
+
+fn build_route(stops: Vec<Stop>, vehicle_id: u32, distance_service: &DistanceService) -> Route {
+    let mut route = Route::new(Vehicle::new(vehicle_id, 10));
+    route.replace_stops(stops, distance_service).unwrap();
+    route
+}
+
+#[rstest]
+fn test_set_partitioning_recombines_pooled_routes(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let route_pool = Rc::new(RefCell::new(RoutePool::new(distance_service.clone())));
+
+    let reference = build_solution(vec![
+        build_route(vec![stops[0], stops[1], stops[0]], 0, &distance_service),
+        build_route(
+            vec![stops[0], stops[2], stops[3], stops[0]],
+            1,
+            &distance_service,
+        ),
+    ]);
+
+    route_pool.borrow_mut().add_routes(&[
+        build_route(
+            vec![stops[0], stops[1], stops[2], stops[0]],
+            0,
+            &distance_service,
+        ),
+        build_route(
+            vec![stops[0], stops[2], stops[3], stops[1], stops[0]],
+            1,
+            &distance_service,
+        ),
+    ]);
+
+    let solver = SetPartitioningSolver::new(
+        vehicle_factory(2),
+        route_pool,
+        distance_service,
+        Box::new(VrpGreedySolver::new(
+            vehicle_factory(2),
+            &distances,
+            stops.clone(),
+        )),
+        SetPartitioningParameters {
+            rounds: 1,
+            node_limit: 1_000,
+        },
+    );
+
+    let solution = solver.recombine(&reference).unwrap();
+
+    assert_eq!(solution.total_distance, 8.0);
+    assert_eq!(solution.routes.len(), 2);
+}
+
+#[rstest]
+fn test_set_partitioning_improves_grasp_solutions(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let route_pool = Rc::new(RefCell::new(RoutePool::new(distance_service.clone())));
+
+    let parameters = GraspSolverParameters {
+        rcl_size: 3,
+        max_improvement_times: 3,
+    };
+
+    let grasp_solver = VrpGraspSolver::new(
+        stops,
+        vehicle_factory(2),
+        &distances,
+        parameters,
+        ChaCha8Rng::seed_from_u64(0),
+    )
+    .with_route_pool(route_pool.clone());
+
+    let mut solver = SetPartitioningSolver::new(
+        vehicle_factory(2),
+        route_pool.clone(),
+        distance_service,
+        Box::new(grasp_solver),
+        SetPartitioningParameters {
+            rounds: 3,
+            node_limit: 1_000,
+        },
+    );
+
+    let solution = solver.solve();
+
+    assert!(!route_pool.borrow().is_empty());
+    assert!(solution.total_distance <= 10.0);
+}
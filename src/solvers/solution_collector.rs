@@ -0,0 +1,3 @@
+pub trait SolutionCollector<S> {
+    fn collect(&mut self, solution: &S);
+}
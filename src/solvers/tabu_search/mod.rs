@@ -0,0 +1,3 @@
+mod tabu_list;
+pub mod tabu_search_solver;
+mod tests;
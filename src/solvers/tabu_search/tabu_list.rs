@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+pub(super) type TabuAttribute = (u32, u32);
+
+#[derive(Default)]
+pub(super) struct TabuList {
+    tenure: u32,
+    expirations: HashMap<TabuAttribute, u32>,
+    frequencies: HashMap<TabuAttribute, u32>,
+}
+
+impl TabuList {
+    pub(super) fn new(tenure: u32) -> Self {
+        Self {
+            tenure,
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn reset(&mut self) {
+        self.expirations.clear();
+        self.frequencies.clear();
+    }
+
+    pub(super) fn is_tabu(&self, attribute: &TabuAttribute, iteration: u32) -> bool {
+        self.expirations
+            .get(attribute)
+            .is_some_and(|expiration| iteration < *expiration)
+    }
+
+    pub(super) fn forbid(&mut self, attribute: TabuAttribute, iteration: u32) {
+        self.expirations.insert(attribute, iteration + self.tenure);
+    }
+
+    pub(super) fn record(&mut self, attribute: TabuAttribute) {
+        *self.frequencies.entry(attribute).or_default() += 1;
+    }
+
+    pub(super) fn get_frequency(&self, attribute: &TabuAttribute) -> u32 {
+        self.frequencies.get(attribute).copied().unwrap_or_default()
+    }
+}
@@ -0,0 +1,326 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    rc::Rc,
+};
+
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::{DistanceMatrix, DistanceService},
+    solvers::{solution::Solution, solver::Solver, vrp_solution::VrpSolution},
+};
+
+use super::tabu_list::{TabuAttribute, TabuList};
+
+type StopLocation = (u32, usize);
+type StopLocations = BTreeMap<u32, StopLocation>;
+type NeighborLists = HashMap<u32, Vec<Stop>>;
+
+pub struct TabuSearchSolverParameters {
+    pub max_iterations: u32,
+    pub tabu_tenure: u32,
+    pub neighborhood_size: usize,
+    pub diversification_factor: f32,
+}
+
+#[derive(Clone, Copy)]
+pub(super) struct RelocateMove {
+    pub(super) stop: Stop,
+    pub(super) origin: StopLocation,
+    pub(super) destination: StopLocation,
+    pub(super) removal_cost: f32,
+    pub(super) insertion_cost: f32,
+}
+
+impl RelocateMove {
+    pub(super) fn cost(&self) -> f32 {
+        self.insertion_cost - self.removal_cost
+    }
+
+    fn get_attribute(&self) -> TabuAttribute {
+        (self.stop.id, self.destination.0)
+    }
+}
+
+pub struct TabuSearchSolver {
+    pub(super) tabu_list: TabuList,
+    neighbor_lists: NeighborLists,
+    parameters: TabuSearchSolverParameters,
+    distance_service: Rc<DistanceService>,
+    first_stage_solver: Box<dyn Solver<VrpSolution>>,
+}
+
+impl Solver<VrpSolution> for TabuSearchSolver {
+    fn solve(&mut self) -> VrpSolution {
+        let mut current_solution = self.first_stage_solver.solve();
+        let mut best_solution = current_solution.clone();
+
+        self.tabu_list.reset();
+
+        for iteration in 0..self.parameters.max_iterations {
+            let relocate_move =
+                match self.find_best_move(&current_solution, &best_solution, iteration) {
+                    Some(relocate_move) => relocate_move,
+                    None => break,
+                };
+
+            Self::apply_move(&mut current_solution, &relocate_move);
+
+            self.tabu_list
+                .forbid((relocate_move.stop.id, relocate_move.origin.0), iteration);
+            self.tabu_list.record(relocate_move.get_attribute());
+
+            if current_solution.is_better_than(&best_solution) {
+                best_solution = current_solution.clone();
+            }
+        }
+
+        best_solution
+    }
+}
+
+impl TabuSearchSolver {
+    pub fn new(
+        stops: Vec<Stop>,
+        distances: &DistanceMatrix,
+        first_stage_solver: Box<dyn Solver<VrpSolution>>,
+        parameters: TabuSearchSolverParameters,
+    ) -> Self {
+        let distance_service = Rc::new(DistanceService::new(stops.clone(), distances));
+
+        Self {
+            first_stage_solver,
+            tabu_list: TabuList::new(parameters.tabu_tenure),
+            neighbor_lists: Self::map_neighbor_lists(
+                &stops,
+                &distance_service,
+                parameters.neighborhood_size,
+            ),
+            distance_service,
+            parameters,
+        }
+    }
+
+    fn map_neighbor_lists(
+        stops: &[Stop],
+        distance_service: &DistanceService,
+        neighborhood_size: usize,
+    ) -> NeighborLists {
+        stops
+            .iter()
+            .map(|stop| {
+                let neighbors = distance_service
+                    .get_k_nearest_stops(stop, neighborhood_size, |neighbor| neighbor.id != stop.id)
+                    .into_iter()
+                    .copied()
+                    .collect();
+
+                (stop.id, neighbors)
+            })
+            .collect()
+    }
+
+    fn map_stop_locations(solution: &VrpSolution) -> StopLocations {
+        solution
+            .routes
+            .iter()
+            .flat_map(|(vehicle_id, route)| {
+                route
+                    .stops
+                    .iter()
+                    .enumerate()
+                    .skip(1)
+                    .take(route.stops.len().saturating_sub(2))
+                    .map(move |(stop_index, stop)| (stop.id, (*vehicle_id, stop_index)))
+            })
+            .collect()
+    }
+
+    fn get_insertion_points(
+        solution: &VrpSolution,
+        locations: &StopLocations,
+        neighbor: &Stop,
+        depot: &Stop,
+    ) -> Vec<StopLocation> {
+        if neighbor.id == depot.id {
+            return solution
+                .routes
+                .iter()
+                .flat_map(|(vehicle_id, route)| {
+                    [(*vehicle_id, 1), (*vehicle_id, route.stops.len() - 1)]
+                })
+                .collect();
+        }
+
+        match locations.get(&neighbor.id) {
+            Some((vehicle_id, stop_index)) => {
+                vec![(*vehicle_id, *stop_index), (*vehicle_id, stop_index + 1)]
+            }
+            None => Vec::new(),
+        }
+    }
+
+    fn evaluate_move(
+        &self,
+        solution: &VrpSolution,
+        stop: Stop,
+        origin: StopLocation,
+        destination: StopLocation,
+    ) -> Option<RelocateMove> {
+        let origin_stops = &solution.routes[&origin.0].stops;
+        let destination_route = &solution.routes[&destination.0];
+
+        if origin.0 == destination.0 {
+            if destination.1 == origin.1 || destination.1 == origin.1 + 1 {
+                return None;
+            }
+        } else if !destination_route.can_add_stop(&stop) {
+            return None;
+        }
+
+        let previous = &origin_stops[origin.1 - 1];
+        let next = &origin_stops[origin.1 + 1];
+
+        let removal_cost = self.distance_service.get_distance(previous, &stop)
+            + self.distance_service.get_distance(&stop, next)
+            - self.distance_service.get_distance(previous, next);
+
+        let before = &destination_route.stops[destination.1 - 1];
+        let after = &destination_route.stops[destination.1];
+
+        let insertion_cost = self.distance_service.get_distance(before, &stop)
+            + self.distance_service.get_distance(&stop, after)
+            - self.distance_service.get_distance(before, after);
+
+        Some(RelocateMove {
+            stop,
+            origin,
+            destination,
+            removal_cost,
+            insertion_cost,
+        })
+    }
+
+    fn get_penalized_cost(&self, relocate_move: &RelocateMove) -> f32 {
+        let cost = relocate_move.cost();
+
+        if cost < 0.0 {
+            return cost;
+        }
+
+        let frequency = self.tabu_list.get_frequency(&relocate_move.get_attribute());
+
+        cost + self.parameters.diversification_factor * frequency as f32
+    }
+
+    fn is_admissible(
+        &self,
+        relocate_move: &RelocateMove,
+        current_solution: &VrpSolution,
+        best_solution: &VrpSolution,
+        iteration: u32,
+    ) -> bool {
+        if !self
+            .tabu_list
+            .is_tabu(&relocate_move.get_attribute(), iteration)
+        {
+            return true;
+        }
+
+        current_solution.get_cost() + relocate_move.cost() < best_solution.get_cost()
+    }
+
+    pub(super) fn find_best_move(
+        &self,
+        current_solution: &VrpSolution,
+        best_solution: &VrpSolution,
+        iteration: u32,
+    ) -> Option<RelocateMove> {
+        let depot = current_solution
+            .routes
+            .values()
+            .next()?
+            .stops
+            .first()
+            .copied()?;
+
+        let locations = Self::map_stop_locations(current_solution);
+
+        let mut best_move: Option<(f32, RelocateMove)> = None;
+
+        for (stop_id, origin) in locations.iter() {
+            let stop = current_solution.routes[&origin.0].stops[origin.1];
+
+            let neighbors = match self.neighbor_lists.get(stop_id) {
+                Some(neighbors) => neighbors,
+                None => continue,
+            };
+
+            for neighbor in neighbors {
+                for destination in
+                    Self::get_insertion_points(current_solution, &locations, neighbor, &depot)
+                {
+                    let relocate_move =
+                        match self.evaluate_move(current_solution, stop, *origin, destination) {
+                            Some(relocate_move) => relocate_move,
+                            None => continue,
+                        };
+
+                    if !self.is_admissible(
+                        &relocate_move,
+                        current_solution,
+                        best_solution,
+                        iteration,
+                    ) {
+                        continue;
+                    }
+
+                    let penalized_cost = self.get_penalized_cost(&relocate_move);
+
+                    match best_move {
+                        Some((best_cost, _)) if best_cost <= penalized_cost => (),
+                        _ => best_move = Some((penalized_cost, relocate_move)),
+                    }
+                }
+            }
+        }
+
+        best_move.map(|(_, relocate_move)| relocate_move)
+    }
+
+    pub(super) fn apply_move(solution: &mut VrpSolution, relocate_move: &RelocateMove) {
+        let (origin_vehicle_id, origin_index) = relocate_move.origin;
+        let (destination_vehicle_id, mut destination_index) = relocate_move.destination;
+
+        let stop = solution
+            .routes
+            .get_mut(&origin_vehicle_id)
+            .unwrap_or_else(|| {
+                panic!("it should exist a route for the vehicle {origin_vehicle_id}")
+            })
+            .remove_stop(origin_index, relocate_move.removal_cost);
+
+        if origin_vehicle_id == destination_vehicle_id && destination_index > origin_index {
+            destination_index -= 1;
+        }
+
+        solution
+            .routes
+            .get_mut(&destination_vehicle_id)
+            .unwrap_or_else(|| {
+                panic!("it should exist a route for the vehicle {destination_vehicle_id}")
+            })
+            .add_stop_at(stop, destination_index, relocate_move.insertion_cost)
+            .unwrap_or_else(|_| {
+                panic!(
+                    "the vehicle {destination_vehicle_id} should support the load from {}",
+                    stop.id
+                )
+            });
+
+        solution.total_distance = solution
+            .routes
+            .values()
+            .map(|route| route.total_distance())
+            .sum();
+    }
+}
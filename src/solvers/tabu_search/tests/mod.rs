@@ -0,0 +1,4 @@
+#[cfg(test)]
+mod tabu_list_test;
+#[cfg(test)]
+mod tabu_search_solver_test;
@@ -0,0 +1,32 @@
+use rstest::rstest;
+
+use crate::solvers::tabu_search::tabu_list::TabuList;
+
+#[rstest]
+fn test_tabu_attribute_expires_after_tenure() {
+    let mut tabu_list = TabuList::new(3);
+
+    tabu_list.forbid((1, 0), 2);
+
+    assert!(tabu_list.is_tabu(&(1, 0), 2));
+    assert!(tabu_list.is_tabu(&(1, 0), 4));
+    assert!(!tabu_list.is_tabu(&(1, 0), 5));
+    assert!(!tabu_list.is_tabu(&(1, 1), 3));
+}
+
+#[rstest]
+fn test_tabu_list_counts_attribute_frequencies() {
+    let mut tabu_list = TabuList::new(3);
+
+    tabu_list.record((1, 0));
+    tabu_list.record((1, 0));
+    tabu_list.record((2, 0));
+
+    assert_eq!(tabu_list.get_frequency(&(1, 0)), 2);
+    assert_eq!(tabu_list.get_frequency(&(2, 0)), 1);
+    assert_eq!(tabu_list.get_frequency(&(3, 0)), 0);
+
+    tabu_list.reset();
+
+    assert_eq!(tabu_list.get_frequency(&(1, 0)), 0);
+}
@@ -0,0 +1,202 @@
+use std::collections::HashSet;
+
+use rstest::{fixture, rstest};
+
+use crate::{
+    domain::{route::Route, stop::Stop, vehicle::Vehicle},
+    services::{
+        distance::distance_service::{DistanceMatrix, DistanceService},
+        route::route_service::RouteMap,
+    },
+    solvers::{
+        greedy::vrp_greedy_solver::VrpGreedySolver,
+        solver::Solver,
+        tabu_search::tabu_search_solver::{TabuSearchSolver, TabuSearchSolverParameters},
+        vrp_solution::VrpSolution,
+    },
+};
+
+use crate::tests::fixtures::distances_fixture::distances;
+use crate::tests::fixtures::stops_fixture::{stops, unloaded_stops};
+use crate::tests::fixtures::vehicles_fixture::{vehicle_factory, VehicleFactory};
+
+#[rstest]
+fn test_tabu_search_does_not_worsen_the_initial_solution(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let initial_solution =
+        VrpGreedySolver::new(vehicle_factory(2), &distances, stops.clone()).solve();
+
+    let first_stage_solver = VrpGreedySolver::new(vehicle_factory(2), &distances, stops.clone());
+
+    let parameters = TabuSearchSolverParameters {
+        max_iterations: 20,
+        tabu_tenure: 3,
+        neighborhood_size: 3,
+        diversification_factor: 0.5,
+    };
+
+    let mut solver =
+        TabuSearchSolver::new(stops, &distances, Box::new(first_stage_solver), parameters);
+
+    let solution = solver.solve();
+
+    assert!(solution.total_distance <= initial_solution.total_distance);
+}
+
+#[rstest]
+fn test_tabu_search_keeps_every_stop_once(
+    distances: DistanceMatrix,
+    stops: Vec<Stop>,
+    vehicle_factory: VehicleFactory,
+) {
+    let first_stage_solver = VrpGreedySolver::new(vehicle_factory(2), &distances, stops.clone());
+
+    let parameters = TabuSearchSolverParameters {
+        max_iterations: 20,
+        tabu_tenure: 3,
+        neighborhood_size: 3,
+        diversification_factor: 0.5,
+    };
+
+    let mut solver =
+        TabuSearchSolver::new(stops, &distances, Box::new(first_stage_solver), parameters);
+
+    let solution = solver.solve();
+
+    let visited_stops: Vec<u32> = solution
+        .routes
+        .values()
+        .flat_map(|route| route.stops[1..route.stops.len() - 1].iter())
+        .map(|stop| stop.id)
+        .collect();
+
+    let unique_stops: HashSet<u32> = visited_stops.iter().copied().collect();
+
+    assert_eq!(visited_stops.len(), unique_stops.len());
+    assert_eq!(unique_stops, HashSet::from([1, 2, 3]));
+}
+
+/// Stop 4 is closest to stop 1, so from `[0, 4, 1, 2, 3, 0]` (27) the only improving relocation,
+/// moving stop 4 to the end of the route (24), is not next to its nearest neighbour. The best
+/// route is `[0, 1, 4, 3, 2, 0]` (23).
+#[fixture]
+fn relocate_distances() -> DistanceMatrix {
+    [
+        ((0, 1), 4.0),
+        ((0, 2), 6.0),
+        ((0, 3), 10.0),
+        ((0, 4), 4.0),
+        ((1, 2), 6.0),
+        ((1, 3), 10.0),
+        ((1, 4), 3.0),
+        ((2, 3), 4.0),
+        ((2, 4), 6.0),
+        ((3, 4), 6.0),
+    ]
+    .into_iter()
+    .flat_map(|((from, to), distance)| [((from, to), distance), ((to, from), distance)])
+    .collect()
+}
+
+fn build_solver(
+    distances: &DistanceMatrix,
+    neighborhood_size: usize,
+    diversification_factor: f32,
+) -> TabuSearchSolver {
+    let stops = unloaded_stops(&[0, 1, 2, 3, 4]);
+    let first_stage_solver =
+        VrpGreedySolver::new(vec![Vehicle::new(0, 10)], distances, stops.clone());
+
+    let parameters = TabuSearchSolverParameters {
+        max_iterations: 1,
+        tabu_tenure: 3,
+        neighborhood_size,
+        diversification_factor,
+    };
+
+    TabuSearchSolver::new(stops, distances, Box::new(first_stage_solver), parameters)
+}
+
+fn build_solution(route_ids: &[u32], distances: &DistanceMatrix) -> VrpSolution {
+    let stops = unloaded_stops(route_ids);
+    let distance_service = DistanceService::new(unloaded_stops(&[0, 1, 2, 3, 4]), distances);
+
+    let mut route = Route::new(Vehicle::new(0, 10));
+    route
+        .replace_stops(stops, &distance_service)
+        .unwrap_or_else(|_| panic!("the vehicle 0 should support the load"));
+
+    let total_distance = route.total_distance();
+
+    VrpSolution::new(&RouteMap::from([(0, route)]), total_distance)
+}
+
+#[rstest]
+#[case::allowed_move(false, &[0, 1, 4, 3, 2, 0], Some(4))]
+#[case::tabu_move_that_beats_the_best(true, &[0, 4, 1, 2, 3, 0], Some(4))]
+#[case::tabu_move_that_does_not_beat_the_best(true, &[0, 1, 4, 3, 2, 0], None)]
+fn test_tabu_move_is_only_accepted_when_it_beats_the_best_solution(
+    relocate_distances: DistanceMatrix,
+    #[case] is_tabu: bool,
+    #[case] best_route: &[u32],
+    #[case] expected_stop: Option<u32>,
+) {
+    let mut solver = build_solver(&relocate_distances, 4, 0.0);
+    let current_solution = build_solution(&[0, 4, 1, 2, 3, 0], &relocate_distances);
+    let best_solution = build_solution(best_route, &relocate_distances);
+
+    if is_tabu {
+        for stop_id in 1..=4 {
+            solver.tabu_list.forbid((stop_id, 0), 0);
+        }
+    }
+
+    let relocate_move = solver.find_best_move(&current_solution, &best_solution, 1);
+
+    assert_eq!(
+        relocate_move.map(|relocate_move| relocate_move.stop.id),
+        expected_stop
+    );
+}
+
+#[rstest]
+fn test_frequency_penalty_changes_the_non_improving_move(relocate_distances: DistanceMatrix) {
+    let mut solver = build_solver(&relocate_distances, 4, 5.0);
+    let solution = build_solution(&[0, 1, 4, 3, 2, 0], &relocate_distances);
+
+    let first_move = solver
+        .find_best_move(&solution, &solution, 0)
+        .expect("there should be a move");
+
+    solver.tabu_list.record((first_move.stop.id, 0));
+
+    let second_move = solver
+        .find_best_move(&solution, &solution, 0)
+        .expect("there should be a move");
+
+    assert_eq!((first_move.stop.id, first_move.cost()), (1, 1.0));
+    assert_eq!((second_move.stop.id, second_move.cost()), (2, 4.0));
+}
+
+#[rstest]
+#[case::nearest_neighbor_only(1, 1, 0.0)]
+#[case::every_neighbor(4, 4, -3.0)]
+fn test_moves_are_restricted_to_the_neighbor_lists(
+    relocate_distances: DistanceMatrix,
+    #[case] neighborhood_size: usize,
+    #[case] expected_stop: u32,
+    #[case] expected_cost: f32,
+) {
+    let solver = build_solver(&relocate_distances, neighborhood_size, 0.0);
+    let solution = build_solution(&[0, 4, 1, 2, 3, 0], &relocate_distances);
+
+    let relocate_move = solver
+        .find_best_move(&solution, &solution, 0)
+        .expect("there should be a move");
+
+    assert_eq!(relocate_move.stop.id, expected_stop);
+    assert_eq!(relocate_move.cost(), expected_cost);
+}
@@ -1,3 +1,5 @@
+use std::{cell::RefCell, rc::Rc};
+
 use rand::Rng;
 
 use crate::{
@@ -5,10 +7,14 @@ use crate::{
     services::distance::distance_service::DistanceMatrix,
     solvers::{
         genetic::{
+            adaptation::generation_parameters::GenerationParameters,
             crossover::crossover_operator::CrossoverOperator,
             genetic_solver::{GeneticSolver, GeneticSolverParameters},
+            initialization::population_initializer::PopulationInitializer,
             population::Population,
+            termination::termination_criteria::StopReason,
         },
+        set_partitioning::route_pool::RoutePool,
         solver::Solver,
         vrp_solution::VrpSolution,
     },
@@ -22,13 +28,17 @@ pub struct TwoStageGeneticSolverParameters {
 pub struct TwoStageGeneticSolver<'a, R: Rng + ?Sized> {
     population_size: u32,
     first_stage_solver: &'a mut dyn Solver<VrpSolution>,
+    initializer: Option<PopulationInitializer<R>>,
     genetic_solver: GeneticSolver<'a, R>,
 }
 
 impl<'a, R: Rng + ?Sized> Solver<VrpSolution> for TwoStageGeneticSolver<'a, R> {
     fn solve(&mut self) -> VrpSolution {
-        let solutions = self.generate_initial_solutions();
-        let population = Population::from(solutions.as_slice());
+        let population = match &mut self.initializer {
+            Some(initializer) => initializer.initialize(self.population_size as usize),
+            None => Population::from(self.generate_initial_solutions().as_slice()),
+        };
+
         self.genetic_solver.update_population(population);
         self.genetic_solver.solve()
     }
@@ -45,6 +55,7 @@ impl<'a, R: Rng + ?Sized> TwoStageGeneticSolver<'a, R> {
     ) -> Self {
         Self {
             first_stage_solver,
+            initializer: None,
             population_size: parameters.population_size,
             genetic_solver: GeneticSolver::new(
                 stops,
@@ -57,6 +68,58 @@ impl<'a, R: Rng + ?Sized> TwoStageGeneticSolver<'a, R> {
         }
     }
 
+    pub fn with_route_pool(mut self, route_pool: Rc<RefCell<RoutePool>>) -> Self {
+        self.genetic_solver.set_route_pool(route_pool);
+        self
+    }
+
+    /// Builds the population with `initializer` instead of the first stage solver. The population
+    /// size stays the one of the solver parameters.
+    pub fn with_initializer(mut self, initializer: PopulationInitializer<R>) -> Self {
+        self.initializer = Some(initializer);
+        self
+    }
+
+    pub fn get_generations(&self) -> u32 {
+        self.genetic_solver.get_current_generation()
+    }
+
+    /// Fitness computations of the last `solve`: crossover children, including those of failed
+    /// crossovers, mutated and locally searched offspring, repairs and immigrants.
+    pub fn get_evaluations(&self) -> u32 {
+        self.genetic_solver.get_evaluations()
+    }
+
+    /// Why the last `solve` stopped, the same value as the returned solution's `stop_reason`; it is
+    /// `None` before the first run.
+    pub fn get_stop_reason(&self) -> Option<StopReason> {
+        self.genetic_solver.get_stop_reason()
+    }
+
+    pub fn get_failed_evaluations(&self) -> u32 {
+        self.genetic_solver.get_failed_evaluations()
+    }
+
+    pub fn get_repaired_individuals(&self) -> u32 {
+        self.genetic_solver.get_repaired_individuals()
+    }
+
+    pub fn get_infeasible_individuals(&self) -> usize {
+        self.genetic_solver.get_infeasible_individuals()
+    }
+
+    pub fn get_rejected_duplicates(&self) -> u32 {
+        self.genetic_solver.get_rejected_duplicates()
+    }
+
+    pub fn get_diversity_history(&self) -> &[f32] {
+        self.genetic_solver.get_diversity_history()
+    }
+
+    pub fn get_parameter_history(&self) -> &[GenerationParameters] {
+        self.genetic_solver.get_parameter_history()
+    }
+
     fn generate_initial_solutions(&mut self) -> Vec<VrpSolution> {
         (0..self.population_size)
             .map(|_| self.first_stage_solver.solve())
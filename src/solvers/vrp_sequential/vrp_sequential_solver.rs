@@ -67,4 +67,11 @@ impl SequentialSolver<Self> for VrpSequentialSolver {
     fn get_candidate_chooser(&self) -> &dyn CandidateChooser<Self> {
         self.candidate_chooser.as_ref()
     }
+
+    fn get_last_candidate(&self, sequence_id: u32) -> Option<u32> {
+        self.route_service
+            .get_route(sequence_id)
+            .get_current_stop()
+            .map(|stop| stop.id)
+    }
 }
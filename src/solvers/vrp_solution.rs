@@ -1,4 +1,11 @@
-use crate::services::route::route_service::RouteMap;
+use crate::{
+    domain::stop::Stop,
+    services::route::route_service::RouteMap,
+    solvers::{
+        genetic::termination::termination_criteria::StopReason,
+        portfolio::portfolio_solver::PortfolioReport,
+    },
+};
 
 use super::solution::Solution;
 
@@ -6,6 +13,13 @@ use super::solution::Solution;
 pub struct VrpSolution {
     pub routes: RouteMap,
     pub total_distance: f32,
+    pub lower_bound: Option<f32>,
+    /// Customers that are left out of the routes because no vehicle could serve them.
+    pub unassigned_stops: Vec<Stop>,
+    /// Why the search that produced the solution stopped, for solvers with a termination rule.
+    pub stop_reason: Option<StopReason>,
+    /// How every strategy did, when the solution is the winner of a portfolio.
+    pub portfolio_report: Option<PortfolioReport>,
 }
 
 impl Default for VrpSolution {
@@ -13,10 +27,25 @@ impl Default for VrpSolution {
         Self {
             total_distance: f32::MAX,
             routes: Default::default(),
+            lower_bound: None,
+            unassigned_stops: Vec::new(),
+            stop_reason: None,
+            portfolio_report: None,
         }
     }
 }
 
+impl PartialEq for VrpSolution {
+    fn eq(&self, other: &Self) -> bool {
+        self.routes.len() == other.routes.len()
+            && self.routes.iter().zip(other.routes.iter()).all(
+                |((vehicle_id, route), (other_vehicle_id, other_route))| {
+                    vehicle_id == other_vehicle_id && route.stops == other_route.stops
+                },
+            )
+    }
+}
+
 impl Solution for VrpSolution {
     type Cost = f32;
     type Data = RouteMap;
@@ -35,7 +64,45 @@ impl VrpSolution {
         Self {
             total_distance,
             routes: routes.clone(),
+            lower_bound: None,
+            unassigned_stops: Vec::new(),
+            stop_reason: None,
+            portfolio_report: None,
+        }
+    }
+
+    pub fn with_lower_bound(mut self, lower_bound: f32) -> Self {
+        self.lower_bound = Some(lower_bound);
+        self
+    }
+
+    pub fn with_unassigned_stops(mut self, unassigned_stops: Vec<Stop>) -> Self {
+        self.unassigned_stops = unassigned_stops;
+        self
+    }
+
+    pub fn with_stop_reason(mut self, stop_reason: Option<StopReason>) -> Self {
+        self.stop_reason = stop_reason;
+        self
+    }
+
+    pub fn with_portfolio_report(mut self, portfolio_report: PortfolioReport) -> Self {
+        self.portfolio_report = Some(portfolio_report);
+        self
+    }
+
+    pub fn is_feasible(&self) -> bool {
+        self.total_distance != f32::MAX && self.unassigned_stops.is_empty()
+    }
+
+    pub fn get_gap(&self) -> Option<f32> {
+        let lower_bound = self.lower_bound?;
+
+        if self.total_distance <= 0.0 || self.total_distance == f32::MAX {
+            return None;
         }
+
+        Some(((self.total_distance - lower_bound) / self.total_distance).max(0.0))
     }
 
     pub fn is_better_than(&self, other: &VrpSolution) -> bool {
@@ -1,21 +1,68 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 use rstest::{fixture, rstest};
 
 use crate::{
-    domain::{stop::Stop, vehicle::Vehicle},
+    domain::{coordinate::CoordinateMap, route::Route, stop::Stop, vehicle::Vehicle},
+    local_search::{
+        double_bridge_shaker::DoubleBridgeShaker, inter_route::InterRouteSearcher,
+        lin_kernighan::LinKernighanSearcher, local_searcher::LocalSearcher,
+        swap_star::SwapStarSearcher, two_opt::TwoOptSearcher,
+    },
     parsers::{
         cvrplib::cvrplib_parser::CvrpLibParser,
         vrp_parser::{VrpInputs, VrpParser},
     },
-    services::distance::distance_service::DistanceMatrix,
+    services::{
+        bounds::lower_bound_service::LowerBoundService,
+        distance::distance_service::{DistanceMatrix, DistanceService},
+    },
     solvers::{
+        ant_colony::ant_colony_solver::{AntColonySolver, AntColonySolverParameters},
+        bounded::bounded_solver::BoundedSolver,
+        branch_and_bound::branch_and_bound_solver::{
+            BranchAndBoundParameters, BranchAndBoundSolver,
+        },
         genetic::{
-            crossover::order_crossover::OrderCrossover, genetic_solver::GeneticSolverParameters,
+            crossover::{
+                best_cost_route_crossover::BestCostRouteCrossover,
+                crossover_operator::CrossoverOperator, cycle_crossover::CycleCrossover,
+                edge_recombination_crossover::EdgeRecombinationCrossover,
+                order_crossover::OrderCrossover,
+                partially_mapped_crossover::PartiallyMappedCrossover,
+                selective_route_exchange_crossover::SelectiveRouteExchangeCrossover,
+            },
+            genetic_solver::GeneticSolverParameters,
+            mutation::{
+                mutation_strategy::{MutationStrategy, WeightedMutation},
+                swap_mutation::SwapMutation,
+            },
         },
         grasp::vrp_grasp_solver::{GraspSolverParameters, VrpGraspSolver},
         greedy::vrp_greedy_solver::VrpGreedySolver,
+        hybrid_genetic::hybrid_genetic_solver::{
+            HybridGeneticSolver, HybridGeneticSolverParameters,
+        },
+        iterated_local_search::{
+            acceptance_criterion::ThresholdAcceptance,
+            iterated_local_search_solver::{
+                IteratedLocalSearchParameters, IteratedLocalSearchSolver,
+            },
+        },
+        portfolio::portfolio_solver::{
+            PortfolioExecution, PortfolioSolver, PortfolioSolverParameters,
+        },
+        ruin_and_recreate::ruin_and_recreate_solver::{
+            RuinAndRecreateParameters, RuinAndRecreateSolver,
+        },
+        set_partitioning::{
+            route_pool::RoutePool,
+            set_partitioning_solver::{SetPartitioningParameters, SetPartitioningSolver},
+        },
         solver::Solver,
+        tabu_search::tabu_search_solver::{TabuSearchSolver, TabuSearchSolverParameters},
         two_stage_genetic::two_stage_genetic_solver::{
             TwoStageGeneticSolver, TwoStageGeneticSolverParameters,
         },
@@ -42,6 +89,37 @@ fn distances(vrp_inputs: VrpInputs) -> DistanceMatrix {
     vrp_inputs.distances
 }
 
+#[fixture]
+fn coordinates(vrp_inputs: VrpInputs) -> CoordinateMap {
+    vrp_inputs.coordinates
+}
+
+#[fixture]
+fn small_vrp_inputs(vrp_inputs: VrpInputs) -> VrpInputs {
+    let stops: Vec<Stop> = vrp_inputs.stops.into_iter().take(11).collect();
+
+    let distances = vrp_inputs
+        .distances
+        .into_iter()
+        .filter(|((from, to), _)| {
+            stops.iter().any(|stop| stop.id == *from) && stops.iter().any(|stop| stop.id == *to)
+        })
+        .collect();
+
+    let coordinates = vrp_inputs
+        .coordinates
+        .into_iter()
+        .filter(|(id, _)| stops.iter().any(|stop| stop.id == *id))
+        .collect();
+
+    VrpInputs {
+        stops,
+        distances,
+        coordinates,
+        vehicles: vrp_inputs.vehicles,
+    }
+}
+
 #[rstest]
 fn test_can_solve_with_greedy_solver(
     stops: Vec<Stop>,
@@ -51,7 +129,58 @@ fn test_can_solve_with_greedy_solver(
     let mut solver = VrpGreedySolver::new(vehicles, &distances, stops);
 
     let solution = solver.solve();
-    assert_eq!(solution.total_distance, 1235.28748)
+    assert_eq!(solution.total_distance, 1235.2875)
+}
+
+#[rstest]
+fn test_can_solve_with_tabu_search_solver(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+) {
+    let first_stage_solver = VrpGreedySolver::new(vehicles, &distances, stops.clone());
+
+    let parameters = TabuSearchSolverParameters {
+        max_iterations: 100,
+        tabu_tenure: 10,
+        neighborhood_size: 8,
+        diversification_factor: 1.0,
+    };
+
+    let mut solver =
+        TabuSearchSolver::new(stops, &distances, Box::new(first_stage_solver), parameters);
+
+    let solution = solver.solve();
+    assert!(solution.total_distance < 1235.2875)
+}
+
+#[rstest]
+fn test_can_solve_with_iterated_local_search_solver(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let first_stage_solver = VrpGreedySolver::new(vehicles, &distances, stops);
+
+    let parameters = IteratedLocalSearchParameters {
+        max_iterations: 50,
+        perturbation_intensity: 2,
+    };
+
+    let mut solver = IteratedLocalSearchSolver::new(
+        parameters,
+        Box::new(first_stage_solver),
+        Box::new(DoubleBridgeShaker::new(
+            distance_service.clone(),
+            ChaCha8Rng::seed_from_u64(0),
+        )),
+        Box::new(TwoOptSearcher::new(distance_service)),
+        Box::new(ThresholdAcceptance { threshold: 10.0 }),
+    );
+
+    let solution = solver.solve();
+    assert!(solution.total_distance < 1235.2875)
 }
 
 #[rstest]
@@ -70,7 +199,7 @@ fn test_can_solve_with_grasp_solver(
     let mut solver = VrpGraspSolver::new(stops, vehicles, &distances, parameters, rng);
     let solution = solver.solve();
 
-    const POSSIBLE_RESULTS: [f32; 2] = [1360.4854, 1429.20361];
+    const POSSIBLE_RESULTS: [f32; 2] = [1316.9117, 1391.0204];
 
     assert!(POSSIBLE_RESULTS.contains(&solution.total_distance));
 }
@@ -101,8 +230,12 @@ fn test_can_solve_with_grasp_genetic_solver(
         genetic_solver_parameters: GeneticSolverParameters {
             elite_size: 5,
             local_search_rate: 0.01,
-            mutation_rate: 0.01,
+            mutations: vec![WeightedMutation::new(
+                MutationStrategy::Swap(SwapMutation),
+                0.01,
+            )],
             max_generations: 10,
+            ..Default::default()
         },
     };
 
@@ -120,3 +253,525 @@ fn test_can_solve_with_grasp_genetic_solver(
     let solution = genetic_solver.solve();
     assert_ne!(solution.total_distance, f32::MAX);
 }
+
+#[rstest]
+#[case::order(Box::new(OrderCrossover::new(255)))]
+#[case::partially_mapped(Box::new(PartiallyMappedCrossover::new(255)))]
+#[case::cycle(Box::new(CycleCrossover::new(255)))]
+#[case::edge_recombination(Box::new(EdgeRecombinationCrossover::new(255)))]
+#[case::best_cost_route(Box::new(BestCostRouteCrossover::new(255)))]
+#[case::selective_route_exchange(Box::new(SelectiveRouteExchangeCrossover::new(255)))]
+fn test_can_solve_with_each_crossover_operator(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+    #[case] crossover_operator: Box<dyn CrossoverOperator<ChaCha8Rng>>,
+) {
+    let rng = ChaCha8Rng::seed_from_u64(0);
+
+    let mut grasp_solver = VrpGraspSolver::new(
+        stops.clone(),
+        vehicles,
+        &distances,
+        GraspSolverParameters {
+            rcl_size: 10,
+            max_improvement_times: 3,
+        },
+        rng.clone(),
+    );
+
+    let genetic_parameters = TwoStageGeneticSolverParameters {
+        population_size: 20,
+        genetic_solver_parameters: GeneticSolverParameters {
+            elite_size: 5,
+            local_search_rate: 0.01,
+            mutations: vec![WeightedMutation::new(
+                MutationStrategy::Swap(SwapMutation),
+                0.01,
+            )],
+            max_generations: 10,
+            ..Default::default()
+        },
+    };
+
+    let mut genetic_solver = TwoStageGeneticSolver::new(
+        stops.clone(),
+        &distances,
+        &mut grasp_solver,
+        genetic_parameters,
+        crossover_operator.as_ref(),
+        Box::new(rng),
+    );
+
+    let solution = genetic_solver.solve();
+
+    let visited_stops: usize = solution
+        .routes
+        .values()
+        .flat_map(|route| route.stops.iter())
+        .filter(|stop| stop.id != stops[0].id)
+        .count();
+
+    assert_eq!(visited_stops, stops.len() - 1);
+    assert_ne!(solution.total_distance, f32::MAX);
+}
+
+#[rstest]
+fn test_can_solve_with_hybrid_genetic_solver(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let parameters = HybridGeneticSolverParameters {
+        population_size: 25,
+        generation_size: 40,
+        closest_neighbors: 5,
+        capacity_penalty: 10.0,
+        genetic_solver_parameters: GeneticSolverParameters {
+            elite_size: 4,
+            mutations: vec![WeightedMutation::new(
+                MutationStrategy::Swap(SwapMutation),
+                0.1,
+            )],
+            max_generations: 200,
+            local_search_rate: 0.5,
+            ..Default::default()
+        },
+    };
+
+    let mut solver = HybridGeneticSolver::new(
+        stops,
+        vehicles,
+        &distances,
+        parameters,
+        Box::new(TwoOptSearcher::new(distance_service)),
+        Box::new(ChaCha8Rng::seed_from_u64(0)),
+    );
+
+    let solution = solver.solve();
+    assert!(solution.total_distance < 1235.2875)
+}
+
+#[rstest]
+fn test_can_solve_with_ant_colony_solver(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+) {
+    let number_of_stops = stops.len();
+
+    let parameters = AntColonySolverParameters {
+        number_of_ants: 10,
+        max_iterations: 30,
+        pheromone_influence: 1.0,
+        distance_influence: 2.0,
+        evaporation_rate: 0.1,
+        improve_best_ant: true,
+    };
+
+    let mut solver = AntColonySolver::new(
+        stops,
+        vehicles,
+        &distances,
+        parameters,
+        ChaCha8Rng::seed_from_u64(0),
+    );
+
+    let solution = solver.solve();
+    let visited_stops: usize = solution
+        .routes
+        .values()
+        .map(|route| route.stops.len() - 2)
+        .sum();
+
+    assert_eq!(visited_stops, number_of_stops - 1);
+    assert!(solution.total_distance < 1235.2875)
+}
+
+#[rstest]
+fn test_branch_and_bound_solver_bounds_the_heuristics(small_vrp_inputs: VrpInputs) {
+    let VrpInputs {
+        stops,
+        vehicles,
+        distances,
+        ..
+    } = small_vrp_inputs;
+
+    let mut greedy_solver = VrpGreedySolver::new(vehicles.clone(), &distances, stops.clone());
+
+    let mut grasp_solver = VrpGraspSolver::new(
+        stops.clone(),
+        vehicles.clone(),
+        &distances,
+        GraspSolverParameters {
+            rcl_size: 3,
+            max_improvement_times: 5,
+        },
+        ChaCha8Rng::seed_from_u64(0),
+    );
+
+    let parameters = BranchAndBoundParameters {
+        node_limit: 10_000_000,
+        time_limit: Duration::from_secs(60),
+    };
+
+    let mut solver = BranchAndBoundSolver::new(stops, vehicles, &distances, parameters);
+
+    let solution = solver.solve();
+    let greedy_solution = greedy_solver.solve();
+    let grasp_solution = grasp_solver.solve();
+
+    assert!(solver.is_optimal());
+    assert!(solution.total_distance <= greedy_solution.total_distance);
+    assert!(solution.total_distance <= grasp_solution.total_distance);
+}
+
+#[rstest]
+fn test_lower_bounds_stay_below_the_known_optimum(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+) {
+    let lower_bounds =
+        LowerBoundService::new(stops.clone(), vehicles.clone(), &distances).calculate();
+
+    let greedy_solver = VrpGreedySolver::new(vehicles, &distances, stops);
+    let mut solver = BoundedSolver::new(Box::new(greedy_solver), lower_bounds.get_best());
+
+    let solution = solver.solve();
+
+    assert_eq!(lower_bounds.minimum_vehicles, 5);
+    assert!(lower_bounds.get_best() <= 784.0);
+    assert!(solution.get_gap().unwrap() > 0.0);
+}
+
+#[rstest]
+fn test_can_recombine_routes_from_grasp_and_genetic_solvers(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let route_pool = Rc::new(RefCell::new(RoutePool::new(distance_service.clone())));
+
+    let mut grasp_solver = VrpGraspSolver::new(
+        stops.clone(),
+        vehicles.clone(),
+        &distances,
+        GraspSolverParameters {
+            rcl_size: 2,
+            max_improvement_times: 10,
+        },
+        ChaCha8Rng::seed_from_u64(0),
+    )
+    .with_route_pool(route_pool.clone());
+
+    let grasp_solution = grasp_solver.solve();
+
+    let crossover_op = OrderCrossover::new(5);
+    let mut first_stage_solver = VrpGreedySolver::new(vehicles.clone(), &distances, stops.clone());
+
+    let mut genetic_solver = TwoStageGeneticSolver::new(
+        stops.clone(),
+        &distances,
+        &mut first_stage_solver,
+        TwoStageGeneticSolverParameters {
+            population_size: 10,
+            genetic_solver_parameters: GeneticSolverParameters {
+                elite_size: 5,
+                mutations: vec![WeightedMutation::new(
+                    MutationStrategy::Swap(SwapMutation),
+                    0.1,
+                )],
+                max_generations: 50,
+                local_search_rate: 0.5,
+                ..Default::default()
+            },
+        },
+        &crossover_op,
+        Box::new(ChaCha8Rng::seed_from_u64(0)),
+    )
+    .with_route_pool(route_pool.clone());
+
+    genetic_solver.solve();
+
+    let mut solver = SetPartitioningSolver::new(
+        vehicles,
+        route_pool.clone(),
+        distance_service,
+        Box::new(grasp_solver),
+        SetPartitioningParameters {
+            rounds: 3,
+            node_limit: 100_000,
+        },
+    );
+
+    let solution = solver.solve();
+
+    assert!(solution.total_distance <= grasp_solution.total_distance);
+    assert!(solution.total_distance < 1235.2875);
+}
+
+#[rstest]
+fn test_lin_kernighan_improves_long_routes_beyond_stop_swaps(
+    stops: Vec<Stop>,
+    distances: DistanceMatrix,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let mut route = Route::new(Vehicle::new(0, u32::MAX));
+    route
+        .replace_stops(
+            stops.iter().chain([&stops[0]]).copied().collect(),
+            &distance_service,
+        )
+        .unwrap();
+
+    let mut swapped_route = route.clone();
+    TwoOptSearcher::new(distance_service.clone()).run(&mut swapped_route);
+
+    LinKernighanSearcher::new(distance_service, 8, 5).run(&mut route);
+
+    assert_eq!(route.stops.len(), stops.len() + 1);
+    assert!(route.total_distance() < swapped_route.total_distance());
+}
+
+#[rstest]
+fn test_inter_route_search_improves_greedy_solutions(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let mut solution = VrpGreedySolver::new(vehicles, &distances, stops.clone()).solve();
+
+    LocalSearcher::run(&InterRouteSearcher::new(distance_service), &mut solution);
+
+    let visited_stops: usize = solution
+        .routes
+        .values()
+        .map(|route| route.stops.len() - 2)
+        .sum();
+
+    assert_eq!(visited_stops, stops.len() - 1);
+    assert!(solution.total_distance < 1235.2875);
+}
+
+#[rstest]
+fn test_can_solve_with_inter_route_genetic_mutation(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+) {
+    let rng = ChaCha8Rng::seed_from_u64(0);
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+
+    let mut grasp_solver = VrpGraspSolver::new(
+        stops.clone(),
+        vehicles,
+        &distances,
+        GraspSolverParameters {
+            rcl_size: 10,
+            max_improvement_times: 3,
+        },
+        rng.clone(),
+    );
+
+    let genetic_parameters = TwoStageGeneticSolverParameters {
+        population_size: 20,
+        genetic_solver_parameters: GeneticSolverParameters {
+            elite_size: 5,
+            local_search_rate: 0.01,
+            mutations: vec![
+                WeightedMutation::new(MutationStrategy::Swap(SwapMutation), 0.25),
+                WeightedMutation::new(
+                    MutationStrategy::InterRouteSearch(Rc::new(InterRouteSearcher::new(
+                        distance_service,
+                    ))),
+                    0.25,
+                ),
+            ],
+            max_generations: 10,
+            ..Default::default()
+        },
+    };
+
+    let crossover_operator = OrderCrossover::new(255);
+
+    let mut genetic_solver = TwoStageGeneticSolver::new(
+        stops.clone(),
+        &distances,
+        &mut grasp_solver,
+        genetic_parameters,
+        &crossover_operator,
+        Box::new(rng),
+    );
+
+    let solution = genetic_solver.solve();
+
+    let visited_stops: usize = solution
+        .routes
+        .values()
+        .flat_map(|route| route.stops.iter())
+        .filter(|stop| stop.id != stops[0].id)
+        .count();
+
+    assert_eq!(visited_stops, stops.len() - 1);
+    assert_ne!(solution.total_distance, f32::MAX);
+}
+
+#[rstest]
+fn test_swap_star_improves_greedy_solutions(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+    coordinates: CoordinateMap,
+) {
+    let distance_service = Rc::new(DistanceService::new(stops.clone(), &distances));
+    let mut solution = VrpGreedySolver::new(vehicles, &distances, stops.clone()).solve();
+
+    LocalSearcher::run(
+        &SwapStarSearcher::new(distance_service, coordinates),
+        &mut solution,
+    );
+
+    let visited_stops = solution
+        .routes
+        .values()
+        .flat_map(|route| route.stops.iter())
+        .filter(|stop| stop.id != stops[0].id)
+        .count();
+
+    assert_eq!(visited_stops, stops.len() - 1);
+    assert!(solution.total_distance < 1235.2875);
+}
+
+#[rstest]
+fn test_ruin_and_recreate_improves_greedy_solutions(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+) {
+    let initial_solution = VrpGreedySolver::new(vehicles, &distances, stops.clone()).solve();
+
+    let parameters = RuinAndRecreateParameters {
+        max_iterations: 3000,
+        ..Default::default()
+    };
+
+    let mut solver = RuinAndRecreateSolver::new(
+        stops.clone(),
+        &distances,
+        initial_solution,
+        parameters,
+        ChaCha8Rng::seed_from_u64(0),
+    );
+
+    let solution = solver.solve();
+
+    let visited_stops = solution
+        .routes
+        .values()
+        .flat_map(|route| route.stops.iter())
+        .filter(|stop| stop.id != stops[0].id)
+        .count();
+
+    assert_eq!(visited_stops, stops.len() - 1);
+    assert!(solution.total_distance >= 784.0);
+    assert!(solution.total_distance < 850.0);
+}
+
+#[rstest]
+fn test_portfolio_solver_races_heuristics(
+    stops: Vec<Stop>,
+    vehicles: Vec<Vehicle>,
+    distances: DistanceMatrix,
+) {
+    let rng = ChaCha8Rng::seed_from_u64(0);
+
+    let mut grasp_solver = VrpGraspSolver::new(
+        stops.clone(),
+        vehicles.clone(),
+        &distances,
+        GraspSolverParameters {
+            rcl_size: 10,
+            max_improvement_times: 3,
+        },
+        rng.clone(),
+    );
+
+    let crossover_operator = OrderCrossover::new(255);
+
+    let genetic_solver = TwoStageGeneticSolver::new(
+        stops.clone(),
+        &distances,
+        &mut grasp_solver,
+        TwoStageGeneticSolverParameters {
+            population_size: 20,
+            genetic_solver_parameters: GeneticSolverParameters {
+                elite_size: 5,
+                local_search_rate: 0.01,
+                mutations: vec![WeightedMutation::new(
+                    MutationStrategy::Swap(SwapMutation),
+                    0.01,
+                )],
+                max_generations: 10,
+                ..Default::default()
+            },
+        },
+        &crossover_operator,
+        Box::new(rng),
+    );
+
+    let ruin_and_recreate_stops = stops.clone();
+    let ruin_and_recreate_vehicles = vehicles.clone();
+    let ruin_and_recreate_distances = distances.clone();
+
+    let mut solver = PortfolioSolver::new(PortfolioSolverParameters {
+        time_budget: Duration::from_secs(30),
+        execution: PortfolioExecution::Sequential,
+        max_runs_per_strategy: 1,
+    })
+    .with_solver(
+        "greedy",
+        Box::new(VrpGreedySolver::new(vehicles, &distances, stops.clone())),
+    )
+    .with_solver("genetic", Box::new(genetic_solver))
+    .with_solver_factory(
+        "ruin_and_recreate",
+        Box::new(move || {
+            let initial_solution = VrpGreedySolver::new(
+                ruin_and_recreate_vehicles.clone(),
+                &ruin_and_recreate_distances,
+                ruin_and_recreate_stops.clone(),
+            )
+            .solve();
+
+            Box::new(RuinAndRecreateSolver::new(
+                ruin_and_recreate_stops.clone(),
+                &ruin_and_recreate_distances,
+                initial_solution,
+                RuinAndRecreateParameters {
+                    max_iterations: 3000,
+                    ..Default::default()
+                },
+                ChaCha8Rng::seed_from_u64(0),
+            ))
+        }),
+    );
+
+    let solution = solver.solve();
+    let report = solution
+        .portfolio_report
+        .as_ref()
+        .expect("the portfolio should report its strategies");
+
+    assert_eq!(report.records.len(), 3);
+    assert_eq!(report.winner.as_deref(), Some("ruin_and_recreate"));
+    assert!(report
+        .records
+        .iter()
+        .all(|record| solution.total_distance <= record.best_cost));
+}
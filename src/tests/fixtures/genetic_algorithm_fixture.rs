@@ -1 +1,94 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use rstest::fixture;
 
+use crate::{
+    domain::stop::Stop,
+    services::distance::distance_service::DistanceMatrix,
+    solvers::{
+        genetic::{
+            crossover::{crossover_operator::CrossoverOperator, order_crossover::OrderCrossover},
+            genetic_solver::GeneticSolverParameters,
+        },
+        random::random_solver::RandomSolver,
+        solver::Solver,
+        two_stage_genetic::two_stage_genetic_solver::{
+            TwoStageGeneticSolver, TwoStageGeneticSolverParameters,
+        },
+        vrp_solution::VrpSolution,
+    },
+};
+
+use super::{
+    distances_fixture::distances, stops_fixture::stops, vehicles_fixture::vehicle_factory,
+};
+
+/// Owns what a `TwoStageGeneticSolver` borrows, so each test only sets the genetic solver
+/// parameters it varies. By default the first stage is a random solver with two vehicles, the
+/// crossover is an `OrderCrossover` and the population has 10 individuals.
+pub struct GeneticSolverFactory {
+    stops: Vec<Stop>,
+    distances: DistanceMatrix,
+    population_size: u32,
+    first_stage_solver: Box<dyn Solver<VrpSolution>>,
+    crossover_op: Box<dyn CrossoverOperator<ChaCha8Rng>>,
+}
+
+impl GeneticSolverFactory {
+    pub fn new(stops: Vec<Stop>, distances: DistanceMatrix) -> Self {
+        let first_stage_solver = RandomSolver::new(
+            stops.clone(),
+            vehicle_factory()(2),
+            &distances,
+            Box::new(ChaCha8Rng::seed_from_u64(0)),
+        );
+
+        Self {
+            stops,
+            distances,
+            population_size: 10,
+            first_stage_solver: Box::new(first_stage_solver),
+            crossover_op: Box::new(OrderCrossover::new(5)),
+        }
+    }
+
+    pub fn with_population_size(mut self, population_size: u32) -> Self {
+        self.population_size = population_size;
+        self
+    }
+
+    pub fn with_first_stage_solver(
+        mut self,
+        first_stage_solver: Box<dyn Solver<VrpSolution>>,
+    ) -> Self {
+        self.first_stage_solver = first_stage_solver;
+        self
+    }
+
+    pub fn with_crossover(mut self, crossover_op: Box<dyn CrossoverOperator<ChaCha8Rng>>) -> Self {
+        self.crossover_op = crossover_op;
+        self
+    }
+
+    pub fn build(
+        &mut self,
+        parameters: GeneticSolverParameters,
+    ) -> TwoStageGeneticSolver<'_, ChaCha8Rng> {
+        TwoStageGeneticSolver::new(
+            self.stops.clone(),
+            &self.distances,
+            self.first_stage_solver.as_mut(),
+            TwoStageGeneticSolverParameters {
+                population_size: self.population_size,
+                genetic_solver_parameters: parameters,
+            },
+            self.crossover_op.as_ref(),
+            Box::new(ChaCha8Rng::seed_from_u64(0)),
+        )
+    }
+}
+
+#[fixture]
+pub fn genetic_solver_factory(stops: Vec<Stop>, distances: DistanceMatrix) -> GeneticSolverFactory {
+    GeneticSolverFactory::new(stops, distances)
+}
@@ -1,4 +1,5 @@
 pub mod distances_fixture;
+#[cfg(test)]
 pub mod genetic_algorithm_fixture;
 pub mod greedy_solver_fixture;
 pub mod routes_fixture;
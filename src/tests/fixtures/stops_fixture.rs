@@ -29,3 +29,14 @@ pub fn stops_with_crossings() -> Vec<Stop> {
         Stop::new(0, 10),
     ])
 }
+
+#[cfg(test)]
+pub fn ids(stops: &[Stop]) -> Vec<u32> {
+    stops.iter().map(|stop| stop.id).collect()
+}
+
+/// Stops without load, one per id.
+#[cfg(test)]
+pub fn unloaded_stops(ids: &[u32]) -> Vec<Stop> {
+    ids.iter().map(|id| Stop::new(*id, 0)).collect()
+}